@@ -0,0 +1,144 @@
+//! CPU-side frustum culling for entities that aren't rendered, such as audio
+//! emitters or level of detail proxies.
+
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, PostUpdate};
+use bevy_ecs::{
+    change_detection::DetectChangesMut, component::Component, query::With,
+    reflect::ReflectComponent, schedule::IntoScheduleConfigs as _, system::Query,
+};
+use bevy_math::Vec3;
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+use bevy_transform::components::GlobalTransform;
+
+use super::VisibilitySystems;
+use crate::{
+    camera::Camera,
+    primitives::{Aabb, Frustum},
+};
+
+/// A plugin that updates the [`Culled`] state of entities with a [`FrustumCulled<T>`] component.
+///
+/// Entities are culled against the [`Frustum`] of the primary camera with the `T` component.
+/// The primary camera is the active [`Camera`] with the lowest [`Camera::order`], which is the
+/// main world camera whose frustum is later extracted for rendering. By default `T` is [`Camera`],
+/// so every camera is considered; use a marker component to pick a specific camera instead.
+///
+/// The plugin must be added once for each `T` used with [`FrustumCulled`].
+pub struct FrustumCulledPlugin<T = Camera>(PhantomData<fn() -> T>);
+
+impl<T> Default for FrustumCulledPlugin<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<T: Component> Plugin for FrustumCulledPlugin<T> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            PostUpdate,
+            update_frustum_culled::<T>
+                .after(VisibilitySystems::UpdateFrusta)
+                .after(VisibilitySystems::CalculateBounds),
+        );
+    }
+}
+
+/// Marks an entity to be frustum culled on the CPU by [`FrustumCulledPlugin<T>`].
+///
+/// Unlike the culling performed by the renderer, this works for any entity with an [`Aabb`]
+/// and a [`GlobalTransform`], and exposes the result to gameplay code through the [`Culled`]
+/// component, which is updated every frame in [`PostUpdate`].
+#[derive(Component)]
+#[require(Culled)]
+pub struct FrustumCulled<T: Component = Camera>(PhantomData<fn() -> T>);
+
+impl<T: Component> Default for FrustumCulled<T> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+/// Whether an entity with a [`FrustumCulled`] component is outside the camera's frustum.
+///
+/// The test is conservative: entities near the edges of the frustum may be reported as visible
+/// even though they lie outside, but visible entities are never culled. The state is left
+/// unchanged on frames without an active camera.
+#[derive(Component, Clone, Copy, Debug, Default, PartialEq, Eq, Reflect)]
+#[reflect(Component, Default, Debug, PartialEq, Clone)]
+pub struct Culled(pub bool);
+
+/// Updates [`Culled`] for entities with a [`FrustumCulled<T>`] component.
+pub fn update_frustum_culled<T: Component>(
+    cameras: Query<(&Camera, &Frustum), With<T>>,
+    mut entities: Query<(&Aabb, &GlobalTransform, &mut Culled), With<FrustumCulled<T>>>,
+) {
+    let Some((_, frustum)) = cameras
+        .iter()
+        .filter(|(camera, _)| camera.is_active)
+        .min_by_key(|(camera, _)| camera.order)
+    else {
+        return;
+    };
+
+    for (aabb, transform, mut culled) in &mut entities {
+        // Compute the world space AABB enclosing the transformed box.
+        let world_from_local = transform.affine();
+        let center = world_from_local.transform_point3a(aabb.center);
+        let half_extents = world_from_local.matrix3.x_axis.abs() * aabb.half_extents.x
+            + world_from_local.matrix3.y_axis.abs() * aabb.half_extents.y
+            + world_from_local.matrix3.z_axis.abs() * aabb.half_extents.z;
+        let is_culled = !frustum.0.intersects_aabb(
+            Vec3::from(center - half_extents),
+            Vec3::from(center + half_extents),
+        );
+        culled.set_if_neq(Culled(is_culled));
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bevy_math::{primitives::ViewFrustum, Mat4};
+
+    #[derive(Component)]
+    struct MainView;
+
+    #[test]
+    fn frustum_culled() {
+        let mut app = App::new();
+        app.add_plugins(FrustumCulledPlugin::<MainView>::default());
+
+        // A camera at the origin looking towards -Z, seeing a 2x2x10 box.
+        let clip_from_world = Mat4::orthographic_rh(-1.0, 1.0, -1.0, 1.0, 0.0, 10.0);
+        app.world_mut().spawn((
+            Camera::default(),
+            Frustum(ViewFrustum::from_clip_from_world(&clip_from_world)),
+            MainView,
+        ));
+
+        let aabb = Aabb::from_min_max(Vec3::splat(-0.5), Vec3::splat(0.5));
+        let inside = app
+            .world_mut()
+            .spawn((
+                FrustumCulled::<MainView>::default(),
+                aabb,
+                GlobalTransform::from_xyz(0.0, 0.0, -5.0),
+            ))
+            .id();
+        let outside = app
+            .world_mut()
+            .spawn((
+                FrustumCulled::<MainView>::default(),
+                aabb,
+                GlobalTransform::from_xyz(5.0, 0.0, -5.0),
+            ))
+            .id();
+
+        app.update();
+
+        assert_eq!(app.world().get::<Culled>(inside), Some(&Culled(false)));
+        assert_eq!(app.world().get::<Culled>(outside), Some(&Culled(true)));
+    }
+}
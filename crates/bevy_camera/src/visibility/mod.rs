@@ -1,3 +1,4 @@
+mod frustum_culled;
 mod range;
 mod render_layers;
 
@@ -10,6 +11,7 @@ use bevy_mesh::skinning::{
     entity_aabb_from_skinned_mesh_bounds, SkinnedMesh, SkinnedMeshInverseBindposes,
 };
 use derive_more::derive::{Deref, DerefMut};
+pub use frustum_culled::*;
 pub use range::*;
 pub use render_layers::*;
 
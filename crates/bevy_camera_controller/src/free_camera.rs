@@ -99,6 +99,14 @@ pub struct FreeCamera {
     pub scroll_factor: f32,
     /// Friction factor used to exponentially decay [`velocity`](FreeCameraState::velocity) over time.
     pub friction: f32,
+    /// Amount of exponential smoothing applied to the camera's translation and rotation.
+    ///
+    /// This is roughly the time in seconds that the camera takes to catch up with the input.
+    /// `0.0` moves and rotates the camera instantly, and higher values make it lag further behind.
+    ///
+    /// While smoothing is enabled, the controller overrides changes made to the camera's
+    /// [`Transform`] by other systems.
+    pub smoothing: f32,
 }
 
 impl Default for FreeCamera {
@@ -118,6 +126,7 @@ impl Default for FreeCamera {
             run_speed: 15.0,
             scroll_factor: 0.5,
             friction: 40.0,
+            smoothing: 0.0,
         }
     }
 }
@@ -170,6 +179,10 @@ pub struct FreeCameraState {
     pub speed_multiplier: f32,
     /// This [`FreeCamera`]'s translation velocity.
     pub velocity: Vec3,
+    /// The translation that the camera moves towards when [`FreeCamera::smoothing`] is enabled.
+    target_translation: Vec3,
+    /// The rotation that the camera turns towards when [`FreeCamera::smoothing`] is enabled.
+    target_rotation: Quat,
 }
 
 impl Default for FreeCameraState {
@@ -181,6 +194,8 @@ impl Default for FreeCameraState {
             yaw: 0.0,
             speed_multiplier: 1.0,
             velocity: Vec3::ZERO,
+            target_translation: Vec3::ZERO,
+            target_rotation: Quat::IDENTITY,
         }
     }
 }
@@ -212,11 +227,17 @@ pub fn run_freecamera_controller(
         let (yaw, pitch, _roll) = transform.rotation.to_euler(EulerRot::YXZ);
         state.yaw = yaw;
         state.pitch = pitch;
+        state.target_translation = transform.translation;
+        state.target_rotation = transform.rotation;
         state.initialized = true;
         info!("{}", *config);
     }
 
     if !state.enabled {
+        // Other systems may move the camera while the controller is disabled.
+        state.target_translation = transform.translation;
+        state.target_rotation = transform.rotation;
+
         // don't keep the cursor grabbed if the camera controller was disabled.
         if *toggle_cursor_grab || *mouse_cursor_grab {
             *toggle_cursor_grab = false;
@@ -295,11 +316,24 @@ pub fn run_freecamera_controller(
         }
     }
 
+    // Movement and rotation are applied to the target transform, which the camera
+    // then follows immediately or with smoothing.
+    let smoothing = config.smoothing.max(0.0);
+    let mut target = if smoothing > 0.0 {
+        Transform {
+            translation: state.target_translation,
+            rotation: state.target_rotation,
+            scale: transform.scale,
+        }
+    } else {
+        *transform
+    };
+
     // Apply movement update
     if state.velocity != Vec3::ZERO {
-        let forward = *transform.forward();
-        let right = *transform.right();
-        transform.translation += state.velocity.x * dt * right
+        let forward = *target.forward();
+        let right = *target.right();
+        target.translation += state.velocity.x * dt * right
             + state.velocity.y * dt * Vec3::Y
             + state.velocity.z * dt * forward;
     }
@@ -330,6 +364,19 @@ pub fn run_freecamera_controller(
             - accumulated_mouse_motion.delta.y * RADIANS_PER_DOT * config.sensitivity)
             .clamp(-PI / 2., PI / 2.);
         state.yaw -= accumulated_mouse_motion.delta.x * RADIANS_PER_DOT * config.sensitivity;
-        transform.rotation = Quat::from_euler(EulerRot::ZYX, 0.0, state.yaw, state.pitch);
+        target.rotation = Quat::from_euler(EulerRot::ZYX, 0.0, state.yaw, state.pitch);
+    }
+
+    state.target_translation = target.translation;
+    state.target_rotation = target.rotation;
+    if smoothing > 0.0 {
+        let decay_rate = 1.0 / smoothing;
+        let mut translation = transform.translation;
+        translation.smooth_nudge(&target.translation, decay_rate, dt);
+        let mut rotation = transform.rotation;
+        rotation.smooth_nudge(&target.rotation, decay_rate, dt);
+        target.translation = translation;
+        target.rotation = rotation;
     }
+    transform.set_if_neq(target);
 }
@@ -35,9 +35,17 @@
 //! please check out the modules of this crate.
 //! Each camera controller is stored in its own module,
 //! and gated behind a feature flag of the same name.
+//!
+//! To move smoothly from one camera controller to another, see the [`transition`] module,
+//! which is always available.
 
 #[cfg(feature = "free_camera")]
 pub mod free_camera;
 
+#[cfg(feature = "orbit_camera")]
+pub mod orbit_camera;
+
 #[cfg(feature = "pan_camera")]
 pub mod pan_camera;
+
+pub mod transition;
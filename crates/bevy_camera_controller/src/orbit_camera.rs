@@ -0,0 +1,283 @@
+//! A controller for 3D cameras that orbit around a focus point.
+//!
+//! To use this controller, add [`OrbitCameraPlugin`] to your app,
+//! and insert an [`OrbitCamera`] component into your camera entity.
+//!
+//! The camera's position is stored in [spherical coordinates] relative to
+//! [`OrbitCamera::focus`], and the camera always looks at the focus point.
+//! Dragging the mouse orbits the camera around the focus, and the mouse wheel zooms in and out.
+//!
+//! To configure the settings of this controller, modify the fields of the [`OrbitCamera`] component.
+//!
+//! [spherical coordinates]: https://en.wikipedia.org/wiki/Spherical_coordinate_system
+
+use bevy_app::{App, Plugin, RunFixedMainLoop, RunFixedMainLoopSystems};
+use bevy_camera::Camera;
+use bevy_ecs::prelude::*;
+use bevy_input::mouse::{
+    AccumulatedMouseMotion, AccumulatedMouseScroll, MouseButton, MouseScrollUnit,
+};
+use bevy_input::ButtonInput;
+use bevy_math::{ops, Vec2, Vec3};
+use bevy_transform::prelude::Transform;
+
+use core::fmt;
+
+/// A plugin that enables orbit camera controls.
+///
+/// Add this plugin to your [`App`] to enable [`OrbitCamera`] behavior
+/// on any camera entity that has the [`OrbitCamera`] component.
+pub struct OrbitCameraPlugin;
+
+impl Plugin for OrbitCameraPlugin {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RunFixedMainLoop,
+            run_orbitcamera_controller.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        );
+    }
+}
+
+/// Configuration and state for an orbit camera controller.
+///
+/// The position of the camera is given by [`radius`](OrbitCamera::radius),
+/// [`inclination`](OrbitCamera::inclination) and [`azimuth`](OrbitCamera::azimuth),
+/// relative to [`focus`](OrbitCamera::focus). The controller overwrites the camera's
+/// [`Transform`] every frame, so move the camera by changing these fields instead.
+///
+/// Add this component to a [`Camera`] entity to enable mouse controls
+/// for orbiting and zooming. Requires the [`OrbitCameraPlugin`].
+#[derive(Component)]
+pub struct OrbitCamera {
+    /// Enables this [`OrbitCamera`] when `true`.
+    pub enabled: bool,
+    /// The point the camera orbits around and looks at.
+    pub focus: Vec3,
+    /// The distance between the camera and the focus point.
+    pub radius: f32,
+    /// The angle in radians between the +Y axis and the camera, as seen from the focus point.
+    pub inclination: f32,
+    /// The angle in radians around the Y axis from the +X axis towards the +Z axis,
+    /// as seen from the focus point.
+    pub azimuth: f32,
+    /// Minimum allowed [`radius`](OrbitCamera::radius).
+    pub min_radius: f32,
+    /// Maximum allowed [`radius`](OrbitCamera::radius).
+    pub max_radius: f32,
+    /// Minimum allowed [`inclination`](OrbitCamera::inclination).
+    ///
+    /// This should stay above 0, since the camera can't look straight down
+    /// at the focus point without its up direction becoming undefined.
+    pub min_inclination: f32,
+    /// Maximum allowed [`inclination`](OrbitCamera::inclination).
+    ///
+    /// This should stay below PI, for the same reason as [`min_inclination`](OrbitCamera::min_inclination).
+    pub max_inclination: f32,
+    /// [`MouseButton`] that must be held to orbit the camera.
+    pub orbit_button: MouseButton,
+    /// The number of radians of azimuth (x) and inclination (y) that one pixel
+    /// of mouse movement corresponds to.
+    pub orbit_speed: Vec2,
+    /// The change in [`radius`](OrbitCamera::radius) that one line of mouse scroll corresponds to.
+    pub zoom_speed: f32,
+}
+
+/// Provides the default values for the `OrbitCamera` controller.
+///
+/// The default settings are:
+/// - Focus: origin
+/// - Radius: 5.0
+/// - Inclination: PI / 4
+/// - Azimuth: PI / 4
+/// - Radius range: 0.01 to infinity
+/// - Inclination range: 0.01 to PI - 0.01
+/// - Orbit button: Left mouse button
+/// - Orbit speed: 0.004 (azimuth) and 0.003 (inclination) radians per pixel
+/// - Zoom speed: 0.15
+impl Default for OrbitCamera {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            focus: Vec3::ZERO,
+            radius: 5.0,
+            inclination: core::f32::consts::FRAC_PI_4,
+            azimuth: core::f32::consts::FRAC_PI_4,
+            min_radius: 0.01,
+            max_radius: f32::INFINITY,
+            min_inclination: 0.01,
+            max_inclination: core::f32::consts::PI - 0.01,
+            orbit_button: MouseButton::Left,
+            orbit_speed: Vec2::new(0.004, 0.003),
+            zoom_speed: 0.15,
+        }
+    }
+}
+
+impl OrbitCamera {
+    /// Creates an [`OrbitCamera`] orbiting `focus` from the camera position `translation`,
+    /// with the remaining settings left at their defaults.
+    ///
+    /// This converts `translation` into spherical coordinates relative to `focus`,
+    /// and is the inverse of [`OrbitCamera::translation`].
+    pub fn from_translation(focus: Vec3, translation: Vec3) -> Self {
+        let mut orbit_camera = Self {
+            focus,
+            ..Self::default()
+        };
+        orbit_camera.set_translation(translation);
+        orbit_camera
+    }
+
+    /// Sets the [`radius`](OrbitCamera::radius), [`inclination`](OrbitCamera::inclination)
+    /// and [`azimuth`](OrbitCamera::azimuth) so the camera is positioned at `translation`.
+    ///
+    /// The radius and inclination are not clamped to their allowed ranges until
+    /// the controller next runs.
+    pub fn set_translation(&mut self, translation: Vec3) {
+        let offset = translation - self.focus;
+        self.radius = offset.length();
+        self.inclination = ops::atan2(Vec2::new(offset.x, offset.z).length(), offset.y);
+        self.azimuth = ops::atan2(offset.z, offset.x);
+    }
+
+    /// Returns the position of the camera, converted from spherical coordinates.
+    pub fn translation(&self) -> Vec3 {
+        // https://en.wikipedia.org/wiki/Spherical_coordinate_system#Cartesian_coordinates
+        self.focus
+            + self.radius
+                * Vec3::new(
+                    ops::sin(self.inclination) * ops::cos(self.azimuth),
+                    ops::cos(self.inclination),
+                    ops::sin(self.inclination) * ops::sin(self.azimuth),
+                )
+    }
+
+    /// Returns the [`Transform`] of the camera, positioned at [`OrbitCamera::translation`]
+    /// and looking at [`focus`](OrbitCamera::focus).
+    pub fn transform(&self) -> Transform {
+        Transform::from_translation(self.translation()).looking_at(self.focus, Vec3::Y)
+    }
+}
+
+impl fmt::Display for OrbitCamera {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "
+OrbitCamera Controls:
+  Orbit - Mouse {:?} + Drag
+  Zoom  - Mouse Scroll
+",
+            self.orbit_button,
+        )
+    }
+}
+
+/// This system is typically added via the [`OrbitCameraPlugin`].
+///
+/// Reads inputs and then moves the camera entity according
+/// to the settings given in [`OrbitCamera`].
+///
+/// Systems that move [`OrbitCamera::focus`] should run before this one,
+/// so the camera doesn't lag a frame behind.
+pub fn run_orbitcamera_controller(
+    mouse_buttons: Res<ButtonInput<MouseButton>>,
+    accumulated_mouse_motion: Res<AccumulatedMouseMotion>,
+    accumulated_mouse_scroll: Res<AccumulatedMouseScroll>,
+    mut query: Query<(&mut Transform, &mut OrbitCamera), With<Camera>>,
+) {
+    let mouse_scroll = match accumulated_mouse_scroll.unit {
+        MouseScrollUnit::Line => accumulated_mouse_scroll.delta.y,
+        MouseScrollUnit::Pixel => {
+            accumulated_mouse_scroll.delta.y / MouseScrollUnit::SCROLL_UNIT_CONVERSION_FACTOR
+        }
+    };
+
+    for (mut transform, mut controller) in &mut query {
+        if !controller.enabled {
+            continue;
+        }
+
+        // === Orbit
+        if mouse_buttons.pressed(controller.orbit_button) {
+            let delta = accumulated_mouse_motion.delta * controller.orbit_speed;
+            controller.azimuth -= delta.x;
+            controller.inclination += delta.y;
+        }
+        controller.inclination = controller
+            .inclination
+            .clamp(controller.min_inclination, controller.max_inclination);
+
+        // === Zoom
+        // Zooming doesn't require a mouse button press, as it uses the mouse wheel.
+        controller.radius = (controller.radius - mouse_scroll * controller.zoom_speed)
+            .clamp(controller.min_radius, controller.max_radius);
+
+        *transform = controller.transform();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_ecs::system::RunSystemOnce;
+
+    use super::*;
+
+    #[test]
+    fn from_translation_round_trip() {
+        let focus = Vec3::new(1.0, -2.0, 3.0);
+        for translation in [
+            Vec3::new(4.0, 5.0, -6.0),
+            Vec3::new(-1.0, -7.0, 2.0),
+            Vec3::new(1.0, 10.0, 3.0),
+            focus + Vec3::X,
+            focus - Vec3::Z,
+        ] {
+            let orbit_camera = OrbitCamera::from_translation(focus, translation);
+            assert_eq!(orbit_camera.focus, focus);
+            assert!(
+                orbit_camera.translation().abs_diff_eq(translation, 1e-4),
+                "{translation} became {}",
+                orbit_camera.translation()
+            );
+            assert!(orbit_camera
+                .transform()
+                .translation
+                .abs_diff_eq(translation, 1e-4));
+        }
+    }
+
+    #[test]
+    fn inclination_is_clamped() {
+        let mut world = World::new();
+        let mut mouse_buttons = ButtonInput::<MouseButton>::default();
+        mouse_buttons.press(MouseButton::Left);
+        world.insert_resource(mouse_buttons);
+        world.init_resource::<AccumulatedMouseMotion>();
+        world.init_resource::<AccumulatedMouseScroll>();
+        let camera = world
+            .spawn((
+                Camera::default(),
+                Transform::default(),
+                OrbitCamera::default(),
+            ))
+            .id();
+
+        let drag = |world: &mut World, delta: Vec2| {
+            world.resource_mut::<AccumulatedMouseMotion>().delta = delta;
+            world.run_system_once(run_orbitcamera_controller).unwrap();
+            world.get::<OrbitCamera>(camera).unwrap().inclination
+        };
+
+        let orbit_camera = OrbitCamera::default();
+        assert_eq!(
+            drag(&mut world, Vec2::new(0.0, 1e4)),
+            orbit_camera.max_inclination
+        );
+        assert_eq!(
+            drag(&mut world, Vec2::new(0.0, -1e4)),
+            orbit_camera.min_inclination
+        );
+        assert!(world.get::<Transform>(camera).unwrap().is_finite());
+    }
+}
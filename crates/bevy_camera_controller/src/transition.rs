@@ -0,0 +1,211 @@
+//! Smooth transitions for handing a camera over to a different controller.
+//!
+//! Swapping one camera controller for another usually makes the camera jump, since the new
+//! controller immediately moves the camera to wherever it wants it to be.
+//! A [`CameraTransition`] eases the camera's [`Transform`] towards a target first,
+//! and only inserts the new controller once the camera has arrived.
+//!
+//! To use transitions, add a [`CameraTransitionPlugin`] for each controller type
+//! you want to transition to, then remove the old controller from the camera entity
+//! and insert a [`CameraTransition`] holding the new one.
+
+use core::marker::PhantomData;
+
+use bevy_app::{App, Plugin, RunFixedMainLoop, RunFixedMainLoopSystems};
+use bevy_ecs::prelude::*;
+use bevy_math::curve::{Curve, EaseFunction};
+use bevy_time::{Real, Time};
+use bevy_transform::prelude::Transform;
+
+/// A plugin that runs [`CameraTransition`]s that end by inserting a `C` controller.
+///
+/// The plugin must be added once for each controller type used with [`CameraTransition`].
+pub struct CameraTransitionPlugin<C>(PhantomData<fn() -> C>);
+
+impl<C> Default for CameraTransitionPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: Component> Plugin for CameraTransitionPlugin<C> {
+    fn build(&self, app: &mut App) {
+        app.add_systems(
+            RunFixedMainLoop,
+            run_camera_transition::<C>.in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop),
+        );
+    }
+}
+
+/// Moves a camera to [`target`](CameraTransition::target) over
+/// [`duration`](CameraTransition::duration) seconds, then replaces itself with the `C` controller.
+///
+/// The transition starts from the camera's [`Transform`] on the first frame it runs.
+/// Transitions created with [`CameraTransition::following`] recompute the target from the
+/// controller every frame, so they keep up with a controller whose target moves.
+/// Remove the previous controller when inserting this component, so the two don't fight
+/// over the camera, and make sure the [`CameraTransitionPlugin<C>`] has been added.
+#[derive(Component)]
+pub struct CameraTransition<C: Component> {
+    /// The [`Transform`] the camera is moved to, typically where the new controller will place it.
+    pub target: Transform,
+    /// The length of the transition in seconds.
+    pub duration: f32,
+    /// The easing applied to the progress of the transition.
+    pub ease: EaseFunction,
+    controller: Option<C>,
+    follow: Option<fn(&C) -> Transform>,
+    start: Option<Transform>,
+    elapsed: f32,
+}
+
+impl<C: Component> CameraTransition<C> {
+    /// The default [`duration`](CameraTransition::duration) of a transition, in seconds.
+    pub const DEFAULT_DURATION: f32 = 0.5;
+
+    /// Creates a transition to `target` that inserts `controller` once it finishes.
+    pub fn new(target: Transform, controller: C) -> Self {
+        Self {
+            target,
+            duration: Self::DEFAULT_DURATION,
+            ease: EaseFunction::SmoothStep,
+            controller: Some(controller),
+            follow: None,
+            start: None,
+            elapsed: 0.0,
+        }
+    }
+
+    /// Creates a transition that inserts `controller` once it finishes, moving the camera to
+    /// the [`Transform`] returned by `target` for the controller.
+    ///
+    /// The [`target`](CameraTransition::target) is recomputed every frame, so changes made
+    /// through [`CameraTransition::controller_mut`] during the transition, such as moving the
+    /// point an orbit camera looks at, are followed without the camera jumping at the end.
+    pub fn following(controller: C, target: fn(&C) -> Transform) -> Self {
+        let mut transition = Self::new(target(&controller), controller);
+        transition.follow = Some(target);
+        transition
+    }
+
+    /// Returns the controller that is inserted once the transition finishes.
+    pub fn controller(&self) -> Option<&C> {
+        self.controller.as_ref()
+    }
+
+    /// Returns a mutable reference to the controller that is inserted once the transition
+    /// finishes, so it can be updated while the camera is still moving.
+    pub fn controller_mut(&mut self) -> Option<&mut C> {
+        self.controller.as_mut()
+    }
+
+    /// Returns this transition with the given [`duration`](CameraTransition::duration) in seconds.
+    pub fn with_duration(mut self, duration: f32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// Returns this transition with the given [`ease`](CameraTransition::ease) function.
+    pub fn with_ease(mut self, ease: EaseFunction) -> Self {
+        self.ease = ease;
+        self
+    }
+}
+
+/// This system is typically added via the [`CameraTransitionPlugin`].
+///
+/// Advances each [`CameraTransition<C>`], and swaps it for its controller once it's complete.
+///
+/// Systems that update the controller of a transition should run before this one.
+pub fn run_camera_transition<C: Component>(
+    mut commands: Commands,
+    time: Res<Time<Real>>,
+    mut query: Query<(Entity, &mut Transform, &mut CameraTransition<C>)>,
+) {
+    for (entity, mut transform, mut transition) in &mut query {
+        let transition = &mut *transition;
+        if let (Some(follow), Some(controller)) = (transition.follow, &transition.controller) {
+            transition.target = follow(controller);
+        }
+
+        let start = *transition.start.get_or_insert(*transform);
+        transition.elapsed += time.delta_secs();
+
+        let progress = if transition.duration > 0.0 {
+            (transition.elapsed / transition.duration).min(1.0)
+        } else {
+            1.0
+        };
+        let t = transition.ease.sample_clamped(progress);
+        let target = transition.target;
+        transform.translation = start.translation.lerp(target.translation, t);
+        transform.rotation = start.rotation.slerp(target.rotation, t);
+        transform.scale = start.scale.lerp(target.scale, t);
+
+        if progress >= 1.0 {
+            let mut entity_commands = commands.entity(entity);
+            entity_commands.remove::<CameraTransition<C>>();
+            if let Some(controller) = transition.controller.take() {
+                entity_commands.insert(controller);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use core::time::Duration;
+
+    use bevy_ecs::system::RunSystemOnce;
+    use bevy_math::Vec3;
+
+    use super::*;
+
+    #[derive(Component)]
+    struct LookAt(Vec3);
+
+    fn look_at_transform(look_at: &LookAt) -> Transform {
+        Transform::from_translation(look_at.0)
+    }
+
+    #[test]
+    fn following_transition_tracks_controller() {
+        let mut world = World::new();
+        world.init_resource::<Time<Real>>();
+        let camera = world
+            .spawn((
+                Transform::default(),
+                CameraTransition::following(LookAt(Vec3::X), look_at_transform)
+                    .with_duration(1.0)
+                    .with_ease(EaseFunction::Linear),
+            ))
+            .id();
+
+        let step = |world: &mut World, seconds: f32| {
+            world
+                .resource_mut::<Time<Real>>()
+                .advance_by(Duration::from_secs_f32(seconds));
+            world
+                .run_system_once(run_camera_transition::<LookAt>)
+                .unwrap();
+        };
+
+        step(&mut world, 0.5);
+        let translation = world.get::<Transform>(camera).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::X * 0.5, 1e-5));
+
+        // The target moves while the camera is on its way.
+        world
+            .get_mut::<CameraTransition<LookAt>>(camera)
+            .unwrap()
+            .controller_mut()
+            .unwrap()
+            .0 = Vec3::X * 3.0;
+        step(&mut world, 0.5);
+
+        let translation = world.get::<Transform>(camera).unwrap().translation;
+        assert!(translation.abs_diff_eq(Vec3::X * 3.0, 1e-5));
+        assert!(world.get::<CameraTransition<LookAt>>(camera).is_none());
+        assert_eq!(world.get::<LookAt>(camera).unwrap().0, Vec3::X * 3.0);
+    }
+}
@@ -0,0 +1,117 @@
+use alloc::format;
+use core::{any::type_name, marker::PhantomData};
+
+use bevy_app::prelude::*;
+use bevy_ecs::{
+    message::{Message, Messages},
+    system::Res,
+};
+
+use crate::{
+    Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic, DEFAULT_MAX_HISTORY_LENGTH,
+};
+
+/// Adds diagnostics tracking the size of the [`Messages<M>`] buffers to an App.
+///
+/// Two diagnostics are measured at the end of every frame, in [`Last`]:
+/// - [`count_path`](Self::count_path): the number of messages written since the last
+///   [`Messages::update`], which is the number of messages written this frame.
+/// - [`capacity_path`](Self::capacity_path): the number of messages the double buffer can
+///   hold without reallocating, see [`Messages::capacity`].
+///
+/// The paths contain the type name of `M`, so the plugin can be added for several message types.
+/// Nothing is measured while the [`Messages<M>`] resource doesn't exist.
+///
+/// # See also
+///
+/// [`LogDiagnosticsPlugin`](crate::LogDiagnosticsPlugin) to output diagnostics to the console.
+pub struct MessageDiagnosticsPlugin<M: Message> {
+    /// The total number of values to keep.
+    pub max_history_length: usize,
+    _marker: PhantomData<fn() -> M>,
+}
+
+impl<M: Message> Default for MessageDiagnosticsPlugin<M> {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_HISTORY_LENGTH)
+    }
+}
+
+impl<M: Message> MessageDiagnosticsPlugin<M> {
+    /// Creates a new `MessageDiagnosticsPlugin` with the specified `max_history_length`.
+    pub fn new(max_history_length: usize) -> Self {
+        Self {
+            max_history_length,
+            _marker: PhantomData,
+        }
+    }
+
+    /// The path of the diagnostic counting the messages of type `M` written this frame.
+    pub fn count_path() -> DiagnosticPath {
+        DiagnosticPath::new(format!("messages/{}/count", type_name::<M>()))
+    }
+
+    /// The path of the diagnostic measuring the capacity of the [`Messages<M>`] buffers.
+    pub fn capacity_path() -> DiagnosticPath {
+        DiagnosticPath::new(format!("messages/{}/capacity", type_name::<M>()))
+    }
+}
+
+impl<M: Message> Plugin for MessageDiagnosticsPlugin<M> {
+    fn build(&self, app: &mut App) {
+        let count_path = Self::count_path();
+        let capacity_path = Self::capacity_path();
+
+        app.register_diagnostic(
+            Diagnostic::new(count_path.clone()).with_max_history_length(self.max_history_length),
+        )
+        .register_diagnostic(
+            Diagnostic::new(capacity_path.clone()).with_max_history_length(self.max_history_length),
+        )
+        .add_systems(
+            Last,
+            move |mut diagnostics: Diagnostics, messages: Option<Res<Messages<M>>>| {
+                let Some(messages) = messages else {
+                    return;
+                };
+                diagnostics.add_measurement(&count_path, || {
+                    messages.iter_current_update_messages().len() as f64
+                });
+                diagnostics.add_measurement(&capacity_path, || messages.capacity() as f64);
+            },
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::DiagnosticsStore;
+    use bevy_ecs::message::MessageWriter;
+
+    #[derive(Message)]
+    struct TestMessage;
+
+    #[test]
+    fn message_diagnostics() {
+        let mut app = App::new();
+        app.add_message::<TestMessage>()
+            .add_plugins(MessageDiagnosticsPlugin::<TestMessage>::default())
+            .add_systems(Update, |mut messages: MessageWriter<TestMessage>| {
+                messages.write_batch((0..5).map(|_| TestMessage));
+            });
+
+        app.update();
+
+        let store = app.world().resource::<DiagnosticsStore>();
+        let count = store
+            .get(&MessageDiagnosticsPlugin::<TestMessage>::count_path())
+            .and_then(Diagnostic::value);
+        assert_eq!(count, Some(5.0));
+        let capacity = store
+            .get(&MessageDiagnosticsPlugin::<TestMessage>::capacity_path())
+            .and_then(Diagnostic::value)
+            .unwrap();
+        assert!(capacity >= 5.0);
+    }
+}
@@ -77,7 +77,8 @@ pub mod prelude {
         hierarchy::{ChildOf, ChildSpawner, ChildSpawnerCommands, Children},
         lifecycle::{Add, Despawn, Insert, Remove, RemovedComponents, Replace},
         message::{
-            Message, MessageMutator, MessageReader, MessageWriter, Messages, PopulatedMessageReader,
+            Message, MessageMutator, MessageReader, MessageWriter, Messages,
+            PopulatedMessageReader, ThresholdMessageReader,
         },
         name::{Name, NameOrEntity},
         observer::{Observer, ObserverSystemExt, On},
@@ -41,6 +41,49 @@ impl<'a, M: Message> ExactSizeIterator for MessageIterator<'a, M> {
     }
 }
 
+/// An iterator that yields and consumes all unread messages from a [`MessageReader`](super::MessageReader).
+///
+/// Any messages that have not been yielded when this iterator is dropped are still marked as read.
+///
+/// Created by [`MessageReader::drain`](super::MessageReader::drain).
+#[derive(Debug)]
+pub struct MessageDrain<'a, M: Message> {
+    iter: MessageIteratorWithId<'a, M>,
+}
+
+impl<'a, M: Message> MessageDrain<'a, M> {
+    /// Creates a new draining iterator that consumes any `messages` that have not yet been seen by `reader`.
+    pub fn new(reader: &'a mut MessageCursor<M>, messages: &'a Messages<M>) -> Self {
+        Self {
+            iter: MessageIteratorWithId::new(reader, messages),
+        }
+    }
+}
+
+impl<'a, M: Message> Iterator for MessageDrain<'a, M> {
+    type Item = &'a M;
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(message, _)| message)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+}
+
+impl<'a, M: Message> ExactSizeIterator for MessageDrain<'a, M> {
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, M: Message> Drop for MessageDrain<'a, M> {
+    fn drop(&mut self) {
+        self.iter.reader.last_message_count += self.iter.unread;
+        self.iter.unread = 0;
+    }
+}
+
 /// An iterator that yields any unread messages (and their IDs) from a [`MessageReader`](super::MessageReader) or [`MessageCursor`].
 #[derive(Debug)]
 pub struct MessageIteratorWithId<'a, M: Message> {
@@ -1,13 +1,20 @@
 #[cfg(feature = "multi_threaded")]
 use crate::message::MessageParIter;
 use crate::{
-    message::{Message, MessageCursor, MessageIterator, MessageIteratorWithId, Messages},
+    message::{
+        Message, MessageCursor, MessageDrain, MessageId, MessageInstance, MessageIterator,
+        MessageIteratorWithId, Messages,
+    },
     system::{Local, Res, SystemParam, SystemParamValidationError},
 };
+use alloc::{format, vec::Vec};
 
 /// Reads [`Message`]s of type `T` in order and tracks which messages have already been read.
 ///
-/// Use [`PopulatedMessageReader<T>`] to skip the system if there are no messages.
+/// Use [`PopulatedMessageReader<T>`] to skip the system if there are no messages,
+/// or [`ThresholdMessageReader<T, N>`] to skip it if there are fewer than `N` messages.
+/// Use [`DedupMessageReader<T>`] to skip messages equal to the one read before them,
+/// or [`EdgeMessageReader<T, K>`] to skip messages whose key is equal to the previous one's.
 ///
 /// # Concurrency
 ///
@@ -35,6 +42,175 @@ impl<'w, 's, M: Message> MessageReader<'w, 's, M> {
         self.reader.read_with_id(&self.messages)
     }
 
+    /// Iterates over all the messages this [`MessageReader`] has not seen yet, consuming them.
+    ///
+    /// Unlike [`read`](Self::read), every unread message is consumed even if the returned
+    /// iterator is dropped before it is exhausted, so [`is_empty`](Self::is_empty) will
+    /// return `true` afterwards.
+    pub fn drain(&mut self) -> MessageDrain<'_, M> {
+        MessageDrain::new(&mut self.reader, &self.messages)
+    }
+
+    /// Consumes all the messages this [`MessageReader`] has not seen yet,
+    /// returning only the most recent one.
+    ///
+    /// This is useful for messages that describe a state where only the newest value matters.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Message)]
+    /// struct VolumeChanged(f32);
+    ///
+    /// fn apply_volume(mut messages: MessageReader<VolumeChanged>) {
+    ///     if let Some(VolumeChanged(volume)) = messages.read_latest() {
+    ///         // Apply the newest volume, ignoring any older changes
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(apply_volume);
+    /// ```
+    pub fn read_latest(&mut self) -> Option<&M> {
+        self.read().last()
+    }
+
+    /// Iterates over the messages this [`MessageReader`] has not seen yet,
+    /// yielding only those for which `predicate` returns `true`.
+    ///
+    /// Messages that don't match are still consumed, so they will not be
+    /// yielded by later reads either.
+    pub fn read_matching(
+        &mut self,
+        mut predicate: impl FnMut(&M) -> bool,
+    ) -> impl Iterator<Item = &M> {
+        self.read().filter(move |message| predicate(message))
+    }
+
+    /// Appends clones of the messages this [`MessageReader`] has not seen yet to `buffer`,
+    /// and marks them as read.
+    ///
+    /// `buffer` isn't cleared first. Keeping it in a [`Local`] and clearing it once its contents
+    /// have been handled avoids allocating a new [`Vec`] every time the system runs.
+    pub fn read_into(&mut self, buffer: &mut Vec<M>)
+    where
+        M: Clone,
+    {
+        buffer.extend(self.read().cloned());
+    }
+
+    /// Iterates over the messages this [`MessageReader`] has not seen yet in chunks of
+    /// `chunk_size` messages. The last chunk may be smaller.
+    ///
+    /// [`Messages`] is double buffered and stores each message alongside its id, so the messages
+    /// can't be handed out as contiguous slices; each chunk collects references to its messages
+    /// instead. Messages are marked as read as the chunks are produced, so stopping early leaves
+    /// the remaining messages unread.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `chunk_size` is 0.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// #
+    /// #[derive(Message)]
+    /// struct Particle(f32);
+    ///
+    /// fn upload_particles(mut messages: MessageReader<Particle>) {
+    ///     for chunk in messages.read_chunked(256) {
+    ///         // Upload up to 256 particles at once
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(upload_particles);
+    /// ```
+    pub fn read_chunked(&mut self, chunk_size: usize) -> impl Iterator<Item = Vec<&M>> {
+        assert!(chunk_size != 0, "chunk size must be non-zero");
+        let mut messages = self.read();
+        core::iter::from_fn(move || {
+            let chunk: Vec<&M> = messages.by_ref().take(chunk_size).collect();
+            (!chunk.is_empty()).then_some(chunk)
+        })
+    }
+
+    /// Like [`read`](Self::read), except also returning the sequence index of the messages.
+    ///
+    /// The index is the absolute position of the message among all messages of this type
+    /// ever written to [`Messages<M>`], the same value as [`MessageId::id`](super::MessageId::id).
+    /// Indices are contiguous across reads, even when those happen in different frames.
+    pub fn read_with_index(&mut self) -> impl Iterator<Item = (usize, &M)> {
+        self.read_with_id().map(|(message, id)| (id.id, message))
+    }
+
+    /// Iterates over the messages this [`MessageReader`] has not seen yet, newest first.
+    ///
+    /// Unlike [`read`](Self::read), all unread messages are marked as read as soon as this is called,
+    /// regardless of how much of the returned iterator is consumed.
+    pub fn read_rev(&mut self) -> impl DoubleEndedIterator<Item = &M> {
+        let messages: &Messages<M> = &self.messages;
+        let (a, b) = Self::unread(&self.reader, messages);
+        self.reader.last_message_count = messages.message_count;
+
+        // Both buffers are in write order, and `a` holds the older messages,
+        // so reversing their concatenation yields the newest messages first.
+        a.iter()
+            .chain(b.iter())
+            .rev()
+            .map(|instance| &instance.message)
+    }
+
+    /// Returns the `n`th message this [`MessageReader`] has not seen yet, counting from zero,
+    /// and marks it and every message before it as read.
+    ///
+    /// If there are `n` or fewer unread messages, this returns `None`
+    /// and all of them are marked as read.
+    /// Use [`peek_nth`](Self::peek_nth) to look ahead without consuming anything.
+    pub fn nth(&mut self, n: usize) -> Option<&M> {
+        self.read().nth(n)
+    }
+
+    /// Returns the `n`th message this [`MessageReader`] has not seen yet, counting from zero,
+    /// without marking any messages as read.
+    pub fn peek_nth(&self, n: usize) -> Option<&M> {
+        let (a, b) = Self::unread(&self.reader, &self.messages);
+        a.iter()
+            .chain(b.iter())
+            .nth(n)
+            .map(|instance| &instance.message)
+    }
+
+    /// Returns the ids of the oldest and the newest message this [`MessageReader`] has not seen yet,
+    /// without marking any messages as read.
+    ///
+    /// Unread messages always have contiguous ids, so this describes all of them, even when they
+    /// were written in different frames. Returns `None` if there are no unread messages.
+    pub fn unread_id_range(&self) -> Option<(MessageId<M>, MessageId<M>)> {
+        let (a, b) = Self::unread(&self.reader, &self.messages);
+        let first = a.first().or(b.first())?;
+        let last = b.last().or(a.last())?;
+        Some((first.message_id, last.message_id))
+    }
+
+    /// Returns the messages `reader` has not seen yet, split between the older and the newer
+    /// buffer of `messages`.
+    fn unread<'a>(
+        reader: &MessageCursor<M>,
+        messages: &'a Messages<M>,
+    ) -> (&'a [MessageInstance<M>], &'a [MessageInstance<M>]) {
+        let a_index = reader
+            .last_message_count
+            .saturating_sub(messages.messages_a.start_message_count);
+        let b_index = reader
+            .last_message_count
+            .saturating_sub(messages.messages_b.start_message_count);
+        (
+            messages.messages_a.get(a_index..).unwrap_or_default(),
+            messages.messages_b.get(b_index..).unwrap_or_default(),
+        )
+    }
+
     /// Returns a parallel iterator over the messages this [`MessageReader`] has not seen yet.
     /// See also [`for_each`](MessageParIter::for_each).
     ///
@@ -195,9 +371,213 @@ unsafe impl<'w, 's, M: Message> SystemParam for PopulatedMessageReader<'w, 's, M
     }
 }
 
+/// Reads [`Message`]s of type `T` in order and tracks which messages have already been read.
+/// Skips the system if there are fewer than `N` messages.
+///
+/// This is useful for batch processing systems that are only worth running once enough messages
+/// have been queued up. Messages are not consumed while the system is skipped, so they keep
+/// accumulating until the threshold is reached.
+///
+/// Use [`PopulatedMessageReader<T>`] to skip the system only if there are no messages.
+#[derive(Debug)]
+pub struct ThresholdMessageReader<'w, 's, M: Message, const N: usize>(MessageReader<'w, 's, M>);
+
+impl<'w, 's, M: Message, const N: usize> core::ops::Deref for ThresholdMessageReader<'w, 's, M, N> {
+    type Target = MessageReader<'w, 's, M>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+impl<'w, 's, M: Message, const N: usize> core::ops::DerefMut
+    for ThresholdMessageReader<'w, 's, M, N>
+{
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.0
+    }
+}
+
+// SAFETY: relies on MessageReader to uphold soundness requirements
+unsafe impl<'w, 's, M: Message, const N: usize> SystemParam
+    for ThresholdMessageReader<'w, 's, M, N>
+{
+    type State = <MessageReader<'w, 's, M> as SystemParam>::State;
+    type Item<'world, 'state> = ThresholdMessageReader<'world, 'state, M, N>;
+
+    fn init_state(world: &mut crate::prelude::World) -> Self::State {
+        MessageReader::<M>::init_state(world)
+    }
+
+    fn init_access(
+        state: &Self::State,
+        system_meta: &mut crate::system::SystemMeta,
+        component_access_set: &mut crate::query::FilteredAccessSet,
+        world: &mut crate::prelude::World,
+    ) {
+        MessageReader::<M>::init_access(state, system_meta, component_access_set, world);
+    }
+
+    unsafe fn get_param<'world, 'state>(
+        state: &'state mut Self::State,
+        system_meta: &crate::system::SystemMeta,
+        world: crate::world::unsafe_world_cell::UnsafeWorldCell<'world>,
+        change_tick: crate::change_detection::Tick,
+    ) -> Self::Item<'world, 'state> {
+        // SAFETY: requirements are upheld by MessageReader's implementation
+        unsafe {
+            ThresholdMessageReader(MessageReader::get_param(
+                state,
+                system_meta,
+                world,
+                change_tick,
+            ))
+        }
+    }
+
+    unsafe fn validate_param(
+        state: &mut Self::State,
+        system_meta: &crate::system::SystemMeta,
+        world: crate::world::unsafe_world_cell::UnsafeWorldCell,
+    ) -> Result<(), SystemParamValidationError> {
+        // SAFETY: requirements are upheld by MessageReader's implementation
+        unsafe { MessageReader::<M>::validate_param(state, system_meta, world) }?;
+
+        // SAFETY: requirements are upheld by MessageReader's implementation
+        let reader =
+            unsafe { MessageReader::get_param(state, system_meta, world, world.change_tick()) };
+        if reader.len() < N {
+            Err(SystemParamValidationError::skipped::<Self>(format!(
+                "message queue has fewer than {N} messages"
+            )))
+        } else {
+            Ok(())
+        }
+    }
+}
+
+/// Reads [`Message`]s of type `T` in order, skipping each message that is equal to the one read
+/// before it.
+///
+/// The last message read is remembered between system runs, so a message equal to the last one
+/// read in a previous run is skipped as well. Skipped messages are still consumed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{message::DedupMessageReader, prelude::*};
+/// #
+/// #[derive(Message, Clone, PartialEq)]
+/// enum ConnectionState {
+///     Online,
+///     Offline,
+/// }
+///
+/// fn log_transitions(mut messages: DedupMessageReader<ConnectionState>) {
+///     for state in messages.read() {
+///         // Only runs when the state actually changes
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(log_transitions);
+/// ```
+#[derive(SystemParam, Debug)]
+pub struct DedupMessageReader<'w, 's, M: Message> {
+    reader: MessageReader<'w, 's, M>,
+    last: Local<'s, Option<M>>,
+}
+
+impl<'w, 's, M: Message + PartialEq + Clone> DedupMessageReader<'w, 's, M> {
+    /// Iterates over the messages this [`DedupMessageReader`] has not seen yet,
+    /// skipping each message that is equal to the one yielded before it.
+    pub fn read(&mut self) -> impl Iterator<Item = &M> {
+        let last = &mut *self.last;
+        self.reader.read().filter(move |message| {
+            if last.as_ref() == Some(*message) {
+                return false;
+            }
+            *last = Some((*message).clone());
+            true
+        })
+    }
+
+    /// Determines the number of messages available to be read, including the ones
+    /// [`read`](Self::read) would skip, without consuming any.
+    pub fn len(&self) -> usize {
+        self.reader.len()
+    }
+
+    /// Returns `true` if there are no messages available to read.
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty()
+    }
+}
+
+/// Reads [`Message`]s of type `T` in order, yielding only those whose key of type `K` differs
+/// from the key of the message yielded before it.
+///
+/// Unlike [`DedupMessageReader`], which compares whole messages, this lets the caller choose what
+/// counts as a change. The last key is remembered between system runs, so the first message of a
+/// run is only yielded if its key differs from the last one yielded in a previous run. Skipped
+/// messages are still consumed.
+///
+/// # Example
+///
+/// ```
+/// # use bevy_ecs::{message::EdgeMessageReader, prelude::*};
+/// #
+/// #[derive(Message)]
+/// struct JumpButton {
+///     pressed: bool,
+/// }
+///
+/// fn jump(mut messages: EdgeMessageReader<JumpButton, bool>) {
+///     // Only the transitions to pressed, ignoring repeated presses
+///     for _ in messages.read(|button| button.pressed).filter(|button| button.pressed) {
+///         // Jump
+///     }
+/// }
+/// # bevy_ecs::system::assert_is_system(jump);
+/// ```
+#[derive(SystemParam, Debug)]
+pub struct EdgeMessageReader<'w, 's, M: Message, K: PartialEq + Send + 'static> {
+    reader: MessageReader<'w, 's, M>,
+    last_key: Local<'s, Option<K>>,
+}
+
+impl<'w, 's, M: Message, K: PartialEq + Send + 'static> EdgeMessageReader<'w, 's, M, K> {
+    /// Iterates over the messages this [`EdgeMessageReader`] has not seen yet,
+    /// yielding only those whose `key` differs from the key of the message yielded before it.
+    ///
+    /// The same `key` function should be used every time the system runs, since the
+    /// remembered key is compared with the keys it returns.
+    pub fn read(&mut self, key: impl Fn(&M) -> K) -> impl Iterator<Item = &M> {
+        let last_key = &mut *self.last_key;
+        self.reader.read().filter(move |message| {
+            let key = key(message);
+            if last_key.as_ref() == Some(&key) {
+                return false;
+            }
+            *last_key = Some(key);
+            true
+        })
+    }
+
+    /// Determines the number of messages available to be read, including the ones
+    /// [`read`](Self::read) would skip, without consuming any.
+    pub fn len(&self) -> usize {
+        self.reader.len()
+    }
+
+    /// Returns `true` if there are no messages available to read.
+    pub fn is_empty(&self) -> bool {
+        self.reader.is_empty()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use core::sync::atomic::{AtomicBool, Ordering};
+    use alloc::{vec, vec::Vec};
+    use core::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
 
     use super::*;
     use crate::message::MessageRegistry;
@@ -235,4 +615,430 @@ mod tests {
         #[derive(Message)]
         struct TheMessage;
     }
+
+    #[test]
+    fn test_threshold_message_reader() {
+        let system_runs = Arc::new(AtomicUsize::new(0));
+
+        let mut world = World::new();
+        MessageRegistry::register_message::<TheMessage>(&mut world);
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems({
+            let system_runs = system_runs.clone();
+            move |mut reader: ThresholdMessageReader<TheMessage, 3>| {
+                reader.clear();
+                system_runs.fetch_add(1, Ordering::SeqCst);
+            }
+        });
+
+        schedule.run(&mut world);
+        assert_eq!(
+            system_runs.load(Ordering::SeqCst),
+            0,
+            "system with ThresholdMessageReader should have been skipped"
+        );
+
+        world.write_message(TheMessage);
+        world.write_message(TheMessage);
+        schedule.run(&mut world);
+        assert_eq!(
+            system_runs.load(Ordering::SeqCst),
+            0,
+            "system with ThresholdMessageReader should have been skipped"
+        );
+
+        world.write_message(TheMessage);
+        schedule.run(&mut world);
+        assert_eq!(
+            system_runs.load(Ordering::SeqCst),
+            1,
+            "system with ThresholdMessageReader should NOT have been skipped"
+        );
+
+        schedule.run(&mut world);
+        assert_eq!(
+            system_runs.load(Ordering::SeqCst),
+            1,
+            "system with ThresholdMessageReader should have been skipped after consuming messages"
+        );
+
+        #[derive(Message)]
+        struct TheMessage;
+    }
+
+    #[test]
+    fn test_message_reader_read_latest() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(
+            |mut messages: MessageReader<TestMessage>| -> (Option<TestMessage>, bool) {
+                (messages.read_latest().copied(), messages.is_empty())
+            },
+        );
+        reader.initialize(&mut world);
+
+        assert_eq!(reader.run((), &mut world).unwrap(), (None, true));
+
+        world.write_message(TestMessage(0));
+        world.write_message(TestMessage(1));
+        world.write_message(TestMessage(2));
+        assert_eq!(
+            reader.run((), &mut world).unwrap(),
+            (Some(TestMessage(2)), true)
+        );
+
+        assert_eq!(reader.run((), &mut world).unwrap(), (None, true));
+    }
+
+    #[test]
+    fn test_message_reader_drain() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+        world.write_message(TestMessage(0));
+        world.write_message(TestMessage(1));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut messages: MessageReader<TestMessage>| {
+            let drained: Vec<_> = messages.drain().copied().collect();
+            assert_eq!(drained, [TestMessage(0), TestMessage(1)]);
+            assert!(messages.is_empty());
+        });
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn test_message_reader_drain_dropped_early() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+        world.write_message(TestMessage(0));
+        world.write_message(TestMessage(1));
+        world.write_message(TestMessage(2));
+
+        let mut schedule = Schedule::default();
+        schedule.add_systems(|mut messages: MessageReader<TestMessage>| {
+            let mut drain = messages.drain();
+            assert_eq!(drain.len(), 3);
+            assert_eq!(drain.next(), Some(&TestMessage(0)));
+            drop(drain);
+            assert!(messages.is_empty());
+            assert_eq!(messages.read().next(), None);
+        });
+        schedule.run(&mut world);
+    }
+
+    #[test]
+    fn test_message_reader_read_matching() {
+        #[derive(Clone, Copy, Debug, PartialEq)]
+        enum Kind {
+            Hit,
+            Miss,
+        }
+
+        #[derive(Message)]
+        struct Shot(Kind, usize);
+
+        let mut world = World::new();
+        world.init_resource::<Messages<Shot>>();
+
+        let mut reader = IntoSystem::into_system(|mut messages: MessageReader<Shot>| {
+            messages
+                .read_matching(|Shot(kind, _)| *kind == Kind::Hit)
+                .map(|Shot(_, i)| *i)
+                .collect::<Vec<_>>()
+        });
+        reader.initialize(&mut world);
+
+        world.write_message(Shot(Kind::Hit, 0));
+        world.write_message(Shot(Kind::Miss, 1));
+        world.write_message(Shot(Kind::Hit, 2));
+        world.write_message(Shot(Kind::Miss, 3));
+        assert_eq!(reader.run((), &mut world).unwrap(), [0, 2]);
+
+        // the skipped messages were consumed as well
+        assert_eq!(reader.run((), &mut world).unwrap(), []);
+    }
+
+    #[test]
+    fn test_message_reader_read_with_index() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(|mut messages: MessageReader<TestMessage>| {
+            messages
+                .read_with_index()
+                .map(|(index, TestMessage(i))| (index, *i))
+                .collect::<Vec<_>>()
+        });
+        reader.initialize(&mut world);
+
+        world.write_message(TestMessage(10));
+        world.write_message(TestMessage(11));
+        assert_eq!(reader.run((), &mut world).unwrap(), [(0, 10), (1, 11)]);
+
+        world.resource_mut::<Messages<TestMessage>>().update();
+        world.write_message(TestMessage(12));
+        assert_eq!(reader.run((), &mut world).unwrap(), [(2, 12)]);
+
+        world.resource_mut::<Messages<TestMessage>>().update();
+        world.resource_mut::<Messages<TestMessage>>().update();
+        world.write_message(TestMessage(13));
+        world.write_message(TestMessage(14));
+        assert_eq!(reader.run((), &mut world).unwrap(), [(3, 13), (4, 14)]);
+    }
+
+    #[test]
+    fn test_message_reader_read_rev() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(|mut messages: MessageReader<TestMessage>| {
+            let read = messages
+                .read_rev()
+                .map(|TestMessage(i)| *i)
+                .collect::<Vec<_>>();
+            (read, messages.is_empty())
+        });
+        reader.initialize(&mut world);
+
+        // spread the messages over both message buffers
+        for i in 0..3 {
+            world.write_message(TestMessage(i));
+        }
+        world.resource_mut::<Messages<TestMessage>>().update();
+        for i in 3..5 {
+            world.write_message(TestMessage(i));
+        }
+
+        assert_eq!(
+            reader.run((), &mut world).unwrap(),
+            (vec![4, 3, 2, 1, 0], true)
+        );
+        assert_eq!(reader.run((), &mut world).unwrap(), (vec![], true));
+    }
+
+    #[test]
+    fn test_message_reader_nth() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(
+            |In(n): In<usize>, mut messages: MessageReader<TestMessage>| {
+                (messages.nth(n).copied(), messages.len())
+            },
+        );
+        reader.initialize(&mut world);
+
+        // spread the messages over both message buffers
+        for i in 0..3 {
+            world.write_message(TestMessage(i));
+        }
+        world.resource_mut::<Messages<TestMessage>>().update();
+        for i in 3..5 {
+            world.write_message(TestMessage(i));
+        }
+
+        assert_eq!(
+            reader.run(1, &mut world).unwrap(),
+            (Some(TestMessage(1)), 3)
+        );
+        // the cursor now points into the newer buffer
+        assert_eq!(
+            reader.run(1, &mut world).unwrap(),
+            (Some(TestMessage(3)), 1)
+        );
+        // out of range consumes the remaining messages
+        assert_eq!(reader.run(5, &mut world).unwrap(), (None, 0));
+        assert_eq!(reader.run(0, &mut world).unwrap(), (None, 0));
+    }
+
+    #[test]
+    fn test_message_reader_peek_nth() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader =
+            IntoSystem::into_system(|In(n): In<usize>, messages: MessageReader<TestMessage>| {
+                (messages.peek_nth(n).copied(), messages.len())
+            });
+        reader.initialize(&mut world);
+
+        for i in 0..3 {
+            world.write_message(TestMessage(i));
+        }
+        world.resource_mut::<Messages<TestMessage>>().update();
+        for i in 3..5 {
+            world.write_message(TestMessage(i));
+        }
+
+        assert_eq!(
+            reader.run(0, &mut world).unwrap(),
+            (Some(TestMessage(0)), 5)
+        );
+        assert_eq!(
+            reader.run(2, &mut world).unwrap(),
+            (Some(TestMessage(2)), 5)
+        );
+        assert_eq!(
+            reader.run(4, &mut world).unwrap(),
+            (Some(TestMessage(4)), 5)
+        );
+        assert_eq!(reader.run(5, &mut world).unwrap(), (None, 5));
+    }
+
+    #[test]
+    fn test_message_reader_unread_id_range() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(
+            |In(skip): In<usize>, mut messages: MessageReader<TestMessage>| {
+                messages.read().take(skip).count();
+                messages
+                    .unread_id_range()
+                    .map(|(first, last)| (first.id, last.id))
+            },
+        );
+        reader.initialize(&mut world);
+
+        // Both the older and the newer buffer contain messages.
+        for i in 0..3 {
+            world.write_message(TestMessage(i));
+        }
+        world.resource_mut::<Messages<TestMessage>>().update();
+        for i in 3..5 {
+            world.write_message(TestMessage(i));
+        }
+
+        assert_eq!(reader.run(0, &mut world).unwrap(), Some((0, 4)));
+        assert_eq!(reader.run(1, &mut world).unwrap(), Some((1, 4)));
+        assert_eq!(reader.run(2, &mut world).unwrap(), Some((3, 4)));
+        assert_eq!(reader.run(2, &mut world).unwrap(), None);
+    }
+
+    #[test]
+    fn test_message_reader_read_into() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(
+            |mut messages: MessageReader<TestMessage>, mut buffer: Local<Vec<TestMessage>>| {
+                messages.read_into(&mut buffer);
+                (buffer.clone(), messages.is_empty())
+            },
+        );
+        reader.initialize(&mut world);
+
+        world.write_message(TestMessage(0));
+        world.write_message(TestMessage(1));
+        assert_eq!(
+            reader.run((), &mut world).unwrap(),
+            (vec![TestMessage(0), TestMessage(1)], true)
+        );
+
+        // the buffer keeps its contents, and only the new message is appended
+        world.write_message(TestMessage(2));
+        assert_eq!(
+            reader.run((), &mut world).unwrap(),
+            (vec![TestMessage(0), TestMessage(1), TestMessage(2)], true)
+        );
+    }
+
+    #[test]
+    fn test_message_reader_read_chunked() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader = IntoSystem::into_system(
+            |mut messages: MessageReader<TestMessage>| -> (Vec<Vec<usize>>, bool) {
+                let chunks = messages
+                    .read_chunked(4)
+                    .map(|chunk| chunk.iter().map(|message| message.0).collect())
+                    .collect();
+                (chunks, messages.is_empty())
+            },
+        );
+        reader.initialize(&mut world);
+
+        // the second chunk spans both message buffers
+        for i in 0..6 {
+            world.write_message(TestMessage(i));
+        }
+        world.resource_mut::<Messages<TestMessage>>().update();
+        for i in 6..10 {
+            world.write_message(TestMessage(i));
+        }
+
+        let (chunks, is_empty) = reader.run((), &mut world).unwrap();
+        assert_eq!(
+            chunks.iter().map(Vec::len).collect::<Vec<_>>(),
+            vec![4, 4, 2]
+        );
+        assert_eq!(chunks.concat(), (0..10).collect::<Vec<_>>());
+        assert!(is_empty);
+    }
+
+    #[derive(Message, Clone, Copy, Debug, PartialEq)]
+    struct TestMessage(usize);
+
+    #[test]
+    fn test_dedup_message_reader() {
+        let mut world = World::new();
+        world.init_resource::<Messages<TestMessage>>();
+
+        let mut reader =
+            IntoSystem::into_system(|mut messages: DedupMessageReader<TestMessage>| {
+                messages.read().map(|TestMessage(i)| *i).collect::<Vec<_>>()
+            });
+        reader.initialize(&mut world);
+
+        for i in [1, 1, 2, 2, 2, 3] {
+            world.write_message(TestMessage(i));
+        }
+        assert_eq!(reader.run((), &mut world).unwrap(), [1, 2, 3]);
+
+        // the last yielded message is remembered between runs
+        for i in [3, 3, 4, 3] {
+            world.write_message(TestMessage(i));
+        }
+        assert_eq!(reader.run((), &mut world).unwrap(), [4, 3]);
+        assert_eq!(reader.run((), &mut world).unwrap(), []);
+    }
+
+    #[test]
+    fn test_edge_message_reader() {
+        #[derive(Message)]
+        struct ButtonMessage {
+            id: usize,
+            pressed: bool,
+        }
+
+        let mut world = World::new();
+        world.init_resource::<Messages<ButtonMessage>>();
+
+        let mut reader =
+            IntoSystem::into_system(|mut messages: EdgeMessageReader<ButtonMessage, bool>| {
+                messages
+                    .read(|message| message.pressed)
+                    .map(|message| message.id)
+                    .collect::<Vec<_>>()
+            });
+        reader.initialize(&mut world);
+
+        for (id, pressed) in [false, true, true, false, false, true]
+            .into_iter()
+            .enumerate()
+        {
+            world.write_message(ButtonMessage { id, pressed });
+        }
+        assert_eq!(reader.run((), &mut world).unwrap(), [0, 1, 3, 5]);
+
+        // the last key is remembered between runs
+        for (id, pressed) in [(6, true), (7, true), (8, false)] {
+            world.write_message(ButtonMessage { id, pressed });
+        }
+        assert_eq!(reader.run((), &mut world).unwrap(), [8]);
+        assert_eq!(reader.run((), &mut world).unwrap(), []);
+    }
 }
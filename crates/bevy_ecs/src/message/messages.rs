@@ -243,6 +243,16 @@ impl<M: Message> Messages<M> {
         self.len() == 0
     }
 
+    /// Returns the number of messages both message buffers can hold without reallocating.
+    ///
+    /// The buffers keep their allocations when they are cleared by [`update`](Self::update),
+    /// so this reflects the peak number of messages written in recent updates, rather than
+    /// the number currently stored, see [`len`](Self::len).
+    #[inline]
+    pub fn capacity(&self) -> usize {
+        self.messages_a.capacity() + self.messages_b.capacity()
+    }
+
     /// Creates a draining iterator that removes all messages.
     pub fn drain(&mut self) -> impl Iterator<Item = M> + '_ {
         self.reset_start_message_count();
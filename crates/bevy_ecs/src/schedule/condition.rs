@@ -383,7 +383,7 @@ pub mod common_conditions {
     use crate::{
         change_detection::DetectChanges,
         lifecycle::RemovedComponents,
-        message::{Message, MessageReader},
+        message::{Message, MessageReader, Messages},
         prelude::{Component, Query, With},
         query::QueryFilter,
         resource::Resource,
@@ -871,6 +871,51 @@ pub mod common_conditions {
         reader.read().count() > 0
     }
 
+    /// Generates a [`SystemCondition`]-satisfying closure that returns `true`
+    /// if at least `n` messages of the given type are stored in [`Messages`].
+    ///
+    /// Unlike [`on_message`], this does not track which messages it has already seen,
+    /// so nothing is consumed and the gated system will still read every message.
+    /// Note that the count covers every message still held by the double buffer of [`Messages`],
+    /// including those written during the previous update.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use bevy_ecs::prelude::*;
+    /// # #[derive(Resource, Default)]
+    /// # struct Counter(usize);
+    /// # let mut app = Schedule::default();
+    /// # let mut world = World::new();
+    /// # world.init_resource::<Counter>();
+    /// # world.init_resource::<Messages<Collision>>();
+    /// app.add_systems(
+    ///     resolve_collisions.run_if(message_count_at_least::<Collision>(2)),
+    /// );
+    ///
+    /// #[derive(Message)]
+    /// struct Collision;
+    ///
+    /// fn resolve_collisions(mut messages: MessageReader<Collision>, mut counter: ResMut<Counter>) {
+    ///     counter.0 += messages.read().count();
+    /// }
+    ///
+    /// world.write_message(Collision);
+    ///
+    /// // Only one `Collision` is pending so `resolve_collisions` won't run
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 0);
+    ///
+    /// world.write_message(Collision);
+    ///
+    /// // Both `Collision` messages are still there for `resolve_collisions` to read
+    /// app.run(&mut world);
+    /// assert_eq!(world.resource::<Counter>().0, 2);
+    /// ```
+    pub fn message_count_at_least<M: Message>(n: usize) -> impl FnMut(Res<Messages<M>>) -> bool {
+        move |messages: Res<Messages<M>>| messages.len() >= n
+    }
+
     /// A [`SystemCondition`]-satisfying system that returns `true`
     /// if there are any entities with the given component type.
     ///
@@ -1,6 +1,6 @@
 //! Additional [`GizmoBuffer`] Functions -- Arrows
 //!
-//! Includes the implementation of [`GizmoBuffer::arrow`] and [`GizmoBuffer::arrow_2d`],
+//! Includes the implementation of [`GizmoBuffer::arrow`], [`GizmoBuffer::arrow_sized`] and [`GizmoBuffer::arrow_2d`],
 //! and assorted support items.
 
 use crate::{gizmos::GizmoBuffer, prelude::GizmoConfigGroup};
@@ -11,7 +11,7 @@ use bevy_color::{
 use bevy_math::{Quat, Vec2, Vec3, Vec3Swizzles};
 use bevy_transform::TransformPoint;
 
-/// A builder returned by [`GizmoBuffer::arrow`] and [`GizmoBuffer::arrow_2d`]
+/// A builder returned by [`GizmoBuffer::arrow`], [`GizmoBuffer::arrow_sized`] and [`GizmoBuffer::arrow_2d`]
 pub struct ArrowBuilder<'a, Config, Clear>
 where
     Config: GizmoConfigGroup,
@@ -23,6 +23,7 @@ where
     color: Color,
     double_ended: bool,
     tip_length: f32,
+    tip_width: Option<f32>,
 }
 
 impl<Config, Clear> ArrowBuilder<'_, Config, Clear>
@@ -50,6 +51,29 @@ where
         self
     }
 
+    /// Change the width of the tips to be `width`, independently of their length.
+    ///
+    /// By default the tips are angled at 45 degrees and `with_tip_length` sets the length of each tip line.
+    /// Once a width is set, the tip length is instead the length of the head along the arrow.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.arrow(Vec3::ZERO, Vec3::ONE, GREEN)
+    ///         .with_tip_length(0.3)
+    ///         .with_tip_width(0.1);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[doc(alias = "arrow_head_width")]
+    pub fn with_tip_width(mut self, width: f32) -> Self {
+        self.tip_width = Some(width);
+        self
+    }
+
     /// Adds another tip to the arrow, appended in the start point.
     /// the default is only one tip at the end point.
     pub fn with_double_end(mut self) -> Self {
@@ -80,10 +104,15 @@ where
             Vec3::new(-1., -1., 0.),
             Vec3::new(-1., 0., -1.),
         ];
-        // - extend the vectors so their length is `tip_length`
+        // - extend the vectors so their length is `tip_length`, or so that the head is
+        //   `tip_length` long and `tip_width` wide if a width was set
         // - rotate the world so +x is facing in the same direction as the arrow
         // - translate over to the tip of the arrow
-        let tips_end = tips.map(|v| rotation_end * (v.normalize() * self.tip_length) + self.end);
+        let tips = tips.map(|v| match self.tip_width {
+            None => v.normalize() * self.tip_length,
+            Some(width) => Vec3::new(-self.tip_length, v.y * width / 2., v.z * width / 2.),
+        });
+        let tips_end = tips.map(|v| rotation_end * v + self.end);
         for v in tips_end {
             // then actually draw the tips
             self.gizmos.line(self.end, v, self.color);
@@ -91,8 +120,7 @@ where
         if self.double_ended {
             let pointing_start = (self.start - self.end).normalize();
             let rotation_start = Quat::from_rotation_arc(Vec3::X, pointing_start);
-            let tips_start =
-                tips.map(|v| rotation_start * (v.normalize() * self.tip_length) + self.start);
+            let tips_start = tips.map(|v| rotation_start * v + self.start);
             for v in tips_start {
                 // draw the start points tips
                 self.gizmos.line(self.start, v, self.color);
@@ -132,9 +160,39 @@ where
             color: color.into(),
             double_ended: false,
             tip_length: length / 10.,
+            tip_width: None,
         }
     }
 
+    /// Draw an arrow in 3D, from `start` to `end`, with a head of a fixed size.
+    ///
+    /// Unlike [`GizmoBuffer::arrow`], the size of the head doesn't depend on the length of the arrow,
+    /// which is useful to draw many arrows of varying lengths with uniform heads.
+    /// `head_length` is measured along the arrow and `head_width` across it, both in world units.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.arrow_sized(Vec3::ZERO, Vec3::ONE, 0.2, 0.1, GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn arrow_sized(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        head_length: f32,
+        head_width: f32,
+        color: impl Into<Color>,
+    ) -> ArrowBuilder<'_, Config, Clear> {
+        self.arrow(start, end, color)
+            .with_tip_length(head_length)
+            .with_tip_width(head_width)
+    }
+
     /// Draw an arrow in 2D (on the xy plane), from `start` to `end`.
     ///
     /// # Example
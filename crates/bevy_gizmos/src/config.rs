@@ -73,6 +73,52 @@ impl Hash for GizmoLineStyle {
     }
 }
 
+/// The width of gizmo lines, either in pixels or in world units.
+#[derive(Copy, Clone, Debug, PartialEq, Reflect)]
+#[reflect(Default, PartialEq, Clone)]
+pub enum GizmoLineWidth {
+    /// The width in pixels.
+    ///
+    /// Lines keep the same thickness on screen regardless of their distance to the camera,
+    /// unless [`GizmoLineConfig::perspective`] is enabled.
+    Pixels(f32),
+    /// The width in world units.
+    ///
+    /// Lines get thinner with distance like regular geometry, and scale with the zoom of
+    /// orthographic cameras. [`GizmoLineConfig::perspective`] has no effect on these lines.
+    World(f32),
+}
+
+impl GizmoLineWidth {
+    /// Returns the width, in the units of the variant.
+    #[inline]
+    pub fn value(self) -> f32 {
+        match self {
+            GizmoLineWidth::Pixels(width) | GizmoLineWidth::World(width) => width,
+        }
+    }
+
+    /// Returns a mutable reference to the width, in the units of the variant.
+    #[inline]
+    pub fn value_mut(&mut self) -> &mut f32 {
+        match self {
+            GizmoLineWidth::Pixels(width) | GizmoLineWidth::World(width) => width,
+        }
+    }
+
+    /// Returns `true` if the width is in world units.
+    #[inline]
+    pub fn is_world(self) -> bool {
+        matches!(self, GizmoLineWidth::World(_))
+    }
+}
+
+impl Default for GizmoLineWidth {
+    fn default() -> Self {
+        GizmoLineWidth::Pixels(2.)
+    }
+}
+
 /// A trait used to create gizmo configs groups.
 ///
 /// Here you can store additional configuration for you gizmo group not covered by [`GizmoConfig`]
@@ -212,6 +258,8 @@ pub struct GizmoConfig {
     pub enabled: bool,
     /// Line settings.
     pub line: GizmoLineConfig,
+    /// Text settings.
+    pub text: GizmoTextConfig,
     /// How closer to the camera than real geometry the gizmos should be.
     ///
     /// In 2D this setting has no effect and is effectively always -1.
@@ -225,6 +273,13 @@ pub struct GizmoConfig {
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
     pub depth_bias: f32,
+    /// Near and far view depths, in world units, between which gizmos fade out.
+    ///
+    /// Lines and text are fully opaque closer than the near depth, and fully transparent
+    /// beyond the far depth. There is no fading if the far depth isn't greater than the near one.
+    ///
+    /// Defaults to `None`.
+    pub depth_fade: Option<(f32, f32)>,
     /// Describes which rendering layers gizmos will be rendered to.
     ///
     /// Gizmos will only be rendered to cameras with intersecting layers.
@@ -236,7 +291,9 @@ impl Default for GizmoConfig {
         Self {
             enabled: true,
             line: Default::default(),
+            text: Default::default(),
             depth_bias: 0.,
+            depth_fade: None,
             render_layers: Default::default(),
         }
     }
@@ -246,15 +303,16 @@ impl Default for GizmoConfig {
 #[derive(Clone, Reflect, Debug)]
 #[reflect(Clone, Default)]
 pub struct GizmoLineConfig {
-    /// Line width specified in pixels.
+    /// Line width, in pixels or in world units.
     ///
-    /// If `perspective` is `true` then this is the size in pixels at the camera's near plane.
+    /// If `perspective` is `true` then a width in pixels is the size at the camera's near plane.
     ///
-    /// Defaults to `2.0`.
-    pub width: f32,
+    /// Defaults to `GizmoLineWidth::Pixels(2.0)`.
+    pub width: GizmoLineWidth,
     /// Apply perspective to gizmo lines.
     ///
-    /// This setting only affects 3D, non-orthographic cameras.
+    /// This setting only affects 3D, non-orthographic cameras, and lines whose width is
+    /// in pixels. Lines with a [`GizmoLineWidth::World`] width always have perspective.
     ///
     /// Defaults to `false`.
     pub perspective: bool,
@@ -267,7 +325,7 @@ pub struct GizmoLineConfig {
 impl Default for GizmoLineConfig {
     fn default() -> Self {
         Self {
-            width: 2.,
+            width: GizmoLineWidth::default(),
             perspective: false,
             style: GizmoLineStyle::Solid,
             joints: GizmoLineJoint::None,
@@ -275,6 +333,22 @@ impl Default for GizmoLineConfig {
     }
 }
 
+/// A struct that stores configuration for gizmo text.
+///
+/// Only text drawn with the immediate mode [`Gizmos`](crate::gizmos::Gizmos) API is affected,
+/// retained [`Gizmo`](crate::retained::Gizmo)s draw their text with their own settings.
+#[derive(Clone, Reflect, Debug, Default)]
+#[reflect(Clone, Default)]
+pub struct GizmoTextConfig {
+    /// How closer to the camera than real geometry the text should be.
+    ///
+    /// Works like [`GizmoConfig::depth_bias`], which is used instead if this is `None`.
+    /// Set this to `Some(-1.0)` to always draw text in front of other things.
+    ///
+    /// Defaults to `None`.
+    pub depth_bias: Option<f32>,
+}
+
 /// Configuration for gizmo meshes.
 #[derive(Component)]
 pub struct GizmoMeshConfig {
@@ -18,7 +18,7 @@ use bevy_ecs::{
     },
     world::{unsafe_world_cell::UnsafeWorldCell, DeferredWorld, World},
 };
-use bevy_math::{bounding::Aabb3d, Isometry2d, Isometry3d, Vec2, Vec3};
+use bevy_math::{bounding::Aabb3d, ops, Isometry2d, Isometry3d, Vec2, Vec3};
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
 use bevy_transform::TransformPoint;
 use bevy_utils::default;
@@ -35,6 +35,8 @@ pub struct GizmoStorage<Config, Clear> {
     pub(crate) list_colors: Vec<LinearRgba>,
     pub(crate) strip_positions: Vec<Vec3>,
     pub(crate) strip_colors: Vec<LinearRgba>,
+    pub(crate) text_positions: Vec<Vec3>,
+    pub(crate) text_colors: Vec<LinearRgba>,
     marker: PhantomData<(Config, Clear)>,
 }
 
@@ -45,6 +47,8 @@ impl<Config, Clear> Default for GizmoStorage<Config, Clear> {
             list_colors: default(),
             strip_positions: default(),
             strip_colors: default(),
+            text_positions: default(),
+            text_colors: default(),
             marker: PhantomData,
         }
     }
@@ -64,6 +68,8 @@ where
         self.list_colors.extend(other.list_colors.iter());
         self.strip_positions.extend(other.strip_positions.iter());
         self.strip_colors.extend(other.strip_colors.iter());
+        self.text_positions.extend(other.text_positions.iter());
+        self.text_colors.extend(other.text_colors.iter());
     }
 
     pub(crate) fn swap<OtherConfig, OtherClear>(
@@ -74,6 +80,8 @@ where
         mem::swap(&mut self.list_colors, &mut other.list_colors);
         mem::swap(&mut self.strip_positions, &mut other.strip_positions);
         mem::swap(&mut self.strip_colors, &mut other.strip_colors);
+        mem::swap(&mut self.text_positions, &mut other.text_positions);
+        mem::swap(&mut self.text_colors, &mut other.text_colors);
     }
 
     /// Clear this gizmo storage of any requested gizmos.
@@ -82,6 +90,8 @@ where
         self.list_colors.clear();
         self.strip_positions.clear();
         self.strip_colors.clear();
+        self.text_positions.clear();
+        self.text_colors.clear();
     }
 }
 
@@ -321,6 +331,10 @@ where
     pub strip_positions: Vec<Vec3>,
     /// The colors of line strip vertices.
     pub strip_colors: Vec<LinearRgba>,
+    /// The positions of the line strip vertices of text, see [`GizmoBufferView::text_positions`].
+    pub(crate) text_positions: Vec<Vec3>,
+    /// The colors of the line strip vertices of text, see [`GizmoBufferView::text_colors`].
+    pub(crate) text_colors: Vec<LinearRgba>,
     #[reflect(ignore, clone)]
     pub(crate) marker: PhantomData<(Config, Clear)>,
 }
@@ -348,6 +362,8 @@ where
             list_colors: Vec::new(),
             strip_positions: Vec::new(),
             strip_colors: Vec::new(),
+            text_positions: Vec::new(),
+            text_colors: Vec::new(),
             marker: PhantomData,
         }
     }
@@ -363,6 +379,13 @@ pub struct GizmoBufferView<'a> {
     pub strip_positions: &'a Vec<Vec3>,
     /// Vertex colors for line-strip topology.
     pub strip_colors: &'a Vec<LinearRgba>,
+    /// Vertex positions for line-strip topology of text.
+    ///
+    /// Text is kept apart from the other line strips so the renderer can draw it with
+    /// the [`GizmoTextConfig`](crate::config::GizmoTextConfig) of its group.
+    pub text_positions: &'a Vec<Vec3>,
+    /// Vertex colors for line-strip topology of text.
+    pub text_colors: &'a Vec<LinearRgba>,
 }
 
 impl<Config, Clear> SystemBuffer for GizmoBuffer<Config, Clear>
@@ -376,6 +399,8 @@ where
             storage.list_colors.append(&mut self.list_colors);
             storage.strip_positions.append(&mut self.strip_positions);
             storage.strip_colors.append(&mut self.strip_colors);
+            storage.text_positions.append(&mut self.text_positions);
+            storage.text_colors.append(&mut self.text_colors);
         } else {
             // Prevent the buffer from growing indefinitely if GizmoStorage
             // for the config group has not been initialized
@@ -383,6 +408,8 @@ where
             self.list_colors.clear();
             self.strip_positions.clear();
             self.strip_colors.clear();
+            self.text_positions.clear();
+            self.text_colors.clear();
         }
     }
 }
@@ -392,12 +419,17 @@ where
     Config: GizmoConfigGroup,
     Clear: 'static + Send + Sync,
 {
+    /// The largest number of dashes [`GizmoBuffer::dashed_line`] splits a line into.
+    pub const MAX_DASHES: u32 = 10_000;
+
     /// Clear all data.
     pub fn clear(&mut self) {
         self.list_positions.clear();
         self.list_colors.clear();
         self.strip_positions.clear();
         self.strip_colors.clear();
+        self.text_positions.clear();
+        self.text_colors.clear();
     }
 
     /// Read-only view into the buffers data.
@@ -407,6 +439,8 @@ where
             list_colors,
             strip_positions,
             strip_colors,
+            text_positions,
+            text_colors,
             ..
         } = self;
         GizmoBufferView {
@@ -414,6 +448,8 @@ where
             list_colors,
             strip_positions,
             strip_colors,
+            text_positions,
+            text_colors,
         }
     }
     /// Draw a line in 3D from `start` to `end`.
@@ -437,6 +473,59 @@ where
         self.add_list_color(color, 2);
     }
 
+    /// Draw a dashed line in 3D from `start` to `end`.
+    ///
+    /// The line starts with a dash of `dash_length`, followed by a gap of `gap_length`, both in world units.
+    /// Unlike [`GizmoLineStyle`](crate::config::GizmoLineStyle), which applies to every line of a
+    /// config group, the dashes are split on the CPU and only affect this line.
+    /// A solid line is drawn if either length isn't positive, or if the line would be split into
+    /// more than [`GizmoBuffer::MAX_DASHES`] dashes, which would be too small to see anyway.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.dashed_line(Vec3::ZERO, Vec3::X, 0.1, 0.05, GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn dashed_line(
+        &mut self,
+        start: Vec3,
+        end: Vec3,
+        dash_length: f32,
+        gap_length: f32,
+        color: impl Into<Color>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        let color = color.into();
+        if dash_length <= 0. || gap_length <= 0. {
+            self.line(start, end, color);
+            return;
+        }
+        let length = start.distance(end);
+        let period = dash_length + gap_length;
+        let dash_count = ops::ceil(length / period);
+        if dash_count > Self::MAX_DASHES as f32 {
+            self.line(start, end, color);
+            return;
+        }
+        let direction = (end - start) / length;
+        for index in 0..dash_count as u32 {
+            let distance = index as f32 * period;
+            let dash_end = (distance + dash_length).min(length);
+            self.line(
+                start + direction * distance,
+                start + direction * dash_end,
+                color,
+            );
+        }
+    }
+
     /// Draw a line in 3D with a color gradient from `start` to `end`.
     ///
     /// # Example
@@ -754,6 +843,38 @@ where
         self.line(start.extend(0.), end.extend(0.), color);
     }
 
+    /// Draw a dashed line in 2D from `start` to `end`.
+    ///
+    /// See [`GizmoBuffer::dashed_line`] for how the dashes are laid out.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.dashed_line_2d(Vec2::ZERO, Vec2::X * 100., 10., 5., GREEN);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    #[inline]
+    pub fn dashed_line_2d(
+        &mut self,
+        start: Vec2,
+        end: Vec2,
+        dash_length: f32,
+        gap_length: f32,
+        color: impl Into<Color>,
+    ) {
+        self.dashed_line(
+            start.extend(0.),
+            end.extend(0.),
+            dash_length,
+            gap_length,
+            color,
+        );
+    }
+
     /// Draw a line in 2D with a color gradient from `start` to `end`.
     ///
     /// # Example
@@ -964,6 +1085,24 @@ where
         self.strip_positions.extend(positions);
         self.strip_positions.push(Vec3::NAN);
     }
+
+    /// Draw a line strip of text, which is rendered with the [`GizmoTextConfig`](crate::config::GizmoTextConfig).
+    #[inline]
+    pub(crate) fn text_strip(
+        &mut self,
+        positions: impl IntoIterator<Item = Vec3>,
+        color: impl Into<Color>,
+    ) {
+        if !self.enabled {
+            return;
+        }
+        self.text_positions.extend(positions);
+        self.text_positions.push(Vec3::NAN);
+        let len = self.text_positions.len();
+        let linear_color = LinearRgba::from(color.into());
+        self.text_colors.resize(len - 1, linear_color);
+        self.text_colors.push(LinearRgba::NAN);
+    }
 }
 
 fn rect_inner(size: Vec2) -> [Vec2; 4] {
@@ -974,3 +1113,35 @@ fn rect_inner(size: Vec2) -> [Vec2; 4] {
     let br = Vec2::new(half_size.x, -half_size.y);
     [tl, tr, br, bl]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn dashed_line_dash_count() {
+        let mut gizmos = GizmoBuffer::<DefaultGizmoConfigGroup, ()>::default();
+        gizmos.dashed_line(Vec3::ZERO, Vec3::X, 0.3, 0.2, Color::WHITE);
+        assert_eq!(
+            gizmos.list_positions,
+            [Vec3::ZERO, Vec3::X * 0.3, Vec3::X * 0.5, Vec3::X * 0.8]
+        );
+
+        // Dashes too small to see are drawn as a solid line instead of flooding the buffer.
+        gizmos.clear();
+        gizmos.dashed_line(Vec3::ZERO, Vec3::X * 1e6, 1e-3, 1e-3, Color::WHITE);
+        assert_eq!(gizmos.list_positions, [Vec3::ZERO, Vec3::X * 1e6]);
+
+        // Exactly the maximum number of dashes is still drawn dashed.
+        gizmos.clear();
+        let max_dashes = GizmoBuffer::<DefaultGizmoConfigGroup, ()>::MAX_DASHES;
+        gizmos.dashed_line(
+            Vec3::ZERO,
+            Vec3::X * max_dashes as f32,
+            0.5,
+            0.5,
+            Color::WHITE,
+        );
+        assert_eq!(gizmos.list_positions.len(), 2 * max_dashes as usize);
+    }
+}
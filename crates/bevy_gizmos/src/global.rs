@@ -30,6 +30,8 @@ fn flush_global_gizmos(mut gizmos: Gizmos) {
     gizmos.strip_colors.extend(buffer.strip_colors);
     gizmos.list_positions.extend(buffer.list_positions);
     gizmos.list_colors.extend(buffer.list_colors);
+    gizmos.text_positions.extend(buffer.text_positions);
+    gizmos.text_colors.extend(buffer.text_colors);
 }
 
 /// A global gizmo context for use outside of bevy systems.
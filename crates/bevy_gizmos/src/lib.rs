@@ -60,7 +60,7 @@ pub mod prelude {
     pub use crate::{
         config::{
             DefaultGizmoConfigGroup, GizmoConfig, GizmoConfigGroup, GizmoConfigStore,
-            GizmoLineConfig, GizmoLineJoint, GizmoLineStyle,
+            GizmoLineConfig, GizmoLineJoint, GizmoLineStyle, GizmoLineWidth, GizmoTextConfig,
         },
         gizmos::Gizmos,
         global::gizmo,
@@ -144,6 +144,7 @@ impl AppGizmoBuilder for App {
         let mut handles = self.world_mut().get_resource_or_init::<GizmoHandles>();
 
         handles.handles.insert(TypeId::of::<Config>(), None);
+        handles.text_handles.insert(TypeId::of::<Config>(), None);
 
         // These handles are safe to mutate in any order
         self.allow_ambiguous_resource::<GizmoHandles>();
@@ -197,6 +198,7 @@ impl AppGizmoBuilder for App {
 #[derive(Resource, Default)]
 pub struct GizmoHandles {
     handles: TypeIdMap<Option<Handle<GizmoAsset>>>,
+    text_handles: TypeIdMap<Option<Handle<GizmoAsset>>>,
 }
 
 impl GizmoHandles {
@@ -204,6 +206,15 @@ impl GizmoHandles {
     pub fn handles(&self) -> &TypeIdMap<Option<Handle<GizmoAsset>>> {
         &self.handles
     }
+
+    /// The handles to the gizmo assets holding the text of each gizmo configuration group.
+    ///
+    /// Text is kept separate from the other gizmos so it can be rendered with
+    /// the [`GizmoTextConfig`](config::GizmoTextConfig) of its group. These assets are
+    /// managed internally and read by the renderer, they shouldn't be modified directly.
+    pub fn text_handles(&self) -> &TypeIdMap<Option<Handle<GizmoAsset>>> {
+        &self.text_handles
+    }
 }
 
 /// Start a new gizmo clearing context.
@@ -283,6 +294,27 @@ fn update_gizmo_meshes<Config: GizmoConfigGroup>(
     mut handles: ResMut<GizmoHandles>,
     mut storage: ResMut<GizmoStorage<Config, ()>>,
 ) {
+    if storage.text_positions.is_empty() {
+        handles.text_handles.insert(TypeId::of::<Config>(), None);
+    } else if let Some(handle) = handles.text_handles.get_mut(&TypeId::of::<Config>()) {
+        if let Some(handle) = handle {
+            let gizmo = gizmo_assets.get_mut(handle.id()).unwrap();
+
+            gizmo.buffer.text_positions = mem::take(&mut storage.text_positions);
+            gizmo.buffer.text_colors = mem::take(&mut storage.text_colors);
+        } else {
+            let mut buffer = GizmoBuffer::new();
+            buffer.text_positions = mem::take(&mut storage.text_positions);
+            buffer.text_colors = mem::take(&mut storage.text_colors);
+            let gizmo = GizmoAsset {
+                config_ty: TypeId::of::<Config>(),
+                buffer,
+            };
+
+            *handle = Some(gizmo_assets.add(gizmo));
+        }
+    }
+
     if storage.list_positions.is_empty() && storage.strip_positions.is_empty() {
         handles.handles.insert(TypeId::of::<Config>(), None);
     } else if let Some(handle) = handles.handles.get_mut(&TypeId::of::<Config>()) {
@@ -302,6 +334,8 @@ fn update_gizmo_meshes<Config: GizmoConfigGroup>(
                     list_colors: mem::take(&mut storage.list_colors),
                     strip_positions: mem::take(&mut storage.strip_positions),
                     strip_colors: mem::take(&mut storage.strip_colors),
+                    text_positions: Vec::new(),
+                    text_colors: Vec::new(),
                     marker: PhantomData,
                 },
             };
@@ -52,7 +52,7 @@ impl DerefMut for GizmoAsset {
 ///     commands.spawn(Gizmo {
 ///         handle: gizmo_assets.add(gizmo),
 ///         line_config: GizmoLineConfig {
-///             width: 4.,
+///             width: GizmoLineWidth::Pixels(4.),
 ///             ..default()
 ///         },
 ///         ..default()
@@ -82,4 +82,11 @@ pub struct Gizmo {
     /// and your wireframe is z-fighting (flickering on/off) with your main model.
     /// You would set this value to a negative number close to 0.
     pub depth_bias: f32,
+    /// Near and far view depths, in world units, between which the gizmo fades out.
+    ///
+    /// Lines and text are fully opaque closer than the near depth, and fully transparent
+    /// beyond the far depth. There is no fading if the far depth isn't greater than the near one.
+    ///
+    /// Defaults to `None`.
+    pub depth_fade: Option<(f32, f32)>,
 }
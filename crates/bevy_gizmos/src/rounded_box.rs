@@ -1,6 +1,7 @@
 //! Additional [`GizmoBuffer`] Functions -- Rounded cuboids and rectangles
 //!
-//! Includes the implementation of [`GizmoBuffer::rounded_rect`], [`GizmoBuffer::rounded_rect_2d`] and [`GizmoBuffer::rounded_cuboid`].
+//! Includes the implementation of [`GizmoBuffer::rounded_rect`], [`GizmoBuffer::rounded_rect_2d`],
+//! [`GizmoBuffer::capsule_2d`] and [`GizmoBuffer::rounded_cuboid`].
 //! and assorted support items.
 
 use core::f32::consts::FRAC_PI_2;
@@ -10,7 +11,7 @@ use bevy_color::Color;
 use bevy_math::{Isometry2d, Isometry3d, Quat, Vec2, Vec3};
 use bevy_transform::components::Transform;
 
-/// A builder returned by [`GizmoBuffer::rounded_rect`] and [`GizmoBuffer::rounded_rect_2d`]
+/// A builder returned by [`GizmoBuffer::rounded_rect`], [`GizmoBuffer::rounded_rect_2d`] and [`GizmoBuffer::capsule_2d`]
 pub struct RoundedRectBuilder<'a, Config, Clear>
 where
     Config: GizmoConfigGroup,
@@ -338,6 +339,48 @@ where
         }
     }
 
+    /// Draw a wireframe capsule in 2D.
+    ///
+    /// The capsule is drawn as a rectangle whose corner radius is equal to its radius, so the
+    /// arcs of each cap are tessellated like the corners of [`GizmoBuffer::rounded_rect_2d`].
+    ///
+    /// # Arguments
+    ///
+    /// - `isometry` defines the translation and rotation of the capsule.
+    ///   - the translation specifies the center of the capsule
+    ///   - defines orientation of the capsule, by default we assume the capsule is aligned with the y-axis.
+    /// - `radius`: the radius of the caps.
+    /// - `half_length`: half the length of the straight section between the centers of the caps.
+    /// - `color`: color of the capsule
+    ///
+    /// # Builder methods
+    ///
+    /// - The resolution of the arcs (i.e. the level of detail) can be adjusted with the
+    ///   `.arc_resolution(...)` method. Each cap is made of two arcs with this resolution.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::css::GREEN;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.capsule_2d(Isometry2d::IDENTITY, 0.5, 1.0, GREEN)
+    ///         .arc_resolution(16);
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn capsule_2d(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        radius: f32,
+        half_length: f32,
+        color: impl Into<Color>,
+    ) -> RoundedRectBuilder<'_, Config, Clear> {
+        let size = Vec2::new(radius, half_length + radius) * 2.0;
+        self.rounded_rect_2d(isometry, size, color)
+            .corner_radius(radius)
+    }
+
     /// Draw a wireframe cuboid with rounded corners in 3D.
     ///
     /// # Arguments
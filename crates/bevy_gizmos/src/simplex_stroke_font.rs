@@ -1403,6 +1403,7 @@ pub(crate) const SIMPLEX_GLYPHS: [(i8, Range<usize>); 95] = [
     (24, 186..188),
 ];
 
+/// The built-in Simplex stroke font used by text gizmos.
 pub const SIMPLEX_STROKE_FONT: StrokeFont = StrokeFont {
     line_height: LINE_HEIGHT,
     advance: 16,
@@ -1,9 +1,11 @@
 //! This module draws text gizmos using a stroke font.
 
-use crate::simplex_stroke_font::*;
+pub use crate::simplex_stroke_font::SIMPLEX_STROKE_FONT;
 use crate::{gizmos::GizmoBuffer, prelude::GizmoConfigGroup};
+use bevy_camera::Camera;
 use bevy_color::Color;
-use bevy_math::{vec2, Isometry2d, Isometry3d, Vec2};
+use bevy_math::{vec2, Isometry2d, Isometry3d, Vec2, Vec3};
+use bevy_transform::components::GlobalTransform;
 use core::ops::Range;
 
 /// A stroke font containing glyphs for the 95 printable ASCII codes.
@@ -27,17 +29,19 @@ pub struct StrokeFont<'a> {
 impl<'a> StrokeFont<'a> {
     /// Builds a `StrokeTextLayout` for `text` at the requested `font_size`.
     pub fn layout(&'a self, text: &'a str, font_size: f32) -> StrokeTextLayout<'a> {
-        let scale = font_size / SIMPLEX_CAP_HEIGHT;
-        let glyph_height = SIMPLEX_HEIGHT * scale;
-        let line_height = LINE_HEIGHT * glyph_height;
+        let scale = font_size / self.cap_height;
+        let glyph_height = self.height * scale;
+        let line_height = self.line_height * glyph_height;
         let margin_top = line_height - glyph_height;
-        let space_advance = SIMPLEX_GLYPHS[0].0 as f32 * scale;
+        let space_advance = self.glyphs[0].0 as f32 * scale;
         StrokeTextLayout {
             font: self,
             scale,
             line_height,
+            line_advance: line_height,
             margin_top,
             space_advance,
+            justify: GizmoTextJustify::Left,
             text,
         }
     }
@@ -62,6 +66,98 @@ impl<'a> StrokeFont<'a> {
     }
 }
 
+/// The size of text drawn with [`GizmoBuffer::text_with_scale`].
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum GizmoTextScale {
+    /// The font size in world units, like [`GizmoBuffer::text`].
+    ///
+    /// Text drawn with a perspective projection gets smaller with distance.
+    World(f32),
+    /// The font size in logical pixels.
+    ///
+    /// The text keeps the same size on screen regardless of its distance to the camera.
+    Screen(f32),
+}
+
+impl GizmoTextScale {
+    /// Returns the font size in world units for text at `position` seen through `camera`.
+    ///
+    /// Returns `None` for [`GizmoTextScale::Screen`] if the position is behind a perspective camera
+    /// or the camera's viewport size isn't known yet. Orthographic cameras never cull the text, since
+    /// their near plane may lie behind the camera.
+    pub fn world_font_size(
+        self,
+        position: Vec3,
+        camera: &Camera,
+        camera_transform: &GlobalTransform,
+    ) -> Option<f32> {
+        let font_size = match self {
+            GizmoTextScale::World(font_size) => return Some(font_size),
+            GizmoTextScale::Screen(font_size) => font_size,
+        };
+
+        let viewport_height = camera.logical_viewport_size()?.y;
+        let clip_from_view = camera.clip_from_view();
+        // The height of the view volume in world units at the text's depth.
+        let view_height = if clip_from_view.w_axis.w == 1.0 {
+            // Orthographic projections have a constant view height.
+            2.0 / clip_from_view.y_axis.y
+        } else {
+            let view_position = camera_transform
+                .affine()
+                .inverse()
+                .transform_point3(position);
+            // Cameras look towards -Z in view space.
+            if view_position.z >= 0.0 {
+                return None;
+            }
+            2.0 * -view_position.z / clip_from_view.y_axis.y
+        };
+
+        Some(font_size * view_height / viewport_height)
+    }
+}
+
+/// The horizontal alignment of the lines within a block of multi-line text.
+///
+/// The anchor still positions the block as a whole; this only moves shorter lines
+/// relative to the longest one, so it has no effect on single-line text.
+///
+/// Set it through [`GizmoTextStyle::justify`], with [`GizmoBuffer::text_styled`] in 3D
+/// or [`GizmoBuffer::text_2d_styled`] in 2D.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum GizmoTextJustify {
+    /// Lines start at the left edge of the block.
+    #[default]
+    Left,
+    /// Lines are centered within the block.
+    Center,
+    /// Lines end at the right edge of the block.
+    Right,
+}
+
+/// Optional decorations and alignment for text drawn by [`GizmoBuffer::text_styled`]
+/// and [`GizmoBuffer::text_2d_styled`].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct GizmoTextStyle {
+    /// If set, the glyph strokes are drawn again, slightly offset in each diagonal direction,
+    /// in this color behind the text.
+    pub outline: Option<Color>,
+    /// If set, a rectangular frame around the text's bounding box is drawn in this color.
+    ///
+    /// Gizmos are made of lines, so this only outlines the box and doesn't fill it.
+    pub frame: Option<Color>,
+    /// The horizontal alignment of the lines of multi-line text.
+    pub justify: GizmoTextJustify,
+}
+
+impl GizmoTextStyle {
+    /// Offset of the outline strokes, relative to the font size.
+    const OUTLINE_OFFSET: f32 = 0.06;
+    /// Space between the text and the frame, relative to the font size.
+    const FRAME_PADDING: f32 = 0.25;
+}
+
 /// Stroke text layout
 pub struct StrokeTextLayout<'a> {
     /// The unscaled font
@@ -72,13 +168,33 @@ pub struct StrokeTextLayout<'a> {
     scale: f32,
     /// Height of each line of text.
     line_height: f32,
+    /// Distance between the tops of consecutive lines of text.
+    line_advance: f32,
     /// Space between top of line and cap height.
     margin_top: f32,
     /// Width of a space.
     space_advance: f32,
+    /// Horizontal alignment of the lines.
+    justify: GizmoTextJustify,
 }
 
 impl<'a> StrokeTextLayout<'a> {
+    /// Sets the distance between consecutive lines of text, as a multiple of the line height.
+    ///
+    /// Defaults to `1.0`.
+    pub fn with_line_spacing(mut self, line_spacing: f32) -> Self {
+        self.line_advance = self.line_height * line_spacing;
+        self
+    }
+
+    /// Sets the horizontal alignment of the lines within the text block.
+    ///
+    /// Defaults to [`GizmoTextJustify::Left`].
+    pub fn with_justify(mut self, justify: GizmoTextJustify) -> Self {
+        self.justify = justify;
+        self
+    }
+
     /// Computes the width and height of a text layout with this font and
     /// the given text.
     ///
@@ -91,29 +207,57 @@ impl<'a> StrokeTextLayout<'a> {
             if c == '\n' {
                 layout_size.x = layout_size.x.max(line_width);
                 line_width = 0.;
-                layout_size.y += self.line_height;
+                layout_size.y += self.line_advance;
                 continue;
             }
 
-            line_width += self
-                .font
-                .get_glyph_advance(c)
-                .map(|advance| advance as f32 * self.scale)
-                .unwrap_or(self.space_advance);
+            line_width += self.char_advance(c);
         }
 
         layout_size.x = layout_size.x.max(line_width);
         layout_size
     }
 
+    fn char_advance(&self, c: char) -> f32 {
+        self.font
+            .get_glyph_advance(c)
+            .map(|advance| advance as f32 * self.scale)
+            .unwrap_or(self.space_advance)
+    }
+
+    /// Returns the horizontal offset of the line at the start of `rest`, given the width of the block.
+    fn line_offset(&self, rest: &str, block_width: f32) -> f32 {
+        let factor = match self.justify {
+            GizmoTextJustify::Left => return 0.0,
+            GizmoTextJustify::Center => 0.5,
+            GizmoTextJustify::Right => 1.0,
+        };
+        let line_width: f32 = rest
+            .chars()
+            .take_while(|&c| c != '\n')
+            .map(|c| self.char_advance(c))
+            .sum();
+        (block_width - line_width) * factor
+    }
+
     /// Returns an iterator over the font strokes for this text layout,
     /// grouped into polylines of `Vec2` points.
     pub fn render(&'a self) -> impl Iterator<Item = impl Iterator<Item = Vec2>> + 'a {
-        let mut chars = self.text.chars();
-        let mut x = 0.0;
+        self.render_indexed().map(|(_, points)| points)
+    }
+
+    /// Like [`StrokeTextLayout::render`], but also returns the byte index in the text
+    /// of the character each polyline belongs to.
+    pub fn render_indexed(
+        &'a self,
+    ) -> impl Iterator<Item = (usize, impl Iterator<Item = Vec2>)> + 'a {
+        let block_width = self.measure().x;
+        let mut chars = self.text.char_indices();
+        let mut x = self.line_offset(self.text, block_width);
         let mut y = -self.margin_top;
         let mut current_strokes: Range<usize> = 0..0;
         let mut current_x = 0.0;
+        let mut current_index = 0;
 
         core::iter::from_fn(move || loop {
             if !current_strokes.is_empty() {
@@ -123,20 +267,23 @@ impl<'a> StrokeTextLayout<'a> {
                         continue;
                     }
 
-                    return Some(stroke.map(move |index| {
-                        let [p, q] = self.font.positions[index];
-                        Vec2::new(
-                            current_x + self.scale * p as f32,
-                            y - self.scale * (self.font.cap_height - q as f32),
-                        )
-                    }));
+                    return Some((
+                        current_index,
+                        stroke.map(move |index| {
+                            let [p, q] = self.font.positions[index];
+                            Vec2::new(
+                                current_x + self.scale * p as f32,
+                                y - self.scale * (self.font.cap_height - q as f32),
+                            )
+                        }),
+                    ));
                 }
             }
 
-            let c = chars.next()?;
+            let (index, c) = chars.next()?;
             if c == '\n' {
-                x = 0.0;
-                y -= self.line_height;
+                x = self.line_offset(chars.as_str(), block_width);
+                y -= self.line_advance;
                 continue;
             }
 
@@ -146,6 +293,7 @@ impl<'a> StrokeTextLayout<'a> {
             };
             current_strokes = strokes;
             current_x = x;
+            current_index = index;
 
             x += advance as f32 * self.scale;
         })
@@ -160,6 +308,8 @@ where
     /// Draw text using a stroke font with the given isometry applied.
     ///
     /// Only ASCII characters in the range 32–126 are supported.
+    /// Newlines (`\n`) start a new line, and the anchor applies to the whole block of text.
+    /// Lines are left-aligned, use [`GizmoBuffer::text_styled`] to center or right-align them.
     ///
     /// # Arguments
     ///
@@ -189,21 +339,159 @@ where
         anchor: Vec2,
         color: impl Into<Color>,
     ) {
-        let isometry: Isometry3d = isometry.into();
-        let color = color.into();
-        let layout = SIMPLEX_STROKE_FONT.layout(text, font_size);
+        self.text_with_line_spacing(isometry, text, font_size, 1.0, anchor, color);
+    }
+
+    /// Draw multi-line text using a stroke font with the given isometry applied.
+    ///
+    /// `line_spacing` is the distance between consecutive lines as a multiple of the line height.
+    /// See [`GizmoBuffer::text`] for the other arguments.
+    pub fn text_with_line_spacing(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        text: &str,
+        font_size: f32,
+        line_spacing: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+    ) {
+        let layout = SIMPLEX_STROKE_FONT
+            .layout(text, font_size)
+            .with_line_spacing(line_spacing);
+        self.text_layout(isometry.into(), &layout, anchor, color.into());
+    }
+
+    /// Draw text using the given stroke font with the given isometry applied.
+    ///
+    /// Glyphs are looked up in `font` instead of the built-in [`SIMPLEX_STROKE_FONT`].
+    /// See [`GizmoBuffer::text`] for the other arguments.
+    pub fn text_with_font(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        text: &str,
+        font: &StrokeFont,
+        font_size: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+    ) {
+        let layout = font.layout(text, font_size);
+        self.text_layout(isometry.into(), &layout, anchor, color.into());
+    }
+
+    fn text_layout(
+        &mut self,
+        isometry: Isometry3d,
+        layout: &StrokeTextLayout,
+        anchor: Vec2,
+        color: Color,
+    ) {
         let layout_anchor = layout.measure() * (vec2(-0.5, 0.5) - anchor);
         for points in layout.render() {
-            self.linestrip(
+            self.text_strip(
                 points.map(|point| isometry * (layout_anchor + point).extend(0.)),
                 color,
             );
         }
     }
 
+    /// Draw text using a stroke font with the given isometry applied, sized according to `scale`.
+    ///
+    /// With [`GizmoTextScale::Screen`], the text keeps a constant size on the screen of
+    /// the given camera, and isn't drawn at all if it is behind that camera.
+    /// See [`GizmoBuffer::text`] for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::{prelude::*, stroke_text::GizmoTextScale};
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_camera::Camera;
+    /// # use bevy_color::Color;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_transform::components::GlobalTransform;
+    /// fn system(mut gizmos: Gizmos, camera: Single<(&Camera, &GlobalTransform)>) {
+    ///     gizmos.text_with_scale(
+    ///         Isometry3d::IDENTITY,
+    ///         "label",
+    ///         GizmoTextScale::Screen(16.),
+    ///         Vec2::ZERO,
+    ///         Color::WHITE,
+    ///         *camera,
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_with_scale(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        text: &str,
+        scale: GizmoTextScale,
+        anchor: Vec2,
+        color: impl Into<Color>,
+        (camera, camera_transform): (&Camera, &GlobalTransform),
+    ) {
+        let isometry: Isometry3d = isometry.into();
+        let Some(font_size) =
+            scale.world_font_size(isometry.translation.into(), camera, camera_transform)
+        else {
+            return;
+        };
+        self.text(isometry, text, font_size, anchor, color);
+    }
+
+    /// Draw text using a stroke font with the given isometry applied, with an optional outline and frame.
+    ///
+    /// The lines of multi-line text are aligned according to [`GizmoTextStyle::justify`].
+    /// The frame is sized to the text's bounding box and follows the anchor and isometry.
+    /// Both decorations are drawn before the glyphs.
+    /// See [`GizmoBuffer::text`] for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_gizmos::stroke_text::GizmoTextStyle;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::Color;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_styled(
+    ///         Isometry3d::IDENTITY,
+    ///         "Readable label",
+    ///         25.,
+    ///         Vec2::ZERO,
+    ///         Color::WHITE,
+    ///         GizmoTextStyle {
+    ///             outline: Some(Color::BLACK),
+    ///             frame: Some(Color::srgb(0.2, 0.2, 0.2)),
+    ///             ..Default::default()
+    ///         },
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_styled(
+        &mut self,
+        isometry: impl Into<Isometry3d>,
+        text: &str,
+        font_size: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+        style: GizmoTextStyle,
+    ) {
+        let isometry: Isometry3d = isometry.into();
+        self.text_layout_styled(
+            |point| isometry * point.extend(0.),
+            text,
+            font_size,
+            anchor,
+            color.into(),
+            style,
+        );
+    }
+
     /// Draw text using a stroke font in 2d with the given isometry applied.
     ///
     /// Only ASCII characters in the range 32–126 are supported.
+    /// Newlines (`\n`) start a new line, and the anchor applies to the whole block of text.
+    /// Lines are left-aligned, use [`GizmoBuffer::text_2d_styled`] to center or right-align them.
     ///
     /// # Arguments
     ///
@@ -233,15 +521,331 @@ where
         anchor: Vec2,
         color: impl Into<Color>,
     ) {
-        let isometry: Isometry2d = isometry.into();
-        let color = color.into();
-        let layout = SIMPLEX_STROKE_FONT.layout(text, font_size);
+        self.text_2d_with_line_spacing(isometry, text, font_size, 1.0, anchor, color);
+    }
+
+    /// Draw multi-line text using a stroke font in 2d with the given isometry applied.
+    ///
+    /// `line_spacing` is the distance between consecutive lines as a multiple of the line height.
+    /// See [`GizmoBuffer::text_2d`] for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::Color;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d_with_line_spacing(
+    ///         Isometry2d::IDENTITY,
+    ///         "first line\nsecond line",
+    ///         25.,
+    ///         1.5,
+    ///         vec2(-0.5, 0.5),
+    ///         Color::WHITE,
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_2d_with_line_spacing(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        text: &str,
+        font_size: f32,
+        line_spacing: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+    ) {
+        let layout = SIMPLEX_STROKE_FONT
+            .layout(text, font_size)
+            .with_line_spacing(line_spacing);
+        self.text_layout_2d(isometry.into(), &layout, anchor, color.into());
+    }
+
+    /// Draw text using the given stroke font in 2d with the given isometry applied.
+    ///
+    /// Glyphs are looked up in `font` instead of the built-in [`SIMPLEX_STROKE_FONT`].
+    /// See [`GizmoBuffer::text_2d`] for the other arguments.
+    pub fn text_2d_with_font(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        text: &str,
+        font: &StrokeFont,
+        font_size: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+    ) {
+        let layout = font.layout(text, font_size);
+        self.text_layout_2d(isometry.into(), &layout, anchor, color.into());
+    }
+
+    fn text_layout_2d(
+        &mut self,
+        isometry: Isometry2d,
+        layout: &StrokeTextLayout,
+        anchor: Vec2,
+        color: Color,
+    ) {
         let layout_anchor = layout.measure() * (vec2(-0.5, 0.5) - anchor);
         for points in layout.render() {
-            self.linestrip_2d(
-                points.map(|point| isometry * (layout_anchor + point)),
+            self.text_strip(
+                points.map(|point| (isometry * (layout_anchor + point)).extend(0.)),
                 color,
             );
         }
     }
+
+    /// Draw text made of differently colored spans using a stroke font in 2d.
+    ///
+    /// The spans are laid out and anchored as a single block of text,
+    /// with each span drawn in its own color.
+    /// See [`GizmoBuffer::text_2d`] for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::palettes::basic::{BLUE, GREEN, RED};
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d_rich(
+    ///         Isometry2d::IDENTITY,
+    ///         &[("X ", RED.into()), ("Y ", GREEN.into()), ("Z", BLUE.into())],
+    ///         25.,
+    ///         Vec2::ZERO,
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_2d_rich(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        spans: &[(&str, Color)],
+        font_size: f32,
+        anchor: Vec2,
+    ) {
+        let isometry: Isometry2d = isometry.into();
+        let text: String = spans.iter().map(|(span, _)| *span).collect();
+        // The byte index in `text` where each span ends.
+        let span_ends: Vec<usize> = spans
+            .iter()
+            .scan(0, |end, (span, _)| {
+                *end += span.len();
+                Some(*end)
+            })
+            .collect();
+        let layout = SIMPLEX_STROKE_FONT.layout(&text, font_size);
+        let layout_anchor = layout.measure() * (vec2(-0.5, 0.5) - anchor);
+        for (index, points) in layout.render_indexed() {
+            let span = span_ends.partition_point(|&end| end <= index);
+            self.text_strip(
+                points.map(|point| (isometry * (layout_anchor + point)).extend(0.)),
+                spans[span].1,
+            );
+        }
+    }
+
+    /// Draw text using a stroke font in 2d with an optional outline and frame.
+    ///
+    /// The lines of multi-line text are aligned according to [`GizmoTextStyle::justify`].
+    /// The frame is sized to the text's bounding box and follows the anchor and rotation.
+    /// Both decorations are drawn before the glyphs.
+    /// See [`GizmoBuffer::text_2d`] for the other arguments.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_gizmos::stroke_text::GizmoTextStyle;
+    /// # use bevy_math::prelude::*;
+    /// # use bevy_color::Color;
+    /// fn system(mut gizmos: Gizmos) {
+    ///     gizmos.text_2d_styled(
+    ///         Isometry2d::IDENTITY,
+    ///         "Readable label",
+    ///         25.,
+    ///         Vec2::ZERO,
+    ///         Color::WHITE,
+    ///         GizmoTextStyle {
+    ///             outline: Some(Color::BLACK),
+    ///             frame: Some(Color::srgb(0.2, 0.2, 0.2)),
+    ///             ..Default::default()
+    ///         },
+    ///     );
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    pub fn text_2d_styled(
+        &mut self,
+        isometry: impl Into<Isometry2d>,
+        text: &str,
+        font_size: f32,
+        anchor: Vec2,
+        color: impl Into<Color>,
+        style: GizmoTextStyle,
+    ) {
+        let isometry: Isometry2d = isometry.into();
+        self.text_layout_styled(
+            |point| (isometry * point).extend(0.),
+            text,
+            font_size,
+            anchor,
+            color.into(),
+            style,
+        );
+    }
+
+    fn text_layout_styled(
+        &mut self,
+        transform: impl Fn(Vec2) -> Vec3,
+        text: &str,
+        font_size: f32,
+        anchor: Vec2,
+        color: Color,
+        style: GizmoTextStyle,
+    ) {
+        let layout = SIMPLEX_STROKE_FONT
+            .layout(text, font_size)
+            .with_justify(style.justify);
+        let size = layout.measure();
+        let layout_anchor = size * (vec2(-0.5, 0.5) - anchor);
+
+        if let Some(frame) = style.frame {
+            let padding = GizmoTextStyle::FRAME_PADDING * font_size;
+            // The center of the bounding box relative to the anchor point.
+            let center = -size * anchor;
+            let half_size = size / 2.0 + padding;
+            let corners = [
+                vec2(-half_size.x, half_size.y),
+                half_size,
+                vec2(half_size.x, -half_size.y),
+                -half_size,
+                vec2(-half_size.x, half_size.y),
+            ];
+            self.text_strip(corners.map(|corner| transform(center + corner)), frame);
+        }
+
+        if let Some(outline) = style.outline {
+            let offset = GizmoTextStyle::OUTLINE_OFFSET * font_size;
+            for direction in [vec2(1., 1.), vec2(-1., 1.), vec2(1., -1.), vec2(-1., -1.)] {
+                let outline_anchor = layout_anchor + offset * direction;
+                for points in layout.render() {
+                    self.text_strip(
+                        points.map(|point| transform(outline_anchor + point)),
+                        outline,
+                    );
+                }
+            }
+        }
+
+        for points in layout.render() {
+            self.text_strip(points.map(|point| transform(layout_anchor + point)), color);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::DefaultGizmoConfigGroup;
+    use bevy_color::{
+        palettes::basic::{BLUE, RED},
+        LinearRgba,
+    };
+
+    /// Returns the points of the glyph at byte index `index` of the layout's text.
+    fn glyph_points(layout: &StrokeTextLayout, index: usize) -> Vec<Vec2> {
+        layout
+            .render_indexed()
+            .filter(|(glyph_index, _)| *glyph_index == index)
+            .flat_map(|(_, points)| points)
+            .collect()
+    }
+
+    #[test]
+    fn justify_line_offset() {
+        let text = "aa\na";
+        let left = SIMPLEX_STROKE_FONT.layout(text, 10.);
+        let block_width = left.measure().x;
+        let line_width = left.char_advance('a');
+        assert_eq!(block_width, 2. * line_width);
+
+        let center = SIMPLEX_STROKE_FONT
+            .layout(text, 10.)
+            .with_justify(GizmoTextJustify::Center);
+        let right = SIMPLEX_STROKE_FONT
+            .layout(text, 10.)
+            .with_justify(GizmoTextJustify::Right);
+
+        // The longest line is never moved.
+        for layout in [&left, &center, &right] {
+            assert_eq!(layout.line_offset(text, block_width), 0.);
+        }
+        assert_eq!(left.line_offset("a", block_width), 0.);
+        assert_eq!(center.line_offset("a", block_width), line_width / 2.);
+        assert_eq!(right.line_offset("a", block_width), line_width);
+
+        // The glyph on the second line is moved by the offset, without changing the block size.
+        let left_points = glyph_points(&left, 3);
+        let right_points = glyph_points(&right, 3);
+        assert!(!left_points.is_empty());
+        for (left_point, right_point) in left_points.iter().zip(&right_points) {
+            assert!((*right_point - *left_point).abs_diff_eq(vec2(line_width, 0.), 1e-4));
+        }
+        assert_eq!(right.measure(), left.measure());
+    }
+
+    #[test]
+    fn line_spacing() {
+        let text = "a\na";
+        let single = SIMPLEX_STROKE_FONT.layout(text, 10.);
+        let double = SIMPLEX_STROKE_FONT.layout(text, 10.).with_line_spacing(2.);
+        let line_height = single.line_height;
+
+        assert_eq!(single.measure().y, 2. * line_height);
+        assert_eq!(double.measure().y, 3. * line_height);
+
+        // The first line doesn't move, and the second one moves down by one more line height.
+        assert_eq!(glyph_points(&single, 0), glyph_points(&double, 0));
+        let single_points = glyph_points(&single, 2);
+        let double_points = glyph_points(&double, 2);
+        assert!(!single_points.is_empty());
+        for (single_point, double_point) in single_points.iter().zip(&double_points) {
+            assert!((*double_point - *single_point).abs_diff_eq(vec2(0., -line_height), 1e-4));
+        }
+    }
+
+    #[test]
+    fn render_indexed_byte_indices() {
+        let layout = SIMPLEX_STROKE_FONT.layout("a b\n\u{e9}c", 10.);
+        let mut indices: Vec<usize> = layout.render_indexed().map(|(index, _)| index).collect();
+        indices.dedup();
+        // Spaces, newlines and unsupported characters have no strokes,
+        // and `é` takes two bytes.
+        assert_eq!(indices, [0, 2, 6]);
+    }
+
+    #[test]
+    fn text_2d_rich_span_colors() {
+        let mut gizmos = GizmoBuffer::<DefaultGizmoConfigGroup, ()>::default();
+        gizmos.text_2d_rich(
+            Isometry2d::IDENTITY,
+            &[("a", RED.into()), ("", Color::WHITE), ("bc", BLUE.into())],
+            10.,
+            Vec2::ZERO,
+        );
+
+        let layout = SIMPLEX_STROKE_FONT.layout("abc", 10.);
+        let expected_colors: Vec<LinearRgba> = layout
+            .render_indexed()
+            .flat_map(|(index, points)| {
+                let color = if index < 1 { RED } else { BLUE };
+                points
+                    .map(move |_| LinearRgba::from(color))
+                    .chain([LinearRgba::NAN])
+            })
+            .collect();
+        let colors = &gizmos.text_colors;
+        assert_eq!(colors.len(), expected_colors.len());
+        for (color, expected) in colors.iter().zip(&expected_colors) {
+            assert!(color == expected || (color.red.is_nan() && expected.red.is_nan()));
+        }
+    }
 }
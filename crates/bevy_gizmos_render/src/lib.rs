@@ -6,6 +6,8 @@
 
 //! This crate renders `bevy_gizmos` with `bevy_render`.
 
+extern crate alloc;
+
 /// System set label for the systems handling the rendering of gizmos.
 #[derive(SystemSet, Clone, Debug, Hash, PartialEq, Eq)]
 pub enum GizmoRenderSystems {
@@ -24,6 +26,7 @@ mod pipeline_2d;
 #[cfg(feature = "bevy_pbr")]
 mod pipeline_3d;
 
+use alloc::borrow::Cow;
 use bevy_app::{App, Plugin};
 use bevy_ecs::{
     resource::Resource,
@@ -139,7 +142,15 @@ fn extract_gizmo_data(
     use bevy_utils::once;
     use tracing::warn;
 
-    for (group_type_id, handle) in handles.handles() {
+    let line_handles = handles
+        .handles()
+        .iter()
+        .map(|(id, handle)| (id, handle, false));
+    let text_handles = handles
+        .text_handles()
+        .iter()
+        .map(|(id, handle)| (id, handle, true));
+    for (group_type_id, handle, is_text) in line_handles.chain(text_handles) {
         let Some((config, _)) = config.get_config_dyn(group_type_id) else {
             continue;
         };
@@ -182,20 +193,29 @@ fn extract_gizmo_data(
             (1.0, 1.0)
         };
 
+        let (depth_fade_near, depth_fade_far) = config.depth_fade.unwrap_or_default();
+
         commands.spawn((
             LineGizmoUniform {
                 world_from_local: Affine3::from(Affine3A::IDENTITY).to_transpose(),
-                line_width: config.line.width,
-                depth_bias: config.depth_bias,
+                line_width: config.line.width.value(),
+                depth_bias: if is_text {
+                    config.text.depth_bias.unwrap_or(config.depth_bias)
+                } else {
+                    config.depth_bias
+                },
                 joints_resolution,
                 gap_scale,
                 line_scale,
+                world_line_width: config.line.width.is_world().into(),
+                depth_fade_near,
+                depth_fade_far,
                 #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
                 _webgl2_padding: Default::default(),
             },
             #[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite_render"))]
             GizmoMeshConfig {
-                line_perspective: config.line.perspective,
+                line_perspective: config.line.perspective || config.line.width.is_world(),
                 line_style: config.line.style,
                 line_joints: config.line.joints,
                 render_layers: config.render_layers.clone(),
@@ -219,6 +239,11 @@ struct LineGizmoUniform {
     // Only used if the current configs `line_style` is set to `GizmoLineStyle::Dashed{_}`
     gap_scale: f32,
     line_scale: f32,
+    // Set to 1 if `line_width` is in world units rather than in pixels
+    world_line_width: u32,
+    // Fading is disabled unless `depth_fade_far` is greater than `depth_fade_near`
+    depth_fade_near: f32,
+    depth_fade_far: f32,
     /// WebGL2 structs must be 16 byte aligned.
     #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
     _webgl2_padding: bevy_math::Vec3,
@@ -251,41 +276,59 @@ impl RenderAsset for GpuLineGizmo {
         render_device: &mut SystemParamItem<Self::Param>,
         _: Option<&Self>,
     ) -> Result<Self, PrepareAssetError<Self::SourceAsset>> {
+        let buffer = gizmo.buffer().buffer();
+
         let list_position_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
             usage: BufferUsages::VERTEX,
             label: Some("LineGizmo Position Buffer"),
-            contents: cast_slice(&gizmo.buffer().list_positions),
+            contents: cast_slice(buffer.list_positions),
         });
 
         let list_color_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
             usage: BufferUsages::VERTEX,
             label: Some("LineGizmo Color Buffer"),
-            contents: cast_slice(&gizmo.buffer().list_colors),
+            contents: cast_slice(buffer.list_colors),
         });
 
+        // Text is drawn as line strips too. Immediate mode gizmos keep their text in
+        // a separate asset, but retained gizmos draw it together with their other strips.
+        let strip_positions = strips_with_text(buffer.strip_positions, buffer.text_positions);
+        let strip_colors = strips_with_text(buffer.strip_colors, buffer.text_colors);
+
         let strip_position_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
             usage: BufferUsages::VERTEX,
             label: Some("LineGizmo Strip Position Buffer"),
-            contents: cast_slice(&gizmo.buffer().strip_positions),
+            contents: cast_slice(&strip_positions),
         });
 
         let strip_color_buffer = render_device.create_buffer_with_data(&BufferInitDescriptor {
             usage: BufferUsages::VERTEX,
             label: Some("LineGizmo Strip Color Buffer"),
-            contents: cast_slice(&gizmo.buffer().strip_colors),
+            contents: cast_slice(&strip_colors),
         });
 
         Ok(GpuLineGizmo {
             list_position_buffer,
             list_color_buffer,
-            list_vertex_count: gizmo.buffer().list_positions.len() as u32,
+            list_vertex_count: buffer.list_positions.len() as u32,
             strip_position_buffer,
             strip_color_buffer,
-            strip_vertex_count: gizmo.buffer().strip_positions.len() as u32,
+            strip_vertex_count: strip_positions.len() as u32,
         })
     }
 }
 
+/// Returns the line strip vertices followed by the text vertices, only copying them if there are both.
+fn strips_with_text<'a, T: Clone>(strips: &'a [T], text: &'a [T]) -> Cow<'a, [T]> {
+    if text.is_empty() {
+        Cow::Borrowed(strips)
+    } else if strips.is_empty() {
+        Cow::Borrowed(text)
+    } else {
+        Cow::Owned([strips, text].concat())
+    }
+}
+
 #[derive(Resource)]
 struct LineGizmoUniformBindgroupLayout {
     layout: BindGroupLayoutDescriptor,
@@ -8,10 +8,11 @@ struct LineGizmoUniform {
     line_width: f32,
     depth_bias: f32,
     resolution: u32,
-#ifdef SIXTEEN_BYTE_ALIGNMENT
-    // WebGL2 structs must be 16 byte aligned.
-    _padding: f32,
-#endif
+    _gap_scale: f32,
+    _line_scale: f32,
+    world_line_width: u32,
+    depth_fade_near: f32,
+    depth_fade_far: f32,
 }
 
 @group(1) @binding(0) var<uniform> joints_gizmo: LineGizmoUniform;
@@ -27,6 +28,7 @@ struct VertexInput {
 struct VertexOutput {
     @builtin(position) clip_position: vec4<f32>,
     @location(0) color: vec4<f32>,
+    @location(1) view_depth: f32,
 };
 
 const EPSILON: f32 = 4.88e-04;
@@ -58,7 +60,7 @@ fn vertex_bevel(vertex: VertexInput) -> VertexOutput {
     let screen_c = resolution * (0.5 * clip_c.xy / clip_c.w + 0.5);
 
     var color = vertex.color;
-    var line_width = joints_gizmo.line_width;
+    var line_width = line_width_pixels(joints_gizmo.line_width, joints_gizmo.world_line_width);
 
 #ifdef PERSPECTIVE
     line_width /= clip_b.w;
@@ -86,7 +88,7 @@ fn vertex_bevel(vertex: VertexInput) -> VertexOutput {
     let depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
-    return VertexOutput(clip_position, color);
+    return VertexOutput(clip_position, color, view_depth(clip_b));
 }
 
 @vertex
@@ -119,7 +121,7 @@ fn vertex_miter(vertex: VertexInput) -> VertexOutput {
     let screen_c = resolution * (0.5 * clip_c.xy / clip_c.w + 0.5);
 
     var color = vertex.color;
-    var line_width = joints_gizmo.line_width;
+    var line_width = line_width_pixels(joints_gizmo.line_width, joints_gizmo.world_line_width);
 
 #ifdef PERSPECTIVE
     line_width /= clip_b.w;
@@ -148,7 +150,7 @@ fn vertex_miter(vertex: VertexInput) -> VertexOutput {
     var depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
-    return VertexOutput(clip_position, color);
+    return VertexOutput(clip_position, color, view_depth(clip_b));
 }
 
 @vertex
@@ -171,7 +173,7 @@ fn vertex_round(vertex: VertexInput) -> VertexOutput {
     let screen_c = resolution * (0.5 * clip_c.xy / clip_c.w + 0.5);
 
     var color = vertex.color;
-    var line_width = joints_gizmo.line_width;
+    var line_width = line_width_pixels(joints_gizmo.line_width, joints_gizmo.world_line_width);
 
 #ifdef PERSPECTIVE
     line_width /= clip_b.w;
@@ -206,7 +208,30 @@ fn vertex_round(vertex: VertexInput) -> VertexOutput {
     var depth = depth(clip_b);
 
     var clip_position = vec4(clip_b.w * ((2. * screen) / resolution - 1.), depth, clip_b.w);
-    return VertexOutput(clip_position, color);
+    return VertexOutput(clip_position, color, view_depth(clip_b));
+}
+
+fn line_width_pixels(line_width: f32, world_line_width: u32) -> f32 {
+    if world_line_width == 0u {
+        return line_width;
+    }
+    // See `line_width_pixels` in `lines.wgsl`.
+    return line_width * 0.5 * view.viewport.w * view.clip_from_view[1][1];
+}
+
+fn view_depth(clip: vec4<f32>) -> f32 {
+    let view_position = view.view_from_clip * clip;
+    return -view_position.z / view_position.w;
+}
+
+fn depth_fade(view_depth: f32) -> f32 {
+    // Depth fade is disabled when the far distance isn't greater than the near distance.
+    if joints_gizmo.depth_fade_far <= joints_gizmo.depth_fade_near {
+        return 1.0;
+    }
+    return 1.0 - saturate(
+        (view_depth - joints_gizmo.depth_fade_near) / (joints_gizmo.depth_fade_far - joints_gizmo.depth_fade_near)
+    );
 }
 
 fn clip_near_plane(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
@@ -242,6 +267,7 @@ fn depth(clip: vec4<f32>) -> f32 {
 
 struct FragmentInput {
     @location(0) color: vec4<f32>,
+    @location(1) view_depth: f32,
 };
 
 struct FragmentOutput {
@@ -251,5 +277,5 @@ struct FragmentOutput {
 @fragment
 fn fragment(in: FragmentInput) -> FragmentOutput {
     // return FragmentOutput(vec4(1, 1, 1, 1));
-    return FragmentOutput(in.color);
+    return FragmentOutput(vec4(in.color.xyz, in.color.w * depth_fade(in.view_depth)));
 }
@@ -11,6 +11,9 @@ struct LineGizmoUniform {
     _joints_resolution: u32,
     gap_scale: f32,
     line_scale: f32,
+    world_line_width: u32,
+    depth_fade_near: f32,
+    depth_fade_far: f32,
 #ifdef SIXTEEN_BYTE_ALIGNMENT
     // WebGL2 structs must be 16 byte aligned.
     _padding: vec3<f32>,
@@ -32,6 +35,7 @@ struct VertexOutput {
     @location(0) color: vec4<f32>,
     @location(1) uv: f32,
     @location(2) line_fraction: f32,
+    @location(3) view_depth: f32,
 };
 
 const EPSILON: f32 = 4.88e-04;
@@ -68,7 +72,9 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
 
     var color = mix(vertex.color_a, vertex.color_b, position.y);
 
-    var line_width = line_gizmo.line_width;
+    // The line width in pixels, at a view depth of 1 for world space widths.
+    let base_line_width = line_width_pixels(line_gizmo.line_width, line_gizmo.world_line_width);
+    var line_width = base_line_width;
     var alpha = 1.;
 
     var uv: f32;
@@ -88,7 +94,7 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
     // Offset to compensate for moved clip positions. If removed dots on lines will slide when position a is ofscreen.
     let clipped_offset = length(position_a.xyz - vertex.position_a);
 
-    uv = (clipped_offset + position.y * world_distance) * resolution.y / near_clipping_plane_height / line_gizmo.line_width;
+    uv = (clipped_offset + position.y * world_distance) * resolution.y / near_clipping_plane_height / base_line_width;
 #else
     // Get the distance of b to the camera along camera axes
     let camera_b = view.view_from_clip * clip_b;
@@ -102,7 +108,7 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
     else {
         depth_adaptment = -camera_b.z;
     }
-    uv = position.y * depth_adaptment * length(screen_b - screen_a) / line_gizmo.line_width;
+    uv = position.y * depth_adaptment * length(screen_b - screen_a) / base_line_width;
 #endif
 
     // Line thinness fade from https://acegikmo.com/shapes/docs/#anti-aliasing
@@ -132,7 +138,32 @@ fn vertex(vertex: VertexInput) -> VertexOutput {
 
     let line_fraction = 2.0 * line_gizmo.line_scale / (line_gizmo.gap_scale + line_gizmo.line_scale);
     uv /= (line_gizmo.gap_scale + line_gizmo.line_scale) / 2.0;
-    return VertexOutput(clip_position, color, uv, line_fraction);
+    return VertexOutput(clip_position, color, uv, line_fraction, view_depth(clip));
+}
+
+fn line_width_pixels(line_width: f32, world_line_width: u32) -> f32 {
+    if world_line_width == 0u {
+        return line_width;
+    }
+    // Project a length in world units at a view depth of 1 to pixels. Lines with a world space
+    // width are always drawn with `PERSPECTIVE`, which divides this by the actual depth.
+    // For orthographic projections `clip.w` is 1, so this is the width at any depth.
+    return line_width * 0.5 * view.viewport.w * view.clip_from_view[1][1];
+}
+
+fn view_depth(clip: vec4<f32>) -> f32 {
+    let view_position = view.view_from_clip * clip;
+    return -view_position.z / view_position.w;
+}
+
+fn depth_fade(view_depth: f32) -> f32 {
+    // Depth fade is disabled when the far distance isn't greater than the near distance.
+    if line_gizmo.depth_fade_far <= line_gizmo.depth_fade_near {
+        return 1.0;
+    }
+    return 1.0 - saturate(
+        (view_depth - line_gizmo.depth_fade_near) / (line_gizmo.depth_fade_far - line_gizmo.depth_fade_near)
+    );
 }
 
 fn clip_near_plane(a: vec4<f32>, b: vec4<f32>) -> vec4<f32> {
@@ -155,6 +186,7 @@ struct FragmentInput {
     @location(0) color: vec4<f32>,
     @location(1) uv: f32,
     @location(2) line_fraction: f32,
+    @location(3) view_depth: f32,
 };
 
 struct FragmentOutput {
@@ -163,7 +195,7 @@ struct FragmentOutput {
 
 @fragment
 fn fragment_solid(in: FragmentInput) -> FragmentOutput {
-    return FragmentOutput(in.color);
+    return FragmentOutput(vec4(in.color.xyz, in.color.w * depth_fade(in.view_depth)));
 }
 @fragment
 fn fragment_dotted(in: FragmentInput) -> FragmentOutput {
@@ -174,7 +206,7 @@ fn fragment_dotted(in: FragmentInput) -> FragmentOutput {
     alpha = 1 - floor((in.uv * in.position.w) % 2.0);
 #endif
     
-    return FragmentOutput(vec4(in.color.xyz, in.color.w * alpha));
+    return FragmentOutput(vec4(in.color.xyz, in.color.w * alpha * depth_fade(in.view_depth)));
 }
 
 @fragment
@@ -186,5 +218,5 @@ fn fragment_dashed(in: FragmentInput) -> FragmentOutput {
 #endif
     let alpha = 1.0 - floor(min((uv % 2.0) / in.line_fraction, 1.0));
     
-    return FragmentOutput(vec4(in.color.xyz, in.color.w * alpha));
+    return FragmentOutput(vec4(in.color.xyz, in.color.w * alpha * depth_fade(in.view_depth)));
 }
@@ -55,20 +55,26 @@ pub(crate) fn extract_linegizmos(
             (1.0, 1.0)
         };
 
+        let (depth_fade_near, depth_fade_far) = gizmo.depth_fade.unwrap_or_default();
+
         values.push((
             LineGizmoUniform {
                 world_from_local: Affine3::from(transform.affine()).to_transpose(),
-                line_width: gizmo.line_config.width,
+                line_width: gizmo.line_config.width.value(),
                 depth_bias: gizmo.depth_bias,
                 joints_resolution,
                 gap_scale,
                 line_scale,
+                world_line_width: gizmo.line_config.width.is_world().into(),
+                depth_fade_near,
+                depth_fade_far,
                 #[cfg(all(feature = "webgl", target_arch = "wasm32", not(feature = "webgpu")))]
                 _webgl2_padding: Default::default(),
             },
             #[cfg(any(feature = "bevy_pbr", feature = "bevy_sprite_render"))]
             bevy_gizmos::config::GizmoMeshConfig {
-                line_perspective: gizmo.line_config.perspective,
+                line_perspective: gizmo.line_config.perspective
+                    || gizmo.line_config.width.is_world(),
                 line_style: gizmo.line_config.style,
                 line_joints: gizmo.line_config.joints,
                 render_layers: render_layers.cloned().unwrap_or_default(),
@@ -103,11 +103,11 @@
 //! | `KHR_lights_punctual`             | ✅        |                                     |
 //! | `KHR_materials_anisotropy`        | ✅        | `pbr_anisotropy_texture`            |
 //! | `KHR_materials_clearcoat`         | ✅        | `pbr_multi_layer_material_textures` |
-//! | `KHR_materials_dispersion`        | ❌        |                                     |
+//! | `KHR_materials_dispersion`        | ✅        |                                     |
 //! | `KHR_materials_emissive_strength` | ✅        |                                     |
 //! | `KHR_materials_ior`               | ✅        |                                     |
-//! | `KHR_materials_iridescence`       | ❌        |                                     |
-//! | `KHR_materials_sheen`             | ❌        |                                     |
+//! | `KHR_materials_iridescence`       | ✅\***    | `gltf_iridescence_texture`          |
+//! | `KHR_materials_sheen`             | ✅        | `pbr_sheen_texture`                 |
 //! | `KHR_materials_specular`          | ✅        | `pbr_specular_textures`             |
 //! | `KHR_materials_transmission`      | ✅        | `pbr_transmission_textures`         |
 //! | `KHR_materials_unlit`             | ✅        |                                     |
@@ -123,7 +123,9 @@
 //!
 //! \*Bevy supports ktx2 and webp formats but doesn't support the extension's syntax, see [#19104](https://github.com/bevyengine/bevy/issues/19104).
 //!
-//! \**`KHR_texture_transform` is only supported on `base_color_texture`, see [#15310](https://github.com/bevyengine/bevy/issues/15310).
+//! \**`KHR_texture_transform` is loaded for each core texture of [`GltfMaterial`], but `StandardMaterial` only supports it on `base_color_texture`, see [#15310](https://github.com/bevyengine/bevy/issues/15310).
+//!
+//! \***`KHR_materials_iridescence` is loaded into [`GltfMaterial`] but isn't rendered by `StandardMaterial`.
 //!
 //! See the [glTF Extension Registry](https://github.com/KhronosGroup/glTF/blob/main/extensions/README.md) for more information on extensions.
 
@@ -159,7 +161,12 @@ pub mod prelude {
 
 use crate::{convert_coordinates::GltfConvertCoordinates, extensions::GltfExtensionHandlers};
 
-pub use {assets::*, label::GltfAssetLabel, loader::*, material::GltfMaterial};
+pub use {
+    assets::*,
+    label::GltfAssetLabel,
+    loader::*,
+    material::{GltfMaterial, GltfMaterialBuilder},
+};
 
 /// Re-exports for GLTF
 pub mod gltf {
@@ -0,0 +1,25 @@
+use gltf::Material;
+
+use serde_json::Value;
+
+/// Parsed data from the `KHR_materials_dispersion` extension.
+///
+/// See the specification:
+/// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_dispersion/README.md>
+#[derive(Default)]
+pub(crate) struct DispersionExtension {
+    pub(crate) dispersion: Option<f64>,
+}
+
+impl DispersionExtension {
+    pub(crate) fn parse(material: &Material) -> Option<DispersionExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_dispersion")?
+            .as_object()?;
+
+        Some(DispersionExtension {
+            dispersion: extension.get("dispersion").and_then(Value::as_f64),
+        })
+    }
+}
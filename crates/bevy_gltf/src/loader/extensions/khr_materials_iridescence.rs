@@ -0,0 +1,90 @@
+use bevy_asset::{AssetPath, Handle};
+use bevy_image::Image;
+
+use gltf::Material;
+
+use serde_json::Value;
+
+#[cfg(feature = "gltf_iridescence_texture")]
+use {crate::loader::gltf_ext::material::parse_material_extension_texture, bevy_mesh::UvChannel};
+
+/// Parsed data from the `KHR_materials_iridescence` extension.
+///
+/// See the specification:
+/// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_iridescence/README.md>
+#[derive(Default)]
+pub(crate) struct IridescenceExtension {
+    pub(crate) iridescence_factor: Option<f64>,
+    pub(crate) iridescence_ior: Option<f64>,
+    pub(crate) iridescence_thickness_minimum: Option<f64>,
+    pub(crate) iridescence_thickness_maximum: Option<f64>,
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub(crate) iridescence_channel: UvChannel,
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub(crate) iridescence_texture: Option<Handle<Image>>,
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub(crate) iridescence_thickness_channel: UvChannel,
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub(crate) iridescence_thickness_texture: Option<Handle<Image>>,
+}
+
+impl IridescenceExtension {
+    #[expect(
+        clippy::allow_attributes,
+        reason = "`unused_variables` is not always linted"
+    )]
+    #[allow(
+        unused_variables,
+        reason = "Depending on what features are used to compile this crate, certain parameters may end up unused."
+    )]
+    pub(crate) fn parse(
+        material: &Material,
+        textures: &[Handle<Image>],
+        asset_path: AssetPath<'_>,
+    ) -> Option<IridescenceExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_iridescence")?
+            .as_object()?;
+
+        #[cfg(feature = "gltf_iridescence_texture")]
+        let (iridescence_channel, iridescence_texture) = parse_material_extension_texture(
+            material,
+            extension,
+            "iridescenceTexture",
+            "iridescence",
+            textures,
+            asset_path.clone(),
+        );
+
+        #[cfg(feature = "gltf_iridescence_texture")]
+        let (iridescence_thickness_channel, iridescence_thickness_texture) =
+            parse_material_extension_texture(
+                material,
+                extension,
+                "iridescenceThicknessTexture",
+                "iridescence thickness",
+                textures,
+                asset_path,
+            );
+
+        Some(IridescenceExtension {
+            iridescence_factor: extension.get("iridescenceFactor").and_then(Value::as_f64),
+            iridescence_ior: extension.get("iridescenceIor").and_then(Value::as_f64),
+            iridescence_thickness_minimum: extension
+                .get("iridescenceThicknessMinimum")
+                .and_then(Value::as_f64),
+            iridescence_thickness_maximum: extension
+                .get("iridescenceThicknessMaximum")
+                .and_then(Value::as_f64),
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_channel,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_texture,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_channel,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_texture,
+        })
+    }
+}
@@ -0,0 +1,96 @@
+use bevy_asset::{AssetPath, Handle};
+use bevy_image::Image;
+
+use gltf::Material;
+
+use serde_json::Value;
+
+#[cfg(feature = "pbr_sheen_texture")]
+use {crate::loader::gltf_ext::material::parse_material_extension_texture, bevy_mesh::UvChannel};
+
+/// Parsed data from the `KHR_materials_sheen` extension.
+///
+/// See the specification:
+/// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_sheen/README.md>
+#[derive(Default)]
+pub(crate) struct SheenExtension {
+    pub(crate) sheen_color_factor: Option<[f64; 3]>,
+    pub(crate) sheen_roughness_factor: Option<f64>,
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub(crate) sheen_color_channel: UvChannel,
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub(crate) sheen_color_texture: Option<Handle<Image>>,
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub(crate) sheen_roughness_channel: UvChannel,
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub(crate) sheen_roughness_texture: Option<Handle<Image>>,
+}
+
+impl SheenExtension {
+    #[expect(
+        clippy::allow_attributes,
+        reason = "`unused_variables` is not always linted"
+    )]
+    #[allow(
+        unused_variables,
+        reason = "Depending on what features are used to compile this crate, certain parameters may end up unused."
+    )]
+    pub(crate) fn parse(
+        material: &Material,
+        textures: &[Handle<Image>],
+        asset_path: AssetPath<'_>,
+    ) -> Option<SheenExtension> {
+        let extension = material
+            .extensions()?
+            .get("KHR_materials_sheen")?
+            .as_object()?;
+
+        #[cfg(feature = "pbr_sheen_texture")]
+        let (sheen_color_channel, sheen_color_texture) = parse_material_extension_texture(
+            material,
+            extension,
+            "sheenColorTexture",
+            "sheen color",
+            textures,
+            asset_path.clone(),
+        );
+
+        #[cfg(feature = "pbr_sheen_texture")]
+        let (sheen_roughness_channel, sheen_roughness_texture) = parse_material_extension_texture(
+            material,
+            extension,
+            "sheenRoughnessTexture",
+            "sheen roughness",
+            textures,
+            asset_path,
+        );
+
+        Some(SheenExtension {
+            sheen_color_factor: extension
+                .get("sheenColorFactor")
+                .and_then(Value::as_array)
+                .and_then(|json_array| {
+                    if json_array.len() < 3 {
+                        None
+                    } else {
+                        Some([
+                            json_array[0].as_f64()?,
+                            json_array[1].as_f64()?,
+                            json_array[2].as_f64()?,
+                        ])
+                    }
+                }),
+            sheen_roughness_factor: extension
+                .get("sheenRoughnessFactor")
+                .and_then(Value::as_f64),
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_channel,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_texture,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_channel,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_texture,
+        })
+    }
+}
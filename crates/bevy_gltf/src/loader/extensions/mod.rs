@@ -2,6 +2,9 @@
 
 mod khr_materials_anisotropy;
 mod khr_materials_clearcoat;
+mod khr_materials_dispersion;
+mod khr_materials_iridescence;
+mod khr_materials_sheen;
 mod khr_materials_specular;
 
 use alloc::sync::Arc;
@@ -25,7 +28,8 @@ use crate::{GltfMaterial, GltfMesh};
 
 pub(crate) use self::{
     khr_materials_anisotropy::AnisotropyExtension, khr_materials_clearcoat::ClearcoatExtension,
-    khr_materials_specular::SpecularExtension,
+    khr_materials_dispersion::DispersionExtension, khr_materials_iridescence::IridescenceExtension,
+    khr_materials_sheen::SheenExtension, khr_materials_specular::SpecularExtension,
 };
 
 /// Stores the `GltfExtensionHandler` implementations so that they
@@ -13,7 +13,9 @@ use super::texture::texture_transform_to_affine2;
 #[cfg(any(
     feature = "pbr_anisotropy_texture",
     feature = "pbr_specular_textures",
-    feature = "pbr_multi_layer_material_textures"
+    feature = "pbr_multi_layer_material_textures",
+    feature = "gltf_iridescence_texture",
+    feature = "pbr_sheen_texture"
 ))]
 use {
     bevy_asset::{AssetPath, Handle},
@@ -26,7 +28,9 @@ use {
 #[cfg(any(
     feature = "pbr_anisotropy_texture",
     feature = "pbr_specular_textures",
-    feature = "pbr_multi_layer_material_textures"
+    feature = "pbr_multi_layer_material_textures",
+    feature = "gltf_iridescence_texture",
+    feature = "pbr_sheen_texture"
 ))]
 pub(crate) fn parse_material_extension_texture(
     material: &Material,
@@ -155,8 +159,8 @@ pub(crate) fn warn_on_differing_texture_transforms(
             .map(|i| format!("index {i}"))
             .unwrap_or_else(|| "default".to_string());
         tracing::warn!(
-            "Only texture transforms on base color textures are supported, but {material_name} ({material_index}) \
-            has a texture transform on {texture_name} (index {}), which will be ignored.", info.texture().index()
+            "`StandardMaterial` only supports texture transforms on base color textures, but {material_name} ({material_index}) \
+            has a texture transform on {texture_name} (index {}), which will be ignored when rendering.", info.texture().index()
         );
     }
 }
@@ -62,6 +62,17 @@ pub(crate) fn get_linear_textures(document: &Document) -> HashSet<usize> {
         {
             linear_textures.insert(texture_index);
         }
+        // Unlike the sheen color texture, the sheen roughness texture isn't sRGB encoded.
+        // If both share a texture, with the roughness in its alpha channel, it stays sRGB,
+        // since alpha is never sRGB encoded.
+        #[cfg(feature = "pbr_sheen_texture")]
+        if let Some(texture_index) =
+            extension_texture_index(&material, "KHR_materials_sheen", "sheenRoughnessTexture")
+            && extension_texture_index(&material, "KHR_materials_sheen", "sheenColorTexture")
+                != Some(texture_index)
+        {
+            linear_textures.insert(texture_index);
+        }
 
         // None of the clearcoat maps should be loaded as sRGB.
         #[cfg(feature = "pbr_multi_layer_material_textures")]
@@ -1,7 +1,12 @@
 use bevy_image::{ImageAddressMode, ImageFilterMode, ImageSamplerDescriptor};
 use bevy_math::Affine2;
 
-use gltf::texture::{MagFilter, MinFilter, Texture, TextureTransform, WrappingMode};
+use gltf::{
+    json::extensions::texture::TextureTransform as JsonTextureTransform,
+    texture::{MagFilter, MinFilter, Texture, TextureTransform, WrappingMode},
+};
+
+use serde_json::{value, Map, Value};
 
 /// Extracts the texture sampler data from the glTF [`Texture`].
 pub(crate) fn texture_sampler(
@@ -63,3 +68,19 @@ pub(crate) fn texture_transform_to_affine2(texture_transform: TextureTransform)
         texture_transform.offset().into(),
     )
 }
+
+/// Parses the `KHR_texture_transform` extension from the extensions of a texture
+/// reference that doesn't expose it directly, such as normal and occlusion textures.
+pub(crate) fn extension_texture_transform_to_affine2(
+    extensions: Option<&Map<String, Value>>,
+) -> Option<Affine2> {
+    let texture_transform = extensions?
+        .get("KHR_texture_transform")
+        .and_then(|value| value::from_value::<JsonTextureTransform>(value.clone()).ok())?;
+
+    Some(Affine2::from_scale_angle_translation(
+        texture_transform.scale.0.into(),
+        -texture_transform.rotation.0,
+        texture_transform.offset.0.into(),
+    ))
+}
@@ -63,7 +63,10 @@ use crate::{
 #[cfg(feature = "bevy_animation")]
 use self::gltf_ext::scene::collect_path;
 use self::{
-    extensions::{AnisotropyExtension, ClearcoatExtension, SpecularExtension},
+    extensions::{
+        AnisotropyExtension, ClearcoatExtension, DispersionExtension, IridescenceExtension,
+        SheenExtension, SpecularExtension,
+    },
     gltf_ext::{
         check_for_cycles, get_linear_textures,
         material::{
@@ -72,7 +75,9 @@ use self::{
         },
         mesh::{primitive_name, primitive_topology},
         scene::{node_name, node_transform},
-        texture::{texture_sampler, texture_transform_to_affine2},
+        texture::{
+            extension_texture_transform_to_affine2, texture_sampler, texture_transform_to_affine2,
+        },
     },
 };
 use crate::convert_coordinates::GltfConvertCoordinates;
@@ -213,6 +218,13 @@ pub struct GltfLoaderSettings {
     pub convert_coordinates: Option<GltfConvertCoordinates>,
     /// Optionally overrides [`GltfPlugin::skinned_mesh_bounds_policy`](crate::GltfPlugin).
     pub skinned_mesh_bounds_policy: Option<GltfSkinnedMeshBoundsPolicy>,
+    /// Overrides [`GltfMaterial::cull_mode`] for every material in the glTF file.
+    ///
+    /// If `None`, the cull mode is derived from each material's `doubleSided` flag.
+    /// `Some(None)` disables culling, which is useful for assets authored with inverted winding.
+    /// As with the derived cull mode, the culled face is swapped for the copies of materials
+    /// used by nodes with an inverted scale.
+    pub cull_mode: Option<Option<Face>>,
 }
 
 impl Default for GltfLoaderSettings {
@@ -228,6 +240,7 @@ impl Default for GltfLoaderSettings {
             override_sampler: false,
             convert_coordinates: None,
             skinned_mesh_bounds_policy: None,
+            cull_mode: None,
         }
     }
 }
@@ -663,6 +676,15 @@ impl GltfLoader {
                 });
         }
 
+        // The indices of the materials used by at least one primitive with vertex colors,
+        // where `None` is the default material.
+        let vertex_color_materials = gltf
+            .meshes()
+            .flat_map(|mesh| mesh.primitives())
+            .filter(|primitive| primitive.get(&gltf::Semantic::Colors(0)).is_some())
+            .map(|primitive| primitive.material().index())
+            .collect::<HashSet<_>>();
+
         let mut materials = vec![];
         let mut named_materials = <HashMap<_, _>>::default();
         // Only include materials in the output if they're set to be retained in the MAIN_WORLD and/or RENDER_WORLD by the load_materials flag
@@ -673,6 +695,8 @@ impl GltfLoader {
                     &material,
                     &texture_handles,
                     false,
+                    vertex_color_materials.contains(&material.index()),
+                    settings.cull_mode,
                     load_context.path().clone(),
                 );
                 let handle = load_context.add_labeled_asset(label.clone(), gltf_material.clone());
@@ -1017,6 +1041,7 @@ impl GltfLoader {
                             #[cfg(feature = "bevy_animation")]
                             None,
                             &texture_handles,
+                            &vertex_color_materials,
                             &convert_coordinates,
                             &mut extensions,
                             skinned_mesh_bounds_policy,
@@ -1211,6 +1236,8 @@ fn load_material(
     material: &Material,
     textures: &[Handle<Image>],
     is_scale_inverted: bool,
+    use_vertex_colors: bool,
+    cull_mode: Option<Option<Face>>,
     asset_path: AssetPath<'_>,
 ) -> (String, GltfMaterial) {
     let pbr = material.pbr_metallic_roughness();
@@ -1228,10 +1255,10 @@ fn load_material(
             .unwrap_or_default()
     });
 
-    let uv_transform = pbr
+    let base_color_uv_transform = pbr
         .base_color_texture()
-        .and_then(|info| info.texture_transform().map(texture_transform_to_affine2))
-        .unwrap_or_default();
+        .and_then(|info| info.texture_transform().map(texture_transform_to_affine2));
+    let uv_transform = base_color_uv_transform.unwrap_or_default();
 
     let normal_map_channel = material
         .normal_texture()
@@ -1239,17 +1266,23 @@ fn load_material(
         .unwrap_or_default();
     let normal_map_texture: Option<Handle<Image>> =
         material.normal_texture().map(|normal_texture| {
-            // TODO: handle normal_texture.scale
             textures
                 .get(normal_texture.texture().index())
                 .cloned()
                 .unwrap_or_default()
         });
+    let normal_map_uv_transform = material
+        .normal_texture()
+        .and_then(|info| extension_texture_transform_to_affine2(info.extensions()));
+    let normal_map_scale = material.normal_texture().map_or(1.0, |info| info.scale());
 
     let metallic_roughness_channel = pbr
         .metallic_roughness_texture()
         .map(|info| uv_channel(material, "metallic/roughness", info.tex_coord()))
         .unwrap_or_default();
+    let metallic_roughness_uv_transform = pbr
+        .metallic_roughness_texture()
+        .and_then(|info| info.texture_transform().map(texture_transform_to_affine2));
     let metallic_roughness_texture = pbr.metallic_roughness_texture().map(|info| {
         warn_on_differing_texture_transforms(material, &info, uv_transform, "metallic/roughness");
         textures
@@ -1263,20 +1296,27 @@ fn load_material(
         .map(|info| uv_channel(material, "occlusion", info.tex_coord()))
         .unwrap_or_default();
     let occlusion_texture = material.occlusion_texture().map(|occlusion_texture| {
-        // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         textures
             .get(occlusion_texture.texture().index())
             .cloned()
             .unwrap_or_default()
     });
+    let occlusion_uv_transform = material
+        .occlusion_texture()
+        .and_then(|info| extension_texture_transform_to_affine2(info.extensions()));
+    let occlusion_strength = material
+        .occlusion_texture()
+        .map_or(1.0, |info| info.strength());
 
     let emissive = material.emissive_factor();
     let emissive_channel = material
         .emissive_texture()
         .map(|info| uv_channel(material, "emissive", info.tex_coord()))
         .unwrap_or_default();
+    let emissive_uv_transform = material
+        .emissive_texture()
+        .and_then(|info| info.texture_transform().map(texture_transform_to_affine2));
     let emissive_texture = material.emissive_texture().map(|info| {
-        // TODO: handle occlusion_texture.strength() (a scalar multiplier for occlusion strength)
         warn_on_differing_texture_transforms(material, &info, uv_transform, "emissive");
         textures
             .get(info.texture().index())
@@ -1367,33 +1407,55 @@ fn load_material(
     let specular =
         SpecularExtension::parse(material, textures, asset_path.clone()).unwrap_or_default();
 
+    // Parse the `KHR_materials_iridescence` extension data if necessary.
+    let iridescence =
+        IridescenceExtension::parse(material, textures, asset_path.clone()).unwrap_or_default();
+
+    // Parse the `KHR_materials_sheen` extension data if necessary.
+    let sheen = SheenExtension::parse(material, textures, asset_path.clone()).unwrap_or_default();
+
+    // Parse the `KHR_materials_dispersion` extension data if necessary.
+    let dispersion = DispersionExtension::parse(material).unwrap_or_default();
+
     // We need to operate in the Linear color space and be willing to exceed 1.0 in our channels
-    let base_emissive = LinearRgba::rgb(emissive[0], emissive[1], emissive[2]);
-    let emissive = base_emissive * material.emissive_strength().unwrap_or(1.0);
+    let emissive = LinearRgba::rgb(emissive[0], emissive[1], emissive[2]);
+    let emissive_strength = material.emissive_strength().unwrap_or(1.0);
 
     let gltf_material = GltfMaterial {
         base_color: Color::linear_rgba(color[0], color[1], color[2], color[3]),
         base_color_channel,
         base_color_texture,
+        base_color_uv_transform,
         perceptual_roughness: pbr.roughness_factor(),
         metallic: pbr.metallic_factor(),
         metallic_roughness_channel,
         metallic_roughness_texture,
+        metallic_roughness_uv_transform,
         normal_map_channel,
         normal_map_texture,
+        normal_map_uv_transform,
+        normal_map_scale,
         double_sided: material.double_sided(),
-        cull_mode: if material.double_sided() {
-            None
-        } else if is_scale_inverted {
-            Some(Face::Front)
-        } else {
-            Some(Face::Back)
-        },
+        cull_mode: cull_mode
+            .unwrap_or(if material.double_sided() {
+                None
+            } else {
+                Some(Face::Back)
+            })
+            .map(|face| match (face, is_scale_inverted) {
+                (Face::Back, true) => Face::Front,
+                (Face::Front, true) => Face::Back,
+                (face, false) => face,
+            }),
         occlusion_channel,
         occlusion_texture,
+        occlusion_uv_transform,
+        occlusion_strength,
         emissive,
+        emissive_strength,
         emissive_channel,
         emissive_texture,
+        emissive_uv_transform,
         specular_transmission,
         #[cfg(feature = "pbr_transmission_textures")]
         specular_transmission_channel,
@@ -1405,6 +1467,7 @@ fn load_material(
         #[cfg(feature = "pbr_transmission_textures")]
         thickness_texture,
         ior,
+        dispersion: dispersion.dispersion.unwrap_or_default() as f32,
         attenuation_distance,
         attenuation_color: Color::linear_rgb(
             attenuation_color[0],
@@ -1412,6 +1475,7 @@ fn load_material(
             attenuation_color[2],
         ),
         unlit: material.unlit(),
+        use_vertex_colors,
         alpha_mode: alpha_mode(material),
         uv_transform,
         clearcoat: clearcoat.clearcoat_factor.unwrap_or_default() as f32,
@@ -1450,6 +1514,35 @@ fn load_material(
         specular_tint_channel: specular.specular_color_channel,
         #[cfg(feature = "pbr_specular_textures")]
         specular_tint_texture: specular.specular_color_texture,
+        // Defaults from the `KHR_materials_iridescence` spec.
+        iridescence: iridescence.iridescence_factor.unwrap_or_default() as f32,
+        iridescence_ior: iridescence.iridescence_ior.unwrap_or(1.3) as f32,
+        iridescence_thickness_min: iridescence.iridescence_thickness_minimum.unwrap_or(100.0)
+            as f32,
+        iridescence_thickness_max: iridescence.iridescence_thickness_maximum.unwrap_or(400.0)
+            as f32,
+        #[cfg(feature = "gltf_iridescence_texture")]
+        iridescence_channel: iridescence.iridescence_channel,
+        #[cfg(feature = "gltf_iridescence_texture")]
+        iridescence_texture: iridescence.iridescence_texture,
+        #[cfg(feature = "gltf_iridescence_texture")]
+        iridescence_thickness_channel: iridescence.iridescence_thickness_channel,
+        #[cfg(feature = "gltf_iridescence_texture")]
+        iridescence_thickness_texture: iridescence.iridescence_thickness_texture,
+        // Defaults from the `KHR_materials_sheen` spec.
+        sheen_color: match sheen.sheen_color_factor {
+            Some(color) => Color::linear_rgb(color[0] as f32, color[1] as f32, color[2] as f32),
+            None => Color::BLACK,
+        },
+        sheen_roughness: sheen.sheen_roughness_factor.unwrap_or_default() as f32,
+        #[cfg(feature = "pbr_sheen_texture")]
+        sheen_color_channel: sheen.sheen_color_channel,
+        #[cfg(feature = "pbr_sheen_texture")]
+        sheen_color_texture: sheen.sheen_color_texture,
+        #[cfg(feature = "pbr_sheen_texture")]
+        sheen_roughness_channel: sheen.sheen_roughness_channel,
+        #[cfg(feature = "pbr_sheen_texture")]
+        sheen_roughness_texture: sheen.sheen_roughness_texture,
     };
 
     (
@@ -1479,6 +1572,7 @@ fn load_node(
     #[cfg(feature = "bevy_animation")] animation_roots: &HashSet<usize>,
     #[cfg(feature = "bevy_animation")] mut animation_context: Option<AnimationContext>,
     textures: &[Handle<Image>],
+    vertex_color_materials: &HashSet<Option<usize>>,
     convert_coordinates: &GltfConvertCoordinates,
     extensions: &mut [Box<dyn extensions::GltfExtensionHandler>],
     skinned_mesh_bounds_policy: GltfSkinnedMeshBoundsPolicy,
@@ -1596,6 +1690,8 @@ fn load_node(
                         &material,
                         textures,
                         is_scale_inverted,
+                        vertex_color_materials.contains(&material.index()),
+                        settings.cull_mode,
                         load_context.path().clone(),
                     );
                     // TODO: maybe move this into `load_material` ?
@@ -1808,6 +1904,7 @@ fn load_node(
                 #[cfg(feature = "bevy_animation")]
                 animation_context.clone(),
                 textures,
+                vertex_color_materials,
                 convert_coordinates,
                 extensions,
                 skinned_mesh_bounds_policy,
@@ -2044,6 +2141,7 @@ mod test {
     use bevy_ecs::{resource::Resource, world::World};
     use bevy_image::{Image, ImageLoaderSettings};
     use bevy_log::LogPlugin;
+    use bevy_material::AlphaMode;
     use bevy_mesh::skinning::SkinnedMeshInverseBindposes;
     use bevy_mesh::MeshPlugin;
     use bevy_reflect::TypePath;
@@ -2151,6 +2249,117 @@ mod test {
         assert_eq!(gltf_node.asset_label(), GltfAssetLabel::Node(0));
     }
 
+    #[test]
+    fn material_alpha_cutoff() {
+        let gltf_path = "test.gltf";
+        let app = load_gltf_into_app(
+            gltf_path,
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "materials": [
+        {
+            "name": "Masked",
+            "alphaMode": "MASK",
+            "alphaCutoff": 0.25
+        },
+        {
+            "name": "DefaultCutoff",
+            "alphaMode": "MASK"
+        }
+    ]
+}
+"#,
+        );
+        let asset_server = app.world().resource::<AssetServer>();
+        let handle = asset_server.load(gltf_path);
+        let gltf_root_assets = app.world().resource::<Assets<Gltf>>();
+        let gltf_material_assets = app.world().resource::<Assets<GltfMaterial>>();
+        let gltf_root = gltf_root_assets.get(&handle).unwrap();
+
+        let masked = gltf_material_assets
+            .get(gltf_root.named_materials.get("Masked").unwrap())
+            .unwrap();
+        assert_eq!(masked.alpha_mode, AlphaMode::Mask(0.25));
+
+        let default_cutoff = gltf_material_assets
+            .get(gltf_root.named_materials.get("DefaultCutoff").unwrap())
+            .unwrap();
+        assert_eq!(default_cutoff.alpha_mode, AlphaMode::Mask(0.5));
+    }
+
+    #[test]
+    fn material_use_vertex_colors() {
+        let gltf_path = "test.gltf";
+        // A triangle drawn once with vertex colors and once without, with different materials.
+        let app = load_gltf_into_app(
+            gltf_path,
+            r#"
+{
+    "asset": {
+        "version": "2.0"
+    },
+    "buffers": [
+        {
+            "uri": "data:application/octet-stream;base64,AAAAAAAAAAAAAAAAAACAPwAAAAAAAAAAAAAAAAAAgD8AAAAAAACAPwAAAAAAAAAAAACAPwAAgD8AAAAAAAAAAAAAgD8AAIA/AAAAAAAAAAAAAIA/",
+            "byteLength": 84
+        }
+    ],
+    "bufferViews": [
+        { "buffer": 0, "byteOffset": 0, "byteLength": 36 },
+        { "buffer": 0, "byteOffset": 36, "byteLength": 48 }
+    ],
+    "accessors": [
+        {
+            "bufferView": 0,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC3",
+            "min": [0.0, 0.0, 0.0],
+            "max": [1.0, 1.0, 0.0]
+        },
+        {
+            "bufferView": 1,
+            "componentType": 5126,
+            "count": 3,
+            "type": "VEC4"
+        }
+    ],
+    "materials": [
+        { "name": "VertexColors" },
+        { "name": "NoVertexColors" },
+        { "name": "Unused" }
+    ],
+    "meshes": [
+        {
+            "primitives": [
+                { "attributes": { "POSITION": 0, "COLOR_0": 1 }, "material": 0 },
+                { "attributes": { "POSITION": 0 }, "material": 1 }
+            ]
+        }
+    ]
+}
+"#,
+        );
+        let asset_server = app.world().resource::<AssetServer>();
+        let handle = asset_server.load(gltf_path);
+        let gltf_root_assets = app.world().resource::<Assets<Gltf>>();
+        let gltf_material_assets = app.world().resource::<Assets<GltfMaterial>>();
+        let gltf_root = gltf_root_assets.get(&handle).unwrap();
+
+        let use_vertex_colors = |name: &str| {
+            gltf_material_assets
+                .get(gltf_root.named_materials.get(name).unwrap())
+                .unwrap()
+                .use_vertex_colors
+        };
+        assert!(use_vertex_colors("VertexColors"));
+        assert!(!use_vertex_colors("NoVertexColors"));
+        assert!(!use_vertex_colors("Unused"));
+    }
+
     #[test]
     fn node_hierarchy_no_hierarchy() {
         let gltf_path = "test.gltf";
@@ -10,7 +10,23 @@ use wgpu_types::Face;
 /// Data to build a Gltf Material
 ///
 /// See [`StandardMaterial`](https://docs.rs/bevy/latest/bevy/pbr/struct.StandardMaterial.html) for details
+///
+/// Use [`GltfMaterial::builder`] to construct one without having to account for feature-gated fields.
+///
+/// # Serialization
+///
+/// With the `serialize` feature, this type implements `serde`'s `Serialize` and `Deserialize`.
+/// Missing fields deserialize to their [`Default`] value.
+///
+/// **Textures are lost in a round trip.** Image handles can't be serialized without an asset server,
+/// so every `*_texture` field is skipped and deserializes as `None`. If you cache materials, store the
+/// textures' asset paths alongside the material and set the handles again after deserializing.
 #[derive(Asset, Debug, Clone, TypePath)]
+#[cfg_attr(
+    feature = "serialize",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(default)
+)]
 pub struct GltfMaterial {
     /// The color of the surface of the material before lighting.
     pub base_color: Color,
@@ -19,18 +35,34 @@ pub struct GltfMaterial {
     pub base_color_channel: UvChannel,
 
     /// The texture component of the material's color before lighting.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub base_color_texture: Option<Handle<Image>>,
 
+    /// The transform applied to the UVs before sampling the [`GltfMaterial::base_color_texture`].
+    /// If `None`, [`GltfMaterial::uv_transform`] is used.
+    pub base_color_uv_transform: Option<Affine2>,
+
     /// Color the material "emits" to the camera.
     pub emissive: LinearRgba,
 
+    /// Multiplier applied to [`GltfMaterial::emissive`], allowing emission above `1.0` for HDR effects
+    /// such as bloom.
+    ///
+    /// Loaded from the `KHR_materials_emissive_strength` extension. Default is `1.0`.
+    pub emissive_strength: f32,
+
     /// The UV channel to use for the [`GltfMaterial::emissive_texture`].
     pub emissive_channel: UvChannel,
 
     /// The emissive map, multiplies pixels with [`GltfMaterial::emissive`]
     /// to get the final "emitting" color of a surface.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub emissive_texture: Option<Handle<Image>>,
 
+    /// The transform applied to the UVs before sampling the [`GltfMaterial::emissive_texture`].
+    /// If `None`, [`GltfMaterial::uv_transform`] is used.
+    pub emissive_uv_transform: Option<Affine2>,
+
     /// Linear perceptual roughness.
     pub perceptual_roughness: f32,
 
@@ -41,8 +73,13 @@ pub struct GltfMaterial {
     pub metallic_roughness_channel: UvChannel,
 
     /// Metallic and roughness maps, stored as a single texture.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub metallic_roughness_texture: Option<Handle<Image>>,
 
+    /// The transform applied to the UVs before sampling the [`GltfMaterial::metallic_roughness_texture`].
+    /// If `None`, [`GltfMaterial::uv_transform`] is used.
+    pub metallic_roughness_uv_transform: Option<Affine2>,
+
     /// Specular intensity for non-metals on a linear scale of `[0.0, 1.0]`.
     pub reflectance: f32,
 
@@ -52,6 +89,7 @@ pub struct GltfMaterial {
 
     /// A map that specifies reflectance for non-metallic materials.
     #[cfg(feature = "pbr_specular_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub specular_texture: Option<Handle<Image>>,
 
     /// A color with which to modulate the [`GltfMaterial::reflectance`] for
@@ -66,6 +104,7 @@ pub struct GltfMaterial {
     /// A map that specifies color adjustment to be applied to the specular
     /// reflection for non-metallic materials.
     #[cfg(feature = "pbr_specular_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub specular_tint_texture: Option<Handle<Image>>,
 
     /// The amount of light transmitted _specularly_ through the material (i.e. via refraction).
@@ -78,6 +117,7 @@ pub struct GltfMaterial {
     /// A map that modulates specular transmission via its red channel. Multiplied by [`GltfMaterial::specular_transmission`]
     /// to obtain the final result.
     #[cfg(feature = "pbr_transmission_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub specular_transmission_texture: Option<Handle<Image>>,
 
     /// Thickness of the volume beneath the material surface.
@@ -90,11 +130,19 @@ pub struct GltfMaterial {
     /// A map that modulates thickness via its green channel. Multiplied by [`GltfMaterial::thickness`]
     /// to obtain the final result.
     #[cfg(feature = "pbr_transmission_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub thickness_texture: Option<Handle<Image>>,
 
     /// The [index of refraction](https://en.wikipedia.org/wiki/Refractive_index) of the material.
     pub ior: f32,
 
+    /// How much the [`GltfMaterial::ior`] varies with the wavelength of light, as `20 / abbe_number`.
+    ///
+    /// Loaded from the `KHR_materials_dispersion` extension. Default is `0.0`, meaning no dispersion.
+    /// Dispersion only affects transmitted light, so this does nothing when
+    /// [`GltfMaterial::specular_transmission`] is `0.0`.
+    pub dispersion: f32,
+
     /// How far, on average, light travels through the volume beneath the material's
     /// surface before being absorbed.
     pub attenuation_distance: f32,
@@ -106,14 +154,38 @@ pub struct GltfMaterial {
     pub normal_map_channel: UvChannel,
 
     /// Used to fake the lighting of bumps and dents on a material.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub normal_map_texture: Option<Handle<Image>>,
 
+    /// The transform applied to the UVs before sampling the [`GltfMaterial::normal_map_texture`].
+    /// If `None`, [`GltfMaterial::uv_transform`] is used.
+    pub normal_map_uv_transform: Option<Affine2>,
+
+    /// The scale of the X and Y components of the tangent-space normal sampled from the
+    /// [`GltfMaterial::normal_map_texture`].
+    ///
+    /// Loaded from `normalTexture.scale`. Default is `1.0`. Values other than `1.0` require the
+    /// shader to scale the X and Y components of the sampled normal before using it, which
+    /// `StandardMaterial` does with its own `normal_map_scale`.
+    pub normal_map_scale: f32,
+
     /// The UV channel to use for the [`GltfMaterial::occlusion_texture`].
     pub occlusion_channel: UvChannel,
 
     /// Specifies the level of exposure to ambient light.
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub occlusion_texture: Option<Handle<Image>>,
 
+    /// The transform applied to the UVs before sampling the [`GltfMaterial::occlusion_texture`].
+    /// If `None`, [`GltfMaterial::uv_transform`] is used.
+    pub occlusion_uv_transform: Option<Affine2>,
+
+    /// How strongly the [`GltfMaterial::occlusion_texture`] is applied, within `[0.0, 1.0]`.
+    ///
+    /// Loaded from `occlusionTexture.strength`, where `0.0` means no occlusion and `1.0` means
+    /// full occlusion. Default is `1.0`.
+    pub occlusion_strength: f32,
+
     /// An extra thin translucent layer on top of the main PBR layer. This is
     /// typically used for painted surfaces.
     pub clearcoat: f32,
@@ -130,6 +202,7 @@ pub struct GltfMaterial {
     /// the red channel. Values sampled from this texture are multiplied by the
     /// main [`GltfMaterial::clearcoat`] factor.
     #[cfg(feature = "pbr_multi_layer_material_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub clearcoat_texture: Option<Handle<Image>>,
 
     /// The UV channel to use for the [`GltfMaterial::clearcoat_roughness_texture`].
@@ -140,6 +213,7 @@ pub struct GltfMaterial {
     /// the green channel. Values from this texture are multiplied by the main
     /// [`GltfMaterial::clearcoat_perceptual_roughness`] factor.
     #[cfg(feature = "pbr_multi_layer_material_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub clearcoat_roughness_texture: Option<Handle<Image>>,
 
     /// The UV channel to use for the [`GltfMaterial::clearcoat_normal_texture`].
@@ -151,6 +225,7 @@ pub struct GltfMaterial {
     /// scratches on an outer layer of varnish. Normal maps are in the same
     /// format as [`GltfMaterial::normal_map_texture`].
     #[cfg(feature = "pbr_multi_layer_material_textures")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub clearcoat_normal_texture: Option<Handle<Image>>,
 
     /// Increases the roughness along a specific direction, so that the specular
@@ -169,8 +244,78 @@ pub struct GltfMaterial {
     /// [`GltfMaterial::anisotropy_strength`] and
     /// [`GltfMaterial::anisotropy_rotation`] to vary across the mesh.
     #[cfg(feature = "pbr_anisotropy_texture")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
     pub anisotropy_texture: Option<Handle<Image>>,
 
+    /// The strength of the thin-film iridescence effect, within `[0.0, 1.0]`.
+    ///
+    /// Iridescence isn't rendered by `StandardMaterial`, so this and the other
+    /// iridescence fields are only available for custom materials.
+    pub iridescence: f32,
+
+    /// The index of refraction of the iridescent thin-film layer.
+    pub iridescence_ior: f32,
+
+    /// The minimum thickness of the thin-film layer, in nanometers.
+    pub iridescence_thickness_min: f32,
+
+    /// The maximum thickness of the thin-film layer, in nanometers.
+    pub iridescence_thickness_max: f32,
+
+    /// The UV channel to use for the [`GltfMaterial::iridescence_texture`].
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub iridescence_channel: UvChannel,
+
+    /// An image texture that specifies the strength of the iridescence in the
+    /// red channel. Values sampled from this texture are multiplied by the main
+    /// [`GltfMaterial::iridescence`] factor.
+    #[cfg(feature = "gltf_iridescence_texture")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub iridescence_texture: Option<Handle<Image>>,
+
+    /// The UV channel to use for the [`GltfMaterial::iridescence_thickness_texture`].
+    #[cfg(feature = "gltf_iridescence_texture")]
+    pub iridescence_thickness_channel: UvChannel,
+
+    /// An image texture that specifies the thickness of the thin-film layer in
+    /// the green channel, interpolating between
+    /// [`GltfMaterial::iridescence_thickness_min`] and
+    /// [`GltfMaterial::iridescence_thickness_max`].
+    #[cfg(feature = "gltf_iridescence_texture")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub iridescence_thickness_texture: Option<Handle<Image>>,
+
+    /// The color of the sheen layer, a retro-reflective highlight seen on cloth-like
+    /// surfaces such as velvet.
+    ///
+    /// Defaults to [`Color::BLACK`], which disables the sheen layer.
+    pub sheen_color: Color,
+
+    /// The roughness of the sheen layer, within `[0.0, 1.0]`.
+    pub sheen_roughness: f32,
+
+    /// The UV channel to use for the [`GltfMaterial::sheen_color_texture`].
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub sheen_color_channel: UvChannel,
+
+    /// An image texture that specifies the sheen color in its RGB channels.
+    /// Values sampled from this texture are multiplied by the main
+    /// [`GltfMaterial::sheen_color`] factor.
+    #[cfg(feature = "pbr_sheen_texture")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub sheen_color_texture: Option<Handle<Image>>,
+
+    /// The UV channel to use for the [`GltfMaterial::sheen_roughness_texture`].
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub sheen_roughness_channel: UvChannel,
+
+    /// An image texture that specifies the sheen roughness in its alpha
+    /// channel. Values sampled from this texture are multiplied by the main
+    /// [`GltfMaterial::sheen_roughness`] factor.
+    #[cfg(feature = "pbr_sheen_texture")]
+    #[cfg_attr(feature = "serialize", serde(skip))]
+    pub sheen_roughness_texture: Option<Handle<Image>>,
+
     /// Support two-sided lighting by automatically flipping the normals for "back" faces
     /// within the PBR lighting shader.
     pub double_sided: bool,
@@ -182,10 +327,20 @@ pub struct GltfMaterial {
     /// Whether to apply only the base color to this material.
     pub unlit: bool,
 
+    /// Whether any mesh primitive using this material has vertex colors (`COLOR_0`),
+    /// which multiply the [`GltfMaterial::base_color`].
+    ///
+    /// `StandardMaterial` applies a mesh's vertex colors whenever they are present, so this is
+    /// mostly useful for custom materials that need to opt in to them. Default is `false`.
+    pub use_vertex_colors: bool,
+
     /// How to apply the alpha channel of the `base_color_texture`.
     pub alpha_mode: AlphaMode,
 
     /// The transform applied to the UVs corresponding to `ATTRIBUTE_UV_0` on the mesh before sampling. Default is identity.
+    ///
+    /// This is shared by all textures that don't have their own transform, such as
+    /// [`GltfMaterial::normal_map_uv_transform`].
     pub uv_transform: Affine2,
 }
 
@@ -197,15 +352,19 @@ impl Default for GltfMaterial {
             base_color: Color::WHITE,
             base_color_channel: UvChannel::Uv0,
             base_color_texture: None,
+            base_color_uv_transform: None,
             emissive: LinearRgba::BLACK,
+            emissive_strength: 1.0,
             emissive_channel: UvChannel::Uv0,
             emissive_texture: None,
+            emissive_uv_transform: None,
             // Matches Blender's default roughness.
             perceptual_roughness: 0.5,
             // Metallic should generally be set to 0.0 or 1.0.
             metallic: 0.0,
             metallic_roughness_channel: UvChannel::Uv0,
             metallic_roughness_texture: None,
+            metallic_roughness_uv_transform: None,
             // Minimum real-world reflectance is 2%, most materials between 2-5%
             // Expressed in a linear scale and equivalent to 4% reflectance see
             // <https://google.github.io/filament/Material%20Properties.pdf>
@@ -221,12 +380,17 @@ impl Default for GltfMaterial {
             #[cfg(feature = "pbr_transmission_textures")]
             thickness_texture: None,
             ior: 1.5,
+            dispersion: 0.0,
             attenuation_color: Color::WHITE,
             attenuation_distance: f32::INFINITY,
             occlusion_channel: UvChannel::Uv0,
             occlusion_texture: None,
+            occlusion_uv_transform: None,
+            occlusion_strength: 1.0,
             normal_map_channel: UvChannel::Uv0,
             normal_map_texture: None,
+            normal_map_uv_transform: None,
+            normal_map_scale: 1.0,
             #[cfg(feature = "pbr_specular_textures")]
             specular_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_specular_textures")]
@@ -256,11 +420,472 @@ impl Default for GltfMaterial {
             anisotropy_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_anisotropy_texture")]
             anisotropy_texture: None,
+            iridescence: 0.0,
+            iridescence_ior: 1.3,
+            iridescence_thickness_min: 100.0,
+            iridescence_thickness_max: 400.0,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_channel: UvChannel::Uv0,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_texture: None,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_channel: UvChannel::Uv0,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_texture: None,
+            sheen_color: Color::BLACK,
+            sheen_roughness: 0.0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_channel: UvChannel::Uv0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_texture: None,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_channel: UvChannel::Uv0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_texture: None,
             double_sided: false,
             cull_mode: Some(Face::Back),
             unlit: false,
+            use_vertex_colors: false,
             alpha_mode: AlphaMode::Opaque,
             uv_transform: Affine2::IDENTITY,
         }
     }
 }
+
+impl GltfMaterial {
+    /// Creates a [`GltfMaterialBuilder`] starting from [`GltfMaterial::default`].
+    pub fn builder() -> GltfMaterialBuilder {
+        GltfMaterialBuilder::default()
+    }
+
+    /// Returns `true` if `self` and `other` have exactly the same contents.
+    ///
+    /// This compares every field, including the ones that only exist with a given cargo feature.
+    /// Textures are compared by [`Handle`] identity, and `f32` fields are compared bit for bit,
+    /// so two `NaN` values with the same bits are equal while `0.0` and `-0.0` are not.
+    /// This makes it suitable for collapsing duplicate materials into a single shared handle.
+    ///
+    /// ```
+    /// # use bevy_gltf::GltfMaterial;
+    /// let a = GltfMaterial::builder().metallic(1.0).build();
+    /// let b = a.clone();
+    /// let c = GltfMaterial::builder().metallic(0.5).build();
+    ///
+    /// assert!(a.content_eq(&b));
+    /// assert!(!a.content_eq(&c));
+    /// ```
+    pub fn content_eq(&self, other: &GltfMaterial) -> bool {
+        // Destructure exhaustively, so that new fields can't be left out of the comparison.
+        let GltfMaterial {
+            base_color,
+            base_color_channel,
+            base_color_texture,
+            base_color_uv_transform,
+            emissive,
+            emissive_strength,
+            emissive_channel,
+            emissive_texture,
+            emissive_uv_transform,
+            perceptual_roughness,
+            metallic,
+            metallic_roughness_channel,
+            metallic_roughness_texture,
+            metallic_roughness_uv_transform,
+            reflectance,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_channel,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_texture,
+            specular_tint,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_tint_channel,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_tint_texture,
+            specular_transmission,
+            #[cfg(feature = "pbr_transmission_textures")]
+            specular_transmission_channel,
+            #[cfg(feature = "pbr_transmission_textures")]
+            specular_transmission_texture,
+            thickness,
+            #[cfg(feature = "pbr_transmission_textures")]
+            thickness_channel,
+            #[cfg(feature = "pbr_transmission_textures")]
+            thickness_texture,
+            ior,
+            dispersion,
+            attenuation_distance,
+            attenuation_color,
+            normal_map_channel,
+            normal_map_texture,
+            normal_map_uv_transform,
+            normal_map_scale,
+            occlusion_channel,
+            occlusion_texture,
+            occlusion_uv_transform,
+            occlusion_strength,
+            clearcoat,
+            clearcoat_perceptual_roughness,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_channel,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_texture,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_roughness_channel,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_roughness_texture,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_normal_channel,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_normal_texture,
+            anisotropy_strength,
+            anisotropy_rotation,
+            #[cfg(feature = "pbr_anisotropy_texture")]
+            anisotropy_channel,
+            #[cfg(feature = "pbr_anisotropy_texture")]
+            anisotropy_texture,
+            iridescence,
+            iridescence_ior,
+            iridescence_thickness_min,
+            iridescence_thickness_max,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_channel,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_texture,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_channel,
+            #[cfg(feature = "gltf_iridescence_texture")]
+            iridescence_thickness_texture,
+            sheen_color,
+            sheen_roughness,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_channel,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_texture,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_channel,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_texture,
+            double_sided,
+            cull_mode,
+            unlit,
+            use_vertex_colors,
+            alpha_mode,
+            uv_transform,
+        } = self;
+
+        #[expect(clippy::allow_attributes, reason = "`unused_mut` is not always linted")]
+        #[allow(
+            unused_mut,
+            reason = "Only feature-gated fields update `eq`, so it may not need to be mutable."
+        )]
+        let mut eq = *base_color == other.base_color
+            && *base_color_channel == other.base_color_channel
+            && *base_color_texture == other.base_color_texture
+            && *base_color_uv_transform == other.base_color_uv_transform
+            && *emissive == other.emissive
+            && emissive_strength.to_bits() == other.emissive_strength.to_bits()
+            && *emissive_channel == other.emissive_channel
+            && *emissive_texture == other.emissive_texture
+            && *emissive_uv_transform == other.emissive_uv_transform
+            && perceptual_roughness.to_bits() == other.perceptual_roughness.to_bits()
+            && metallic.to_bits() == other.metallic.to_bits()
+            && *metallic_roughness_channel == other.metallic_roughness_channel
+            && *metallic_roughness_texture == other.metallic_roughness_texture
+            && *metallic_roughness_uv_transform == other.metallic_roughness_uv_transform
+            && reflectance.to_bits() == other.reflectance.to_bits()
+            && *specular_tint == other.specular_tint
+            && specular_transmission.to_bits() == other.specular_transmission.to_bits()
+            && thickness.to_bits() == other.thickness.to_bits()
+            && ior.to_bits() == other.ior.to_bits()
+            && dispersion.to_bits() == other.dispersion.to_bits()
+            && attenuation_distance.to_bits() == other.attenuation_distance.to_bits()
+            && *attenuation_color == other.attenuation_color
+            && *normal_map_channel == other.normal_map_channel
+            && *normal_map_texture == other.normal_map_texture
+            && *normal_map_uv_transform == other.normal_map_uv_transform
+            && normal_map_scale.to_bits() == other.normal_map_scale.to_bits()
+            && *occlusion_channel == other.occlusion_channel
+            && *occlusion_texture == other.occlusion_texture
+            && *occlusion_uv_transform == other.occlusion_uv_transform
+            && occlusion_strength.to_bits() == other.occlusion_strength.to_bits()
+            && clearcoat.to_bits() == other.clearcoat.to_bits()
+            && clearcoat_perceptual_roughness.to_bits()
+                == other.clearcoat_perceptual_roughness.to_bits()
+            && anisotropy_strength.to_bits() == other.anisotropy_strength.to_bits()
+            && anisotropy_rotation.to_bits() == other.anisotropy_rotation.to_bits()
+            && iridescence.to_bits() == other.iridescence.to_bits()
+            && iridescence_ior.to_bits() == other.iridescence_ior.to_bits()
+            && iridescence_thickness_min.to_bits() == other.iridescence_thickness_min.to_bits()
+            && iridescence_thickness_max.to_bits() == other.iridescence_thickness_max.to_bits()
+            && *sheen_color == other.sheen_color
+            && sheen_roughness.to_bits() == other.sheen_roughness.to_bits()
+            && *double_sided == other.double_sided
+            && *cull_mode == other.cull_mode
+            && *unlit == other.unlit
+            && *use_vertex_colors == other.use_vertex_colors
+            && *alpha_mode == other.alpha_mode
+            && *uv_transform == other.uv_transform;
+
+        #[cfg(feature = "pbr_specular_textures")]
+        {
+            eq = eq
+                && *specular_channel == other.specular_channel
+                && *specular_texture == other.specular_texture
+                && *specular_tint_channel == other.specular_tint_channel
+                && *specular_tint_texture == other.specular_tint_texture;
+        }
+
+        #[cfg(feature = "pbr_transmission_textures")]
+        {
+            eq = eq
+                && *specular_transmission_channel == other.specular_transmission_channel
+                && *specular_transmission_texture == other.specular_transmission_texture
+                && *thickness_channel == other.thickness_channel
+                && *thickness_texture == other.thickness_texture;
+        }
+
+        #[cfg(feature = "pbr_multi_layer_material_textures")]
+        {
+            eq = eq
+                && *clearcoat_channel == other.clearcoat_channel
+                && *clearcoat_texture == other.clearcoat_texture
+                && *clearcoat_roughness_channel == other.clearcoat_roughness_channel
+                && *clearcoat_roughness_texture == other.clearcoat_roughness_texture
+                && *clearcoat_normal_channel == other.clearcoat_normal_channel
+                && *clearcoat_normal_texture == other.clearcoat_normal_texture;
+        }
+
+        #[cfg(feature = "pbr_anisotropy_texture")]
+        {
+            eq = eq
+                && *anisotropy_channel == other.anisotropy_channel
+                && *anisotropy_texture == other.anisotropy_texture;
+        }
+
+        #[cfg(feature = "gltf_iridescence_texture")]
+        {
+            eq = eq
+                && *iridescence_channel == other.iridescence_channel
+                && *iridescence_texture == other.iridescence_texture
+                && *iridescence_thickness_channel == other.iridescence_thickness_channel
+                && *iridescence_thickness_texture == other.iridescence_thickness_texture;
+        }
+
+        #[cfg(feature = "pbr_sheen_texture")]
+        {
+            eq = eq
+                && *sheen_color_channel == other.sheen_color_channel
+                && *sheen_color_texture == other.sheen_color_texture
+                && *sheen_roughness_channel == other.sheen_roughness_channel
+                && *sheen_roughness_texture == other.sheen_roughness_texture;
+        }
+
+        eq
+    }
+}
+
+/// Builder for [`GltfMaterial`].
+///
+/// Setters for fields that only exist with a given cargo feature are always available,
+/// and do nothing when that feature is disabled.
+///
+/// ```
+/// # use bevy_color::Color;
+/// # use bevy_gltf::GltfMaterial;
+/// # use bevy_material::AlphaMode;
+/// let material = GltfMaterial::builder()
+///     .base_color(Color::srgb(0.8, 0.2, 0.2))
+///     .metallic(1.0)
+///     .anisotropy_texture(None)
+///     .alpha_mode(AlphaMode::Mask(0.5))
+///     .build();
+///
+/// assert_eq!(material.metallic, 1.0);
+/// ```
+#[derive(Debug, Clone, Default)]
+pub struct GltfMaterialBuilder {
+    material: GltfMaterial,
+}
+
+macro_rules! setters {
+    ($($field:ident: $ty:ty),* $(,)?) => {$(
+        #[doc = concat!("Sets [`GltfMaterial::", stringify!($field), "`].")]
+        pub fn $field(mut self, $field: $ty) -> Self {
+            self.material.$field = $field;
+            self
+        }
+    )*};
+}
+
+macro_rules! feature_gated_setters {
+    ($feature:literal => $($field:ident: $ty:ty),* $(,)?) => {$(
+        #[doc = concat!(
+            "Sets `GltfMaterial::", stringify!($field), "`.\n\n",
+            "Does nothing unless the `", $feature, "` feature is enabled."
+        )]
+        pub fn $field(mut self, $field: $ty) -> Self {
+            #[cfg(feature = $feature)]
+            {
+                self.material.$field = $field;
+            }
+            #[cfg(not(feature = $feature))]
+            let _ = (&mut self, $field);
+            self
+        }
+    )*};
+}
+
+impl GltfMaterialBuilder {
+    /// Creates a builder starting from an existing material.
+    pub fn from_material(material: GltfMaterial) -> Self {
+        Self { material }
+    }
+
+    /// Returns the built [`GltfMaterial`].
+    pub fn build(self) -> GltfMaterial {
+        self.material
+    }
+
+    setters! {
+        base_color: Color,
+        base_color_channel: UvChannel,
+        base_color_texture: Option<Handle<Image>>,
+        base_color_uv_transform: Option<Affine2>,
+        emissive: LinearRgba,
+        emissive_strength: f32,
+        emissive_channel: UvChannel,
+        emissive_texture: Option<Handle<Image>>,
+        emissive_uv_transform: Option<Affine2>,
+        perceptual_roughness: f32,
+        metallic: f32,
+        metallic_roughness_channel: UvChannel,
+        metallic_roughness_texture: Option<Handle<Image>>,
+        metallic_roughness_uv_transform: Option<Affine2>,
+        reflectance: f32,
+        specular_tint: Color,
+        specular_transmission: f32,
+        thickness: f32,
+        ior: f32,
+        dispersion: f32,
+        attenuation_distance: f32,
+        attenuation_color: Color,
+        normal_map_channel: UvChannel,
+        normal_map_texture: Option<Handle<Image>>,
+        normal_map_uv_transform: Option<Affine2>,
+        normal_map_scale: f32,
+        occlusion_channel: UvChannel,
+        occlusion_texture: Option<Handle<Image>>,
+        occlusion_uv_transform: Option<Affine2>,
+        occlusion_strength: f32,
+        clearcoat: f32,
+        clearcoat_perceptual_roughness: f32,
+        anisotropy_strength: f32,
+        anisotropy_rotation: f32,
+        iridescence: f32,
+        iridescence_ior: f32,
+        iridescence_thickness_min: f32,
+        iridescence_thickness_max: f32,
+        sheen_color: Color,
+        sheen_roughness: f32,
+        double_sided: bool,
+        cull_mode: Option<Face>,
+        unlit: bool,
+        use_vertex_colors: bool,
+        alpha_mode: AlphaMode,
+        uv_transform: Affine2,
+    }
+
+    feature_gated_setters! {
+        "pbr_specular_textures" =>
+        specular_channel: UvChannel,
+        specular_texture: Option<Handle<Image>>,
+        specular_tint_channel: UvChannel,
+        specular_tint_texture: Option<Handle<Image>>,
+    }
+
+    feature_gated_setters! {
+        "pbr_transmission_textures" =>
+        specular_transmission_channel: UvChannel,
+        specular_transmission_texture: Option<Handle<Image>>,
+        thickness_channel: UvChannel,
+        thickness_texture: Option<Handle<Image>>,
+    }
+
+    feature_gated_setters! {
+        "pbr_multi_layer_material_textures" =>
+        clearcoat_channel: UvChannel,
+        clearcoat_texture: Option<Handle<Image>>,
+        clearcoat_roughness_channel: UvChannel,
+        clearcoat_roughness_texture: Option<Handle<Image>>,
+        clearcoat_normal_channel: UvChannel,
+        clearcoat_normal_texture: Option<Handle<Image>>,
+    }
+
+    feature_gated_setters! {
+        "pbr_anisotropy_texture" =>
+        anisotropy_channel: UvChannel,
+        anisotropy_texture: Option<Handle<Image>>,
+    }
+
+    feature_gated_setters! {
+        "gltf_iridescence_texture" =>
+        iridescence_channel: UvChannel,
+        iridescence_texture: Option<Handle<Image>>,
+        iridescence_thickness_channel: UvChannel,
+        iridescence_thickness_texture: Option<Handle<Image>>,
+    }
+
+    feature_gated_setters! {
+        "pbr_sheen_texture" =>
+        sheen_color_channel: UvChannel,
+        sheen_color_texture: Option<Handle<Image>>,
+        sheen_roughness_channel: UvChannel,
+        sheen_roughness_texture: Option<Handle<Image>>,
+    }
+}
+
+#[cfg(all(test, feature = "serialize"))]
+mod tests {
+    use bevy_asset::Handle;
+    use bevy_color::{Color, LinearRgba};
+    use bevy_material::AlphaMode;
+    use bevy_math::Affine2;
+    use wgpu_types::Face;
+
+    use super::GltfMaterial;
+
+    fn round_trip(material: &GltfMaterial) -> GltfMaterial {
+        let json = serde_json::to_string(material).unwrap();
+        serde_json::from_str(&json).unwrap()
+    }
+
+    #[test]
+    fn serde_round_trip() {
+        let material = GltfMaterial::builder()
+            .base_color(Color::srgb(0.2, 0.4, 0.6))
+            .base_color_uv_transform(Some(Affine2::from_scale((2.0, 2.0).into())))
+            .emissive(LinearRgba::rgb(1.0, 0.5, 0.0))
+            .emissive_strength(4.0)
+            .metallic(0.7)
+            .ior(1.33)
+            .cull_mode(Some(Face::Front))
+            .alpha_mode(AlphaMode::Mask(0.25))
+            .build();
+
+        assert!(round_trip(&material).content_eq(&material));
+    }
+
+    #[test]
+    fn serde_round_trip_drops_textures() {
+        let material = GltfMaterial::builder()
+            .metallic(0.7)
+            .base_color_texture(Some(Handle::default()))
+            .normal_map_texture(Some(Handle::default()))
+            .build();
+
+        let deserialized = round_trip(&material);
+        assert_eq!(deserialized.base_color_texture, None);
+        assert_eq!(deserialized.normal_map_texture, None);
+        assert_eq!(deserialized.metallic, 0.7);
+    }
+}
@@ -1,13 +1,17 @@
-//! A module adding debug visualization of [`PointLight`]s, [`SpotLight`]s and [`DirectionalLight`]s.
+//! A module adding debug visualization of [`PointLight`]s, [`SpotLight`]s, [`DirectionalLight`]s
+//! and [`LightProbe`]s.
 
 use core::f32::consts::PI;
 
 use bevy_gizmos::primitives::dim3::GizmoPrimitive3d;
 
-use crate::{DirectionalLight, PointLight, SpotLight};
+use crate::{DirectionalLight, LightProbe, ParallaxCorrection, PointLight, SpotLight};
 use bevy_app::{Plugin, PostUpdate};
 use bevy_color::{
-    palettes::basic::{BLUE, GREEN, RED},
+    palettes::{
+        basic::{BLUE, GREEN, RED},
+        css::{CORNFLOWER_BLUE, CRIMSON, TAN},
+    },
     Color, Oklcha,
 };
 use bevy_ecs::{
@@ -24,11 +28,14 @@ use bevy_math::{
     Isometry3d, Quat, Vec3,
 };
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
-use bevy_transform::{components::GlobalTransform, TransformSystems};
+use bevy_transform::{
+    components::{GlobalTransform, Transform},
+    TransformSystems,
+};
 
 use bevy_gizmos::{
     config::{GizmoConfigGroup, GizmoConfigStore},
-    gizmos::Gizmos,
+    gizmos::{GizmoBuffer, Gizmos},
     AppGizmoBuilder,
 };
 
@@ -307,3 +314,229 @@ fn draw_all_lights(
         }
     }
 }
+
+/// A extension trait adding `light_probe` to [`Gizmos`].
+pub trait GizmoLightProbe<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Draw the boxes associated with a [`LightProbe`] at the given `transform`.
+    ///
+    /// This draws the bounds of the light probe, the inner box where its falloff
+    /// begins, and, for [`ParallaxCorrection::Custom`], the parallax correction
+    /// bounds. The colors can be changed with the returned builder.
+    ///
+    /// # Example
+    /// ```
+    /// # use bevy_gizmos::prelude::*;
+    /// # use bevy_ecs::prelude::*;
+    /// # use bevy_light::{gizmos::GizmoLightProbe, LightProbe, ParallaxCorrection};
+    /// # use bevy_transform::components::Transform;
+    /// fn system(
+    ///     mut gizmos: Gizmos,
+    ///     light_probes: Query<(&LightProbe, &ParallaxCorrection, &Transform)>,
+    /// ) {
+    ///     for (light_probe, parallax_correction, transform) in &light_probes {
+    ///         gizmos.light_probe(*transform, light_probe, parallax_correction);
+    ///     }
+    /// }
+    /// # bevy_ecs::system::assert_is_system(system);
+    /// ```
+    fn light_probe(
+        &mut self,
+        transform: Transform,
+        light_probe: &LightProbe,
+        parallax_correction: &ParallaxCorrection,
+    ) -> LightProbeGizmoBuilder<'_, Config, Clear>;
+}
+
+impl<Config, Clear> GizmoLightProbe<Config, Clear> for GizmoBuffer<Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn light_probe(
+        &mut self,
+        transform: Transform,
+        light_probe: &LightProbe,
+        parallax_correction: &ParallaxCorrection,
+    ) -> LightProbeGizmoBuilder<'_, Config, Clear> {
+        LightProbeGizmoBuilder {
+            gizmos: self,
+            transform,
+            falloff: light_probe.falloff,
+            parallax_correction: *parallax_correction,
+            bounds_color: TAN.into(),
+            falloff_color: CRIMSON.into(),
+            parallax_correction_color: CORNFLOWER_BLUE.into(),
+            inner_dashes: None,
+        }
+    }
+}
+
+/// A builder returned by [`GizmoLightProbe::light_probe`].
+pub struct LightProbeGizmoBuilder<'a, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    gizmos: &'a mut GizmoBuffer<Config, Clear>,
+    transform: Transform,
+    falloff: Vec3,
+    parallax_correction: ParallaxCorrection,
+    bounds_color: Color,
+    falloff_color: Color,
+    parallax_correction_color: Color,
+    inner_dashes: Option<(f32, f32)>,
+}
+
+impl<Config, Clear> LightProbeGizmoBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    /// Set the color of the light probe bounds.
+    ///
+    /// Defaults to [`TAN`].
+    pub fn bounds_color(mut self, color: impl Into<Color>) -> Self {
+        self.bounds_color = color.into();
+        self
+    }
+
+    /// Set the color of the box inside which the light probe has full influence.
+    ///
+    /// Defaults to [`CRIMSON`].
+    pub fn falloff_color(mut self, color: impl Into<Color>) -> Self {
+        self.falloff_color = color.into();
+        self
+    }
+
+    /// Set the color of the parallax correction bounds.
+    ///
+    /// Defaults to [`CORNFLOWER_BLUE`].
+    pub fn parallax_correction_color(mut self, color: impl Into<Color>) -> Self {
+        self.parallax_correction_color = color.into();
+        self
+    }
+
+    /// Draw the falloff and parallax correction boxes with dashed lines, so they can be told
+    /// apart from the bounds where they overlap.
+    ///
+    /// The lengths are in world units, see [`GizmoBuffer::dashed_line`].
+    /// By default, all boxes are drawn with solid lines.
+    pub fn dashed_inner_boxes(mut self, dash_length: f32, gap_length: f32) -> Self {
+        self.inner_dashes = Some((dash_length, gap_length));
+        self
+    }
+
+    /// Draws a box that lies inside the bounds, dashed if requested.
+    fn inner_box(&mut self, transform: Transform, color: Color) {
+        let Some((dash_length, gap_length)) = self.inner_dashes else {
+            self.gizmos.cube(transform, color);
+            return;
+        };
+
+        // Dash each of the 12 edges of the unit cube, 4 parallel to each axis.
+        for axis in 0..3 {
+            for [u, v] in [[-0.5, -0.5], [-0.5, 0.5], [0.5, -0.5], [0.5, 0.5]] {
+                let mut start = Vec3::ZERO;
+                start[(axis + 1) % 3] = u;
+                start[(axis + 2) % 3] = v;
+                let mut end = start;
+                start[axis] = -0.5;
+                end[axis] = 0.5;
+                self.gizmos.dashed_line(
+                    transform.transform_point(start),
+                    transform.transform_point(end),
+                    dash_length,
+                    gap_length,
+                    color,
+                );
+            }
+        }
+    }
+}
+
+impl<Config, Clear> Drop for LightProbeGizmoBuilder<'_, Config, Clear>
+where
+    Config: GizmoConfigGroup,
+    Clear: 'static + Send + Sync,
+{
+    fn drop(&mut self) {
+        let transform = self.transform;
+
+        self.gizmos.cube(transform, self.bounds_color);
+
+        self.inner_box(
+            Transform {
+                scale: transform.scale * (Vec3::ONE - self.falloff),
+                ..transform
+            },
+            self.falloff_color,
+        );
+
+        if let ParallaxCorrection::Custom(parallax_correction_bounds) = self.parallax_correction {
+            // The custom bounds are half extents, while the cube spans from -0.5 to 0.5.
+            self.inner_box(
+                Transform {
+                    scale: transform.scale * 2.0 * parallax_correction_bounds,
+                    ..transform
+                },
+                self.parallax_correction_color,
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_color::{palettes::basic::LIME, LinearRgba};
+    use bevy_gizmos::{config::DefaultGizmoConfigGroup, gizmos::GizmoBuffer};
+    use bevy_math::{vec3, Quat, Vec3};
+    use bevy_transform::components::Transform;
+
+    use super::GizmoLightProbe;
+    use crate::{LightProbe, ParallaxCorrection};
+
+    #[test]
+    fn light_probe_parallax_correction_box() {
+        let bounds = vec3(0.5, 1.0, 2.0);
+        let transform = Transform {
+            translation: vec3(1.0, 2.0, 3.0),
+            rotation: Quat::from_rotation_y(0.5),
+            scale: Vec3::splat(3.0),
+        };
+
+        let mut gizmos = GizmoBuffer::<DefaultGizmoConfigGroup, ()>::default();
+        gizmos
+            .light_probe(
+                transform,
+                &LightProbe::default(),
+                &ParallaxCorrection::Custom(bounds),
+            )
+            .parallax_correction_color(LIME);
+
+        let color = LinearRgba::from(LIME);
+        let buffer = gizmos.buffer();
+        let positions: Vec<Vec3> = buffer
+            .strip_positions
+            .iter()
+            .zip(buffer.strip_colors)
+            .chain(buffer.list_positions.iter().zip(buffer.list_colors))
+            .filter(|(_, vertex_color)| **vertex_color == color)
+            .map(|(position, _)| *position)
+            .collect();
+        assert!(!positions.is_empty());
+
+        // Every corner of the box lies on the bounds, in the light probe's local space.
+        let probe_from_world = transform.compute_affine().inverse();
+        for position in positions {
+            let local = probe_from_world.transform_point3(position);
+            assert!(
+                local.abs().abs_diff_eq(bounds, 1e-4),
+                "{local} isn't a corner of {bounds}"
+            );
+        }
+    }
+}
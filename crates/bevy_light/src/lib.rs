@@ -34,9 +34,9 @@ use bevy_camera::visibility::SetViewVisibility;
 
 mod probe;
 pub use probe::{
-    automatically_add_parallax_correction_components, AtmosphereEnvironmentMapLight,
-    EnvironmentMapLight, GeneratedEnvironmentMapLight, IrradianceVolume, LightProbe,
-    ParallaxCorrection, Skybox,
+    automatically_add_parallax_correction_components, update_light_probe_influences,
+    AtmosphereEnvironmentMapLight, EnvironmentMapLight, GeneratedEnvironmentMapLight,
+    IrradianceVolume, LightProbe, LightProbeInfluence, ParallaxCorrection, Skybox,
 };
 pub mod atmosphere;
 pub use atmosphere::Atmosphere;
@@ -75,7 +75,9 @@ pub mod prelude {
 
     #[doc(hidden)]
     #[cfg(feature = "bevy_gizmos")]
-    pub use crate::gizmos::{LightGizmoColor, LightGizmoConfigGroup, ShowLightGizmo};
+    pub use crate::gizmos::{
+        GizmoLightProbe, LightGizmoColor, LightGizmoConfigGroup, ShowLightGizmo,
+    };
 }
 
 use crate::{
@@ -229,6 +231,7 @@ impl Plugin for LightPlugin {
                         .in_set(SimulationLightSystems::UpdateDirectionalLightCascades)
                         .after(TransformSystems::Propagate)
                         .after(CameraUpdateSystems),
+                    update_light_probe_influences.after(TransformSystems::Propagate),
                 ),
             );
 
@@ -5,7 +5,8 @@ use bevy_ecs::prelude::*;
 use bevy_image::Image;
 use bevy_math::{Quat, UVec2, Vec3};
 use bevy_reflect::prelude::*;
-use bevy_transform::components::Transform;
+use bevy_transform::components::{GlobalTransform, Transform};
+use core::cmp::Ordering;
 use wgpu_types::{
     Extent3d, TextureDimension, TextureFormat, TextureViewDescriptor, TextureViewDimension,
 };
@@ -55,7 +56,9 @@ use crate::cluster::ClusterVisibilityClass;
 /// Multiple light probes of the same type can apply to a single fragment. By
 /// setting falloff regions appropriately, one can achieve a gradual blend from
 /// one reflection probe and/or irradiance volume to another as objects move
-/// between them.
+/// between them. If the overlapping light probes have different
+/// [`LightProbe::priority`] values, the ones with the highest priority take
+/// precedence instead.
 ///
 /// A terminology note: Unfortunately, there is little agreement across game and
 /// graphics engines as to what to call the various techniques that Bevy groups
@@ -87,6 +90,21 @@ pub struct LightProbe {
     /// In other words, falloff modifies the *interior* of the light probe cube
     /// instead of increasing the *exterior* boundaries of the cube.
     pub falloff: Vec3,
+
+    /// The priority of this light probe relative to other overlapping light
+    /// probes of the same type.
+    ///
+    /// Where light probes overlap, the ones with the highest priority receive
+    /// their full falloff weight, and lower-priority light probes only fill in
+    /// the influence that remains, for example inside the falloff region of
+    /// the higher-priority probe. Light probes with equal priorities are
+    /// blended together based on their falloff alone.
+    ///
+    /// When a view contains more light probes than can be rendered at once,
+    /// higher-priority light probes are kept first.
+    ///
+    /// Defaults to 0.
+    pub priority: i32,
 }
 
 impl LightProbe {
@@ -95,6 +113,120 @@ impl LightProbe {
     pub fn new() -> Self {
         Self::default()
     }
+
+    /// Returns the influence of this light probe, from 0 to 1, at the given
+    /// position in light probe space, based on its [`LightProbe::falloff`].
+    ///
+    /// The influence ramps up linearly over the falloff range on each axis
+    /// separately, and the smallest of the per-axis values is used. This is
+    /// the same weight that the renderer uses to blend light probes, before
+    /// [`LightProbe::priority`] is taken into account.
+    pub fn falloff_weight(&self, probe_space_position: Vec3) -> f32 {
+        // Avoid division by zero.
+        let falloff = self.falloff.max(Vec3::splat(0.0001));
+        let axis_weights =
+            ((Vec3::ONE - 2.0 * probe_space_position.abs()) / falloff).clamp(Vec3::ZERO, Vec3::ONE);
+        axis_weights.min_element()
+    }
+
+    /// Writes the weights with which the renderer blends `light_probes` at `world_position` into
+    /// `weights`, replacing its previous contents.
+    ///
+    /// This mirrors the blending the shaders do for each fragment, taking both
+    /// [`LightProbe::falloff`] and [`LightProbe::priority`] into account, which is useful for
+    /// debugging the placement of light probes. Light probes that don't affect `world_position`
+    /// are left out. Pass light probes of a single type only, as reflection probes and irradiance
+    /// volumes are blended separately. `light_probes` is iterated twice, and `weights` can be
+    /// reused across calls to avoid allocating.
+    ///
+    /// If the weights add up to 1 or more, they're normalized to sum to 1. Otherwise they're
+    /// returned as is, since the final normalization depends on the view: the renderer fills in
+    /// the remainder with the view's [`EnvironmentMapLight`] if it has one, and scales the weights
+    /// up to sum to 1 if it doesn't. Irradiance volumes are always scaled up to sum to 1.
+    /// Light probes that the renderer skips, for example because their textures haven't loaded
+    /// yet or the view has too many light probes, are still included here.
+    pub fn blend_weights<'a, T, I>(
+        world_position: Vec3,
+        light_probes: I,
+        weights: &mut Vec<(T, f32)>,
+    ) where
+        I: IntoIterator<Item = (T, &'a LightProbe, &'a GlobalTransform)>,
+        I::IntoIter: Clone,
+    {
+        weights.clear();
+        let light_probes = light_probes.into_iter();
+
+        // Find the highest priority among the light probes that affect `world_position`, and how
+        // much the light probes with that priority cover it.
+        let mut max_priority = None;
+        let mut coverage = 0.0;
+        for (id, light_probe, transform) in light_probes.clone() {
+            let probe_space_position = transform
+                .affine()
+                .inverse()
+                .transform_point3(world_position);
+            let weight = light_probe.falloff_weight(probe_space_position);
+            if weight > 0.0 {
+                match max_priority.map(|max_priority| light_probe.priority.cmp(&max_priority)) {
+                    Some(Ordering::Less) => {}
+                    Some(Ordering::Equal) => coverage += weight,
+                    None | Some(Ordering::Greater) => {
+                        max_priority = Some(light_probe.priority);
+                        coverage = weight;
+                    }
+                }
+            }
+            weights.push((id, weight));
+        }
+        let Some(max_priority) = max_priority else {
+            weights.clear();
+            return;
+        };
+
+        // The highest-priority light probes keep their weight, and lower-priority ones only fill
+        // in the influence the highest-priority ones leave over. This must match
+        // `light_probe_priority_weight` in `light_probe.wgsl`.
+        for ((_, weight), (_, light_probe, _)) in weights.iter_mut().zip(light_probes) {
+            if light_probe.priority < max_priority {
+                *weight *= 1.0 - coverage.clamp(0.0, 1.0);
+            }
+        }
+        weights.retain(|(_, weight)| *weight > 0.0);
+
+        let total_weight: f32 = weights.iter().map(|(_, weight)| weight).sum();
+        if total_weight >= 0.9999 {
+            for (_, weight) in weights.iter_mut() {
+                *weight /= total_weight;
+            }
+        }
+    }
+}
+
+/// The blend weights of the reflection probes at this entity's position.
+///
+/// The weights are recomputed in [`PostUpdate`](bevy_app::PostUpdate) with
+/// [`LightProbe::blend_weights`], from every [`LightProbe`] with an [`EnvironmentMapLight`].
+/// Add this component to an entity to inspect how reflection probes blend around it,
+/// for example to display "Room 1: 0.7, Room 2: 0.3" while debugging.
+#[derive(Component, Clone, Debug, Default, Reflect)]
+#[reflect(Component, Default, Debug, Clone)]
+pub struct LightProbeInfluence {
+    /// The reflection probes that affect this entity, along with their blend weights.
+    pub weights: Vec<(Entity, f32)>,
+}
+
+/// Updates the [`LightProbeInfluence`] of every entity that has one.
+pub fn update_light_probe_influences(
+    mut influences: Query<(&GlobalTransform, &mut LightProbeInfluence)>,
+    light_probes: Query<(Entity, &LightProbe, &GlobalTransform), With<EnvironmentMapLight>>,
+) {
+    for (transform, mut influence) in &mut influences {
+        LightProbe::blend_weights(
+            transform.translation(),
+            light_probes.iter(),
+            &mut influence.weights,
+        );
+    }
 }
 
 /// A pair of cubemap textures that represent the surroundings of a specific
@@ -395,6 +527,17 @@ impl Default for IrradianceVolume {
 /// light probe will coincide with the simulated boundaries used for parallax
 /// correction.
 ///
+/// # Blending
+///
+/// When reflection probes overlap, each probe samples its cubemap using its own
+/// parallax correction setting, and the results are blended according to the
+/// probes' weights. A probe with [`ParallaxCorrection::None`] can therefore be
+/// blended with parallax-corrected probes, for instance to fade from a room
+/// into a distant outdoor environment: the reflection shifts from tracking the
+/// room's walls to staying fixed relative to the view as the weights change.
+/// The bounds of a probe without parallax correction still limit its region of
+/// influence.
+///
 /// See the `pccm` example for an example of usage of parallax-corrected
 /// cubemaps and the `light_probe_blending` example for an example of use of
 /// custom parallax correction boundaries.
@@ -414,6 +557,7 @@ pub enum ParallaxCorrection {
     /// to reflection probes that don't have a [`ParallaxCorrection`] component.
     /// It's equivalent to `ParallaxCorrection::Custom(Vec3::splat(0.5))`.
     #[default]
+    #[doc(alias = "FromProbeBounds")]
     Auto,
 
     /// The parallax correction boundaries are specified manually.
@@ -428,6 +572,20 @@ pub enum ParallaxCorrection {
     Custom(Vec3),
 }
 
+impl ParallaxCorrection {
+    /// Creates custom parallax correction boundaries from the *full* size of
+    /// the simulated reflected area in world space, given the scale of the
+    /// light probe's [`Transform`].
+    ///
+    /// This converts the size to the half extents in light probe space that
+    /// [`ParallaxCorrection::Custom`] expects, so that the parallax correction
+    /// box can match a room that's smaller or larger than the light probe. If
+    /// the boxes are the same, use [`ParallaxCorrection::Auto`] instead.
+    pub fn from_world_size(size: Vec3, light_probe_scale: Vec3) -> Self {
+        ParallaxCorrection::Custom(size * 0.5 / light_probe_scale.abs())
+    }
+}
+
 /// A system that automatically adds a [`ParallaxCorrection::Auto`] component to
 /// any reflection probe that doesn't already have a [`ParallaxCorrection`]
 /// component.
@@ -451,3 +609,111 @@ pub fn automatically_add_parallax_correction_components(
             .insert(ParallaxCorrection::default());
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use bevy_math::{vec3, Vec3};
+    use bevy_transform::components::{GlobalTransform, Transform};
+
+    use super::LightProbe;
+
+    fn weights_at<const N: usize>(
+        world_position: Vec3,
+        light_probes: [(i32, &LightProbe, &GlobalTransform); N],
+    ) -> Vec<(i32, f32)> {
+        let mut weights = Vec::new();
+        LightProbe::blend_weights(world_position, light_probes, &mut weights);
+        weights
+    }
+
+    #[test]
+    fn blend_weights() {
+        let light_probe = LightProbe {
+            falloff: Vec3::splat(0.5),
+            ..LightProbe::default()
+        };
+        let high_priority = LightProbe {
+            priority: 1,
+            ..light_probe
+        };
+        // Two 4×4×4 light probes overlapping by half.
+        let room_1 = GlobalTransform::from(Transform::from_scale(Vec3::splat(4.0)));
+        let room_2 =
+            GlobalTransform::from(Transform::from_xyz(2.0, 0.0, 0.0).with_scale(Vec3::splat(4.0)));
+
+        // Inside the first probe's inner region only.
+        assert_eq!(
+            weights_at(vec3(-1.0, 0.0, 0.0), [(1, &light_probe, &room_1)]),
+            vec![(1, 1.0)]
+        );
+        // In the first probe's falloff region, leaving the rest to the view's environment map.
+        assert_eq!(
+            weights_at(vec3(-1.5, 0.0, 0.0), [(1, &light_probe, &room_1)]),
+            vec![(1, 0.5)]
+        );
+        // Outside of both probes.
+        assert!(weights_at(
+            vec3(10.0, 0.0, 0.0),
+            [(1, &light_probe, &room_1), (2, &light_probe, &room_2)],
+        )
+        .is_empty());
+        // Inside the inner regions of both probes, which share the influence equally.
+        assert_eq!(
+            weights_at(
+                vec3(1.0, 0.0, 0.0),
+                [(1, &light_probe, &room_1), (2, &light_probe, &room_2)],
+            ),
+            vec![(1, 0.5), (2, 0.5)]
+        );
+        // In the first probe's falloff region and the second probe's inner region.
+        assert_eq!(
+            weights_at(
+                vec3(1.5, 0.0, 0.0),
+                [(1, &light_probe, &room_1), (2, &light_probe, &room_2)],
+            ),
+            vec![(1, 0.5 / 1.5), (2, 1.0 / 1.5)]
+        );
+        // A higher-priority probe only leaves the influence it doesn't cover to the other one.
+        assert_eq!(
+            weights_at(
+                vec3(0.5, 0.0, 0.0),
+                [(1, &light_probe, &room_1), (2, &high_priority, &room_2)],
+            ),
+            vec![(1, 0.5), (2, 0.5)]
+        );
+        assert_eq!(
+            weights_at(
+                vec3(1.5, 0.0, 0.0),
+                [(1, &light_probe, &room_1), (2, &high_priority, &room_2)],
+            ),
+            vec![(2, 1.0)]
+        );
+    }
+
+    #[test]
+    fn falloff_weight() {
+        let light_probe = LightProbe {
+            falloff: Vec3::splat(0.5),
+            ..LightProbe::default()
+        };
+        assert_eq!(light_probe.falloff_weight(Vec3::ZERO), 1.0);
+        assert_eq!(light_probe.falloff_weight(Vec3::splat(0.25)), 1.0);
+        assert_eq!(light_probe.falloff_weight(vec3(0.375, 0.0, 0.0)), 0.5);
+        assert_eq!(light_probe.falloff_weight(vec3(0.0, 0.0, -0.5)), 0.0);
+        assert_eq!(light_probe.falloff_weight(vec3(0.0, 0.75, 0.0)), 0.0);
+    }
+
+    #[test]
+    fn falloff_weight_asymmetric() {
+        // Fades out over the outer half of the probe along X, but has a sharp
+        // edge along Y and Z.
+        let light_probe = LightProbe {
+            falloff: vec3(0.5, 0.0, 0.0),
+            ..LightProbe::default()
+        };
+        assert_eq!(light_probe.falloff_weight(vec3(0.375, 0.0, 0.0)), 0.5);
+        assert_eq!(light_probe.falloff_weight(vec3(0.0, 0.49, 0.49)), 1.0);
+        assert_eq!(light_probe.falloff_weight(vec3(0.375, 0.49, 0.0)), 0.5);
+        assert_eq!(light_probe.falloff_weight(vec3(0.0, 0.5, 0.0)), 0.0);
+    }
+}
@@ -1,10 +1,13 @@
 //! Allows configuring a material's transparency behavior.
 
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(feature = "serialize")]
+use serde::{Deserialize, Serialize};
 
 // TODO: add discussion about performance.
 /// Sets how a material's base color alpha channel is used for transparency.
 #[derive(Debug, Default, Reflect, Copy, Clone, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[reflect(Default, Debug, Clone)]
 pub enum AlphaMode {
     /// Base color alpha values are overridden to be fully opaque (1.0).
@@ -76,7 +76,14 @@ impl HalfSpace {
         self.normal_d
     }
 
+    /// The smallest volume, relative to the lengths of the normals, that the normals of three
+    /// planes can span for [`HalfSpace::intersection_point`] to compute their intersection.
+    const NEAR_PARALLEL_TOLERANCE: f32 = 1e-5;
+
     /// Returns the intersection point if the three halfspaces all intersect at a single point.
+    ///
+    /// Returns `None` when two of the bisecting planes are parallel or nearly parallel, in which
+    /// case the point is either undefined or dominated by rounding errors.
     #[inline]
     pub fn intersection_point(a: HalfSpace, b: HalfSpace, c: HalfSpace) -> Option<Vec3> {
         let an = a.normal();
@@ -94,7 +101,10 @@ impl HalfSpace {
 
         let denom = x.dot(u);
 
-        if ops::abs(denom) < f32::EPSILON {
+        // The denominator is the volume spanned by the three normals, so compare it with their
+        // lengths to tell nearly parallel planes apart.
+        let normals_scale = an.length() * bn.length() * cn.length();
+        if ops::abs(denom) <= Self::NEAR_PARALLEL_TOLERANCE * normals_scale {
             return None;
         }
 
@@ -109,7 +119,7 @@ mod half_space_tests {
     use approx::assert_relative_eq;
 
     use super::HalfSpace;
-    use crate::{Vec3, Vec4};
+    use crate::{ops, Vec3, Vec4};
 
     #[test]
     fn intersection_point() {
@@ -150,4 +160,21 @@ mod half_space_tests {
         };
         assert!(HalfSpace::intersection_point(xy_at_z_3, xz_at_y_2, ill_defined).is_none());
     }
+
+    #[test]
+    fn intersection_point_near_parallel() {
+        let xz_at_y_2 = HalfSpace::new(Vec4::new(0., 1., 0., -2.));
+        let yz_at_x_1 = HalfSpace::new(Vec4::new(1., 0., 0., -1.));
+
+        // A plane tilted away from `xz_at_y_2` by a tiny angle, for which the intersection
+        // would be far away and mostly made of rounding errors.
+        let angle = 1e-6_f32;
+        let tilted_xz_at_y_3 = HalfSpace::new(Vec4::new(0., ops::cos(angle), ops::sin(angle), -3.));
+        assert!(HalfSpace::intersection_point(yz_at_x_1, xz_at_y_2, tilted_xz_at_y_3).is_none());
+
+        // A clearly tilted plane still intersects.
+        let angle = 0.1_f32;
+        let tilted_xz_at_y_2 = HalfSpace::new(Vec4::new(0., ops::cos(angle), ops::sin(angle), -2.));
+        assert!(HalfSpace::intersection_point(yz_at_x_1, xz_at_y_2, tilted_xz_at_y_2).is_some());
+    }
 }
@@ -13,6 +13,8 @@ mod polygon;
 pub use half_space::*;
 mod view_frustum;
 pub use view_frustum::*;
+mod view_rect_2d;
+pub use view_rect_2d::*;
 
 /// A marker trait for 2D primitives
 pub trait Primitive2d {}
@@ -1,4 +1,4 @@
-use crate::{primitives::HalfSpace, Mat4, Vec3, Vec4};
+use crate::{ops, primitives::HalfSpace, Affine3A, Mat4, Rect, Vec3, Vec4};
 
 #[cfg(feature = "bevy_reflect")]
 use bevy_reflect::{std_traits::ReflectDefault, Reflect};
@@ -37,6 +37,56 @@ impl ViewFrustum {
     /// The signed distance along the normal from the plane to the origin is set to `f32::INFINITY`.
     const INACTIVE_HALF_SPACE: Vec4 = Vec4::new(0.0, 0.0, 0.0, f32::INFINITY);
 
+    /// Returns the left half-space of the frustum.
+    #[inline]
+    pub fn left(&self) -> HalfSpace {
+        self.half_spaces[0]
+    }
+
+    /// Returns the right half-space of the frustum.
+    #[inline]
+    pub fn right(&self) -> HalfSpace {
+        self.half_spaces[1]
+    }
+
+    /// Returns the top half-space of the frustum.
+    #[inline]
+    pub fn top(&self) -> HalfSpace {
+        self.half_spaces[2]
+    }
+
+    /// Returns the bottom half-space of the frustum.
+    #[inline]
+    pub fn bottom(&self) -> HalfSpace {
+        self.half_spaces[3]
+    }
+
+    /// Returns the near half-space of the frustum.
+    #[inline]
+    pub fn near(&self) -> HalfSpace {
+        self.half_spaces[Self::NEAR_PLANE_IDX]
+    }
+
+    /// Returns the far half-space of the frustum, or `None` if the far plane is inactive.
+    #[inline]
+    pub fn far(&self) -> Option<HalfSpace> {
+        let far = self.half_spaces[Self::FAR_PLANE_IDX];
+        Self::is_active(&far).then_some(far)
+    }
+
+    /// Returns `true` if the far plane is active, meaning the frustum doesn't extend infinitely.
+    #[inline]
+    pub fn has_far_plane(&self) -> bool {
+        Self::is_active(&self.half_spaces[Self::FAR_PLANE_IDX])
+    }
+
+    /// Returns `true` if the frustum isn't properly defined,
+    /// meaning [`ViewFrustum::corners`] returns `None`.
+    #[inline]
+    pub fn is_degenerate(&self) -> bool {
+        self.corners().is_none()
+    }
+
     /// Returns a view frustum derived from `clip_from_world`.
     #[inline]
     pub fn from_clip_from_world(clip_from_world: &Mat4) -> Self {
@@ -54,10 +104,151 @@ impl ViewFrustum {
         view_backward: &Vec3,
         far: f32,
     ) -> Self {
-        let mut frustum = ViewFrustum::from_clip_from_world_no_far(clip_from_world);
-        let far_center = *view_translation - far * *view_backward;
-        frustum.half_spaces[Self::FAR_PLANE_IDX] =
+        ViewFrustum::from_clip_from_world_no_far(clip_from_world).with_far_plane(
+            far,
+            *view_translation,
+            *view_backward,
+        )
+    }
+
+    /// Returns this frustum with its far plane placed `far` units in front of a view at
+    /// `view_translation` looking along `-view_backward`, replacing any existing far plane.
+    ///
+    /// This allows building the frustum in stages, for example starting from
+    /// [`ViewFrustum::from_clip_from_world_no_far`] and only adding a far plane once the
+    /// view distance is known. `view_backward` is expected to be normalized.
+    #[inline]
+    pub fn with_far_plane(mut self, far: f32, view_translation: Vec3, view_backward: Vec3) -> Self {
+        let far_center = view_translation - far * view_backward;
+        self.half_spaces[Self::FAR_PLANE_IDX] =
             HalfSpace::new(view_backward.extend(-view_backward.dot(far_center)));
+        self
+    }
+
+    /// Returns a perspective view frustum with the vertical field of view `fov_y` (in radians),
+    /// the given `aspect_ratio` (width over height) and `near` and `far` plane distances,
+    /// placed in the world by `world_from_view`.
+    ///
+    /// The view looks down its local negative Z axis, and the half-spaces are ordered the same way as
+    /// [`ViewFrustum::from_clip_from_world`] orders them. If `far` is infinite, the far plane is inactive.
+    #[inline]
+    pub fn from_perspective(
+        fov_y: f32,
+        aspect_ratio: f32,
+        near: f32,
+        far: f32,
+        world_from_view: &Affine3A,
+    ) -> Self {
+        let half_height = ops::tan(fov_y * 0.5);
+        let half_width = half_height * aspect_ratio;
+        Self::from_view_half_spaces(
+            [
+                Vec4::new(1.0, 0.0, -half_width, 0.0),
+                Vec4::new(-1.0, 0.0, -half_width, 0.0),
+                Vec4::new(0.0, 1.0, -half_height, 0.0),
+                Vec4::new(0.0, -1.0, -half_height, 0.0),
+            ],
+            near,
+            far,
+            world_from_view,
+        )
+    }
+
+    /// Returns an orthographic view frustum covering `area` in view space,
+    /// between the `near` and `far` plane distances, placed in the world by `world_from_view`.
+    ///
+    /// The view looks down its local negative Z axis, and the half-spaces are ordered the same way as
+    /// [`ViewFrustum::from_clip_from_world`] orders them. If `far` is infinite, the far plane is inactive.
+    #[inline]
+    pub fn from_orthographic(area: Rect, near: f32, far: f32, world_from_view: &Affine3A) -> Self {
+        Self::from_view_half_spaces(
+            [
+                Vec4::new(1.0, 0.0, 0.0, -area.min.x),
+                Vec4::new(-1.0, 0.0, 0.0, area.max.x),
+                Vec4::new(0.0, 1.0, 0.0, -area.min.y),
+                Vec4::new(0.0, -1.0, 0.0, area.max.y),
+            ],
+            near,
+            far,
+            world_from_view,
+        )
+    }
+
+    /// Reconstructs a view frustum from its 8 corners.
+    ///
+    /// The corners must be in the order returned by [`ViewFrustum::corners`]:
+    /// near top left, near top right, near bottom right, near bottom left,
+    /// far top left, far top right, far bottom right, far bottom left.
+    ///
+    /// Returns `None` if any of the six planes can't be determined because its corners are
+    /// degenerate, for example when the near plane collapses into a single point.
+    /// This includes frustums without a far plane: their far corners are `Vec3::NAN`, so
+    /// the corners of an infinite frustum can't be turned back into a frustum.
+    pub fn from_corners(corners: [Vec3; 8]) -> Option<Self> {
+        let [ntl, ntr, nbr, nbl, ftl, ftr, fbr, fbl] = corners;
+        let interior = corners.iter().sum::<Vec3>() / 8.0;
+
+        let half_space = |a: Vec3, b: Vec3, c: Vec3| {
+            let normal = (b - a).cross(c - a).try_normalize()?;
+            let normal = if normal.dot(interior - a) < 0.0 {
+                -normal
+            } else {
+                normal
+            };
+            Some(HalfSpace::new(normal.extend(-normal.dot(a))))
+        };
+
+        Some(Self {
+            half_spaces: [
+                half_space(ntl, ftl, fbl)?,
+                half_space(ntr, ftr, fbr)?,
+                half_space(ntl, ftl, ftr)?,
+                half_space(nbl, fbl, fbr)?,
+                half_space(ntl, ntr, nbr)?,
+                half_space(ftl, ftr, fbr)?,
+            ],
+        })
+    }
+
+    /// Returns this frustum transformed by `transform`.
+    ///
+    /// Plane normals don't transform like points, so each half-space is transformed by the
+    /// inverse-transpose of `transform`. An inactive far plane stays inactive.
+    #[inline]
+    pub fn transformed(&self, transform: &Mat4) -> ViewFrustum {
+        let plane_transform = transform.inverse().transpose();
+        let mut frustum = *self;
+        for half_space in &mut frustum.half_spaces {
+            if Self::is_active(half_space) {
+                *half_space = HalfSpace::new(plane_transform * half_space.normal_d());
+            }
+        }
+        frustum
+    }
+
+    /// Returns the sub-frustum between `near_fraction` and `far_fraction` of the way from
+    /// this frustum's near plane to its far plane, such as for a shadow cascade.
+    ///
+    /// The side planes are kept as-is. A `near_fraction` of `0.0` and a `far_fraction` of `1.0`
+    /// reproduce the original near and far planes. This assumes the near and far planes are
+    /// parallel, as they are for frustums built by [`ViewFrustum::from_clip_from_world`] and
+    /// [`ViewFrustum::from_clip_from_world_custom_far`].
+    ///
+    /// If this frustum has no far plane there is no depth range to split,
+    /// and an unchanged copy of the frustum is returned.
+    #[inline]
+    pub fn split_depth(&self, near_fraction: f32, far_fraction: f32) -> ViewFrustum {
+        let Some(far) = self.far() else {
+            return *self;
+        };
+        let near = self.near().normal_d();
+        // The far plane, flipped to face the same direction as the near plane.
+        let far = -far.normal_d();
+        let plane_at = |fraction: f32| near * (1.0 - fraction) + far * fraction;
+
+        let mut frustum = *self;
+        frustum.half_spaces[Self::NEAR_PLANE_IDX] = HalfSpace::new(plane_at(near_fraction));
+        frustum.half_spaces[Self::FAR_PLANE_IDX] = HalfSpace::new(-plane_at(far_fraction));
         frustum
     }
 
@@ -83,12 +274,227 @@ impl ViewFrustum {
         ])
     }
 
+    /// Checks if `point` lies inside the frustum.
+    ///
+    /// A point is inside when it is within every half-space of the frustum.
+    /// An inactive far plane is ignored, so frustums without a far plane extend infinitely.
+    #[inline]
+    pub fn contains_point(&self, point: Vec3) -> bool {
+        let point = point.extend(1.0);
+        self.active_half_spaces()
+            .all(|half_space| half_space.normal_d().dot(point) > 0.0)
+    }
+
+    /// Returns the signed distance from `point` to the frustum, measured against its planes.
+    ///
+    /// This is the largest signed distance from `point` to any half-space of the frustum,
+    /// so it is negative inside the frustum, zero on its boundary and positive outside.
+    /// An inactive far plane is ignored.
+    ///
+    /// Inside the frustum the magnitude is the exact distance to the nearest plane.
+    /// Outside, it is the exact Euclidean distance to the frustum only when a single plane is
+    /// violated; near edges and corners it underestimates the true distance, which makes it
+    /// a conservative value for soft culling.
+    #[inline]
+    pub fn signed_distance(&self, point: Vec3) -> f32 {
+        let point = point.extend(1.0);
+        self.active_half_spaces()
+            .map(|half_space| -half_space.normal_d().dot(point))
+            .fold(f32::NEG_INFINITY, f32::max)
+    }
+
+    /// Checks if a sphere with the given `center` and `radius` intersects the frustum.
+    ///
+    /// Returns `true` if any part of the sphere lies inside the frustum or crosses one of its planes.
+    /// An inactive far plane is ignored.
+    #[inline]
+    pub fn intersects_sphere(&self, center: Vec3, radius: f32) -> bool {
+        let center = center.extend(1.0);
+        self.active_half_spaces()
+            .all(|half_space| half_space.normal_d().dot(center) + radius > 0.0)
+    }
+
+    /// Checks if the axis-aligned bounding box spanning from `min` to `max` intersects the frustum.
+    ///
+    /// For each half-space, this tests the corner of the box furthest along the plane's normal.
+    /// The test is conservative: boxes near the frustum's edges may be reported as intersecting
+    /// even though they lie outside, but boxes that intersect are never reported as outside.
+    /// An inactive far plane is ignored.
+    #[inline]
+    pub fn intersects_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.active_half_spaces().all(|half_space| {
+            let normal = Vec3::from(half_space.normal());
+            let positive_vertex = Vec3::select(normal.cmpge(Vec3::ZERO), max, min);
+            normal.dot(positive_vertex) + half_space.d() > 0.0
+        })
+    }
+
+    /// Checks if the axis-aligned bounding box spanning from `min` to `max` lies entirely
+    /// inside the frustum.
+    ///
+    /// This is exact, unlike [`ViewFrustum::intersects_aabb`]: for each half-space, the corner
+    /// of the box furthest against the plane's normal must be inside, so all eight corners are.
+    /// An inactive far plane is ignored, so boxes are never cut off by it.
+    #[inline]
+    pub fn contains_aabb(&self, min: Vec3, max: Vec3) -> bool {
+        self.active_half_spaces().all(|half_space| {
+            let normal = Vec3::from(half_space.normal());
+            let negative_vertex = Vec3::select(normal.cmpge(Vec3::ZERO), min, max);
+            normal.dot(negative_vertex) + half_space.d() > 0.0
+        })
+    }
+
+    /// Checks if this frustum intersects `other`.
+    ///
+    /// The frustums are considered disjoint when all of one frustum lies outside one of the other
+    /// frustum's planes. This test is conservative: frustums that are only separated along an axis
+    /// formed by the cross product of two edges may be reported as intersecting, but frustums that
+    /// intersect are never reported as disjoint. Frustums with an inactive far plane are treated as
+    /// extending infinitely along their side edges. If either frustum's corners can't be computed,
+    /// see [`ViewFrustum::corners`], this returns `true`.
+    #[inline]
+    pub fn intersects_frustum(&self, other: &ViewFrustum) -> bool {
+        !self.separates(other) && !other.separates(self)
+    }
+
+    /// Clips the ray starting at `origin` and travelling along `direction` against the frustum,
+    /// returning the parametric distances `(entry, exit)` at which it enters and leaves the volume.
+    ///
+    /// The distances are multiples of `direction`, so `origin + entry * direction` is the entry point.
+    /// If `origin` is inside the frustum the entry distance is `0.0`, and if the far plane is
+    /// inactive a ray travelling away from the camera has an exit distance of [`f32::INFINITY`].
+    /// Returns `None` if the ray misses the frustum.
+    #[inline]
+    pub fn clip_ray(&self, origin: Vec3, direction: Vec3) -> Option<(f32, f32)> {
+        let origin = origin.extend(1.0);
+        let direction = direction.extend(0.0);
+        let mut entry = 0.0_f32;
+        let mut exit = f32::INFINITY;
+        for half_space in self.active_half_spaces() {
+            // Liang-Barsky: each plane either bounds where the ray enters or where it leaves.
+            let distance = half_space.normal_d().dot(origin);
+            let rate = half_space.normal_d().dot(direction);
+            if rate == 0.0 {
+                if distance <= 0.0 {
+                    return None;
+                }
+                continue;
+            }
+            let t = -distance / rate;
+            if rate > 0.0 {
+                entry = entry.max(t);
+            } else {
+                exit = exit.min(t);
+            }
+            if entry > exit {
+                return None;
+            }
+        }
+        Some((entry, exit))
+    }
+
+    /// Returns an iterator over the half-spaces of this frustum, skipping an inactive far plane.
+    #[inline]
+    fn active_half_spaces(&self) -> impl Iterator<Item = &HalfSpace> {
+        self.half_spaces
+            .iter()
+            .filter(|half_space| Self::is_active(half_space))
+    }
+
+    /// Returns `true` if `other` lies entirely outside one of this frustum's half-spaces.
+    fn separates(&self, other: &ViewFrustum) -> bool {
+        let Some(corners) = other.corners() else {
+            return false;
+        };
+        // Without a far plane, `other` is the convex hull of its near corners,
+        // extended infinitely along the edges running away from its near plane.
+        let (corners, edge_directions) = match other.far() {
+            Some(_) => (&corners[..], None),
+            None => (&corners[..4], Some(other.side_edge_directions())),
+        };
+        self.active_half_spaces().any(|half_space| {
+            let normal = Vec3::from(half_space.normal());
+            corners
+                .iter()
+                .all(|corner| normal.dot(*corner) + half_space.d() <= 0.0)
+                && edge_directions
+                    .iter()
+                    .flatten()
+                    .all(|direction| normal.dot(*direction) <= 0.0)
+        })
+    }
+
+    /// Returns the directions of the four side edges of this frustum, pointing away from the
+    /// near plane, in the same order as the near corners returned by [`ViewFrustum::corners`].
+    fn side_edge_directions(&self) -> [Vec3; 4] {
+        let [left, right, top, bottom, near, _] =
+            self.half_spaces.map(|half_space| half_space.normal());
+        [(top, left), (top, right), (bottom, right), (bottom, left)].map(|(a, b)| {
+            let direction = Vec3::from(a.cross(b));
+            if direction.dot(near.into()) < 0.0 {
+                -direction
+            } else {
+                direction
+            }
+        })
+    }
+
+    /// Returns `false` if `half_space` was created from [`Self::INACTIVE_HALF_SPACE`].
+    #[inline]
+    fn is_active(half_space: &HalfSpace) -> bool {
+        half_space.d() != f32::INFINITY
+    }
+
+    /// Calculates the axis-aligned bounding box of this frustum's corners,
+    /// returned as `(min, max)`.
+    ///
+    /// Returns `None` if the corners can't be computed, see [`ViewFrustum::corners`].
+    /// If the far plane is inactive, the far corners are ignored and the box only
+    /// bounds the near corners, even though the frustum itself is unbounded.
+    #[inline]
+    pub fn bounding_aabb(&self) -> Option<(Vec3, Vec3)> {
+        self.corners()?
+            .into_iter()
+            .filter(|corner| !corner.is_nan())
+            .fold(None, |bounds, corner| match bounds {
+                Some((min, max)) => Some((corner.min(min), corner.max(max))),
+                None => Some((corner, corner)),
+            })
+    }
+
+    /// Returns a view frustum from its four side planes in view space and the distances to
+    /// its near and far planes, transformed into the world by `world_from_view`.
+    fn from_view_half_spaces(
+        [left, right, top, bottom]: [Vec4; 4],
+        near: f32,
+        far: f32,
+        world_from_view: &Affine3A,
+    ) -> Self {
+        let far = if far.is_finite() {
+            Vec4::new(0.0, 0.0, 1.0, far)
+        } else {
+            Self::INACTIVE_HALF_SPACE
+        };
+        let view_frustum = Self {
+            half_spaces: [
+                HalfSpace::new(left),
+                HalfSpace::new(right),
+                HalfSpace::new(top),
+                HalfSpace::new(bottom),
+                HalfSpace::new(Vec4::new(0.0, 0.0, -1.0, -near)),
+                HalfSpace::new(far),
+            ],
+        };
+        view_frustum.transformed(&Mat4::from(*world_from_view))
+    }
+
     // NOTE: This approach of extracting the frustum half-space from the view
     // projection matrix is from Foundations of Game Engine Development 2
     // Rendering by Lengyel.
-    /// Returns a view frustum derived from `view_projection`,
+    /// Returns a view frustum derived from `clip_from_world`,
     /// without a far plane.
-    fn from_clip_from_world_no_far(clip_from_world: &Mat4) -> Self {
+    #[inline]
+    pub fn from_clip_from_world_no_far(clip_from_world: &Mat4) -> Self {
         let row0 = clip_from_world.row(0);
         let row1 = clip_from_world.row(1);
         let row2 = clip_from_world.row(2);
@@ -114,11 +520,10 @@ mod view_frustum_tests {
     use approx::assert_relative_eq;
 
     use super::ViewFrustum;
-    use crate::{primitives::HalfSpace, Vec3, Vec4};
+    use crate::{primitives::HalfSpace, Affine3A, Mat4, Quat, Rect, Vec3, Vec4};
 
-    #[test]
-    fn cuboid_frustum_corners() {
-        let cuboid_frustum = ViewFrustum {
+    fn cuboid_frustum() -> ViewFrustum {
+        ViewFrustum {
             // left: x = -5; right: x = 4
             // near: y = 0; far: y = 6
             // top: z = 3; bottom: z = -2
@@ -136,8 +541,66 @@ mod view_frustum_tests {
                 // far: xz plane at y = 6
                 HalfSpace::new(Vec4::new(0., -1., 0., 6.)),
             ],
-        };
-        let corners = cuboid_frustum.corners().unwrap();
+        }
+    }
+
+    fn pyramid_frustum() -> ViewFrustum {
+        // a frustum where the near plane intersects the left right top and bottom planes
+        // at a single point
+        ViewFrustum {
+            half_spaces: [
+                // left
+                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                // right
+                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                // top
+                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+                // bottom
+                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+                // near: xz plane at y = -1
+                HalfSpace::new(Vec4::new(0., 1., 0., 1.)),
+                // far: xz plane at y = 3
+                HalfSpace::new(Vec4::new(0., -1., 0., 3.)),
+            ],
+        }
+    }
+
+    fn no_far_frustum() -> ViewFrustum {
+        ViewFrustum {
+            half_spaces: [
+                // left: a yz plane rotated outwards
+                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                // right: a yz plane rotated outwards
+                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                // top: an xz plane rotated outwards
+                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+                // bottom: xz plane rotated outwards
+                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+                // near: xz plane at origin (y = 0)
+                HalfSpace::new(Vec4::new(0., 1., 0., 0.)),
+                // far
+                HalfSpace::new(ViewFrustum::INACTIVE_HALF_SPACE),
+            ],
+        }
+    }
+
+    fn invalid_frustum() -> ViewFrustum {
+        ViewFrustum {
+            half_spaces: [
+                // the left and the top half spaces are the same, resulting in no intersection point
+                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., -FRAC_1_SQRT_2)),
+                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
+                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
+                HalfSpace::new(Vec4::new(0., 1., 0., 0.)),
+                HalfSpace::new(Vec4::new(0., -1., 0., 3.)),
+            ],
+        }
+    }
+
+    #[test]
+    fn cuboid_frustum_corners() {
+        let corners = cuboid_frustum().corners().unwrap();
         // near top left
         assert_relative_eq!(corners[0], Vec3::new(-5., 0., 3.), epsilon = 2e-7);
         // near top right
@@ -158,25 +621,7 @@ mod view_frustum_tests {
 
     #[test]
     fn pyramid_frustum_corners() {
-        // a frustum where the near plane intersects the left right top and bottom planes
-        // at a single point
-        let pyramid_frustum = ViewFrustum {
-            half_spaces: [
-                // left
-                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                // right
-                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                // top
-                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
-                // bottom
-                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
-                // near: xz plane at y = -1
-                HalfSpace::new(Vec4::new(0., 1., 0., 1.)),
-                // far: xz plane at y = 3
-                HalfSpace::new(Vec4::new(0., -1., 0., 3.)),
-            ],
-        };
-        let corners = pyramid_frustum.corners().unwrap();
+        let corners = pyramid_frustum().corners().unwrap();
         // near top left
         assert_relative_eq!(corners[0], Vec3::new(0., -1., 0.), epsilon = 2e-7);
         // near top right
@@ -198,23 +643,7 @@ mod view_frustum_tests {
     #[test]
     fn frustum_with_some_nan_corners() {
         // frustum with no far plane has NAN far corners
-        let no_far = ViewFrustum {
-            half_spaces: [
-                // left: a yz plane rotated outwards
-                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                // right: a yz plane rotated outwards
-                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                // top: an xz plane rotated outwards
-                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, -FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
-                // bottom: xz plane rotated outwards
-                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
-                // near: xz plane at origin (y = 0)
-                HalfSpace::new(Vec4::new(0., 1., 0., 0.)),
-                // far
-                HalfSpace::new(ViewFrustum::INACTIVE_HALF_SPACE),
-            ],
-        };
-        let corners = no_far.corners().unwrap();
+        let corners = no_far_frustum().corners().unwrap();
         // near top left
         assert_relative_eq!(corners[0], Vec3::new(-1., 0., 1.), epsilon = 2e-7);
         // near top right
@@ -235,17 +664,449 @@ mod view_frustum_tests {
 
     #[test]
     fn invalid_frustum_corners() {
-        let invalid = ViewFrustum {
-            half_spaces: [
-                // the left and the top half spaces are the same, resulting in no intersection point
-                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                HalfSpace::new(Vec4::new(-FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., -FRAC_1_SQRT_2)),
-                HalfSpace::new(Vec4::new(FRAC_1_SQRT_2, FRAC_1_SQRT_2, 0., FRAC_1_SQRT_2)),
-                HalfSpace::new(Vec4::new(0., FRAC_1_SQRT_2, FRAC_1_SQRT_2, FRAC_1_SQRT_2)),
-                HalfSpace::new(Vec4::new(0., 1., 0., 0.)),
-                HalfSpace::new(Vec4::new(0., -1., 0., 3.)),
-            ],
-        };
-        assert!(invalid.corners().is_none());
+        assert!(invalid_frustum().corners().is_none());
+    }
+
+    #[test]
+    fn has_far_plane() {
+        assert!(cuboid_frustum().has_far_plane());
+        assert!(pyramid_frustum().has_far_plane());
+        assert!(!no_far_frustum().has_far_plane());
+    }
+
+    #[test]
+    fn is_degenerate() {
+        assert!(invalid_frustum().is_degenerate());
+        assert!(!cuboid_frustum().is_degenerate());
+        // the far corners are NAN, but they can still be computed
+        assert!(!no_far_frustum().is_degenerate());
+    }
+
+    #[test]
+    fn cuboid_frustum_contains_point() {
+        let frustum = cuboid_frustum();
+        assert!(frustum.contains_point(Vec3::new(0., 3., 0.)));
+        assert!(frustum.contains_point(Vec3::new(-4.9, 5.9, 2.9)));
+        // outside the left plane
+        assert!(!frustum.contains_point(Vec3::new(-6., 3., 0.)));
+        // behind the near plane
+        assert!(!frustum.contains_point(Vec3::new(0., -1., 0.)));
+        // beyond the far plane
+        assert!(!frustum.contains_point(Vec3::new(0., 7., 0.)));
+    }
+
+    #[test]
+    fn pyramid_frustum_contains_point() {
+        let frustum = pyramid_frustum();
+        assert!(frustum.contains_point(Vec3::new(0., 1., 0.)));
+        assert!(frustum.contains_point(Vec3::new(3.5, 2.9, -3.5)));
+        // outside the widening side planes
+        assert!(!frustum.contains_point(Vec3::new(2., 0., 0.)));
+        assert!(!frustum.contains_point(Vec3::new(0., 0., -2.)));
+        // beyond the far plane
+        assert!(!frustum.contains_point(Vec3::new(0., 4., 0.)));
+    }
+
+    #[test]
+    fn no_far_frustum_contains_point() {
+        let frustum = no_far_frustum();
+        assert!(frustum.contains_point(Vec3::new(0., 1., 0.)));
+        // arbitrarily far away points are inside when there is no far plane
+        assert!(frustum.contains_point(Vec3::new(0., 1e6, 0.)));
+        assert!(!frustum.contains_point(Vec3::new(0., -1., 0.)));
+        assert!(!frustum.contains_point(Vec3::new(0., 1e6, 2e6)));
+    }
+
+    #[test]
+    fn cuboid_frustum_signed_distance() {
+        let frustum = cuboid_frustum();
+        // interior: the nearest plane is the bottom one at z = -2
+        assert_relative_eq!(
+            frustum.signed_distance(Vec3::new(0., 3., -1.)),
+            -1.,
+            epsilon = 2e-7
+        );
+        // on the near plane
+        assert_relative_eq!(
+            frustum.signed_distance(Vec3::new(0., 0., 0.)),
+            0.,
+            epsilon = 2e-7
+        );
+        // exterior, only violating the right plane
+        assert_relative_eq!(
+            frustum.signed_distance(Vec3::new(6., 3., 0.)),
+            2.,
+            epsilon = 2e-7
+        );
+        // exterior past a corner: the larger of the two violations
+        assert_relative_eq!(
+            frustum.signed_distance(Vec3::new(7., 9., 0.)),
+            3.,
+            epsilon = 2e-7
+        );
+    }
+
+    #[test]
+    fn no_far_frustum_signed_distance() {
+        let frustum = no_far_frustum();
+        // without a far plane, the distance to the side planes keeps growing
+        assert!(frustum.signed_distance(Vec3::new(0., 1e6, 0.)) < -1e5);
+        assert_relative_eq!(
+            frustum.signed_distance(Vec3::new(0., -1., 0.)),
+            1.,
+            epsilon = 2e-7
+        );
+    }
+
+    #[test]
+    fn cuboid_frustum_intersects_sphere() {
+        let frustum = cuboid_frustum();
+        // fully inside
+        assert!(frustum.intersects_sphere(Vec3::new(0., 3., 0.), 1.));
+        // straddling the near plane
+        assert!(frustum.intersects_sphere(Vec3::new(0., -0.5, 0.), 1.));
+        // fully behind the left plane
+        assert!(!frustum.intersects_sphere(Vec3::new(-7., 3., 0.), 1.));
+        // fully behind the far plane
+        assert!(!frustum.intersects_sphere(Vec3::new(0., 8., 0.), 1.));
+    }
+
+    #[test]
+    fn no_far_frustum_intersects_sphere() {
+        let frustum = no_far_frustum();
+        assert!(frustum.intersects_sphere(Vec3::new(0., 1e6, 0.), 1.));
+        // straddling the near plane
+        assert!(frustum.intersects_sphere(Vec3::new(0., -0.5, 0.), 1.));
+        // fully behind the near plane
+        assert!(!frustum.intersects_sphere(Vec3::new(0., -2., 0.), 1.));
+    }
+
+    #[test]
+    fn cuboid_frustum_intersects_aabb() {
+        let frustum = cuboid_frustum();
+        // fully inside
+        assert!(frustum.intersects_aabb(Vec3::new(-1., 1., -1.), Vec3::new(1., 3., 1.)));
+        // fully outside the right plane
+        assert!(!frustum.intersects_aabb(Vec3::new(5., 1., -1.), Vec3::new(7., 3., 1.)));
+        // intersecting the far plane
+        assert!(frustum.intersects_aabb(Vec3::new(-1., 5., -1.), Vec3::new(1., 7., 1.)));
+        // surrounding the whole frustum
+        assert!(frustum.intersects_aabb(Vec3::splat(-10.), Vec3::splat(10.)));
+    }
+
+    #[test]
+    fn no_far_frustum_intersects_aabb() {
+        let frustum = no_far_frustum();
+        assert!(frustum.intersects_aabb(Vec3::new(-1., 1e6, -1.), Vec3::new(1., 1e6 + 2., 1.)));
+        assert!(!frustum.intersects_aabb(Vec3::new(-1., -3., -1.), Vec3::new(1., -1., 1.)));
+    }
+
+    #[test]
+    fn cuboid_frustum_contains_aabb() {
+        let frustum = cuboid_frustum();
+        // small box at the center
+        assert!(frustum.contains_aabb(Vec3::new(-1., 2., 0.), Vec3::new(0., 4., 1.)));
+        // poking through the near plane
+        let (min, max) = (Vec3::new(-1., -1., -1.), Vec3::new(1., 1., 1.));
+        assert!(!frustum.contains_aabb(min, max));
+        assert!(frustum.intersects_aabb(min, max));
+        // fully outside the right plane
+        assert!(!frustum.contains_aabb(Vec3::new(5., 1., -1.), Vec3::new(7., 3., 1.)));
+        // surrounding the whole frustum
+        assert!(!frustum.contains_aabb(Vec3::splat(-10.), Vec3::splat(10.)));
+    }
+
+    #[test]
+    fn no_far_frustum_contains_aabb() {
+        let frustum = no_far_frustum();
+        assert!(frustum.contains_aabb(Vec3::new(-1., 1e6, -1.), Vec3::new(1., 1e6 + 2., 1.)));
+        assert!(!frustum.contains_aabb(Vec3::new(-1., -1., -1.), Vec3::new(1., 2., 1.)));
+    }
+
+    #[test]
+    fn cuboid_frustum_clip_ray() {
+        let frustum = cuboid_frustum();
+        // enters through the near plane at (2, 0, 0) and leaves through the right plane at (4, 2, 0)
+        let (entry, exit) = frustum
+            .clip_ray(Vec3::new(0., -2., 0.), Vec3::new(1., 1., 0.))
+            .unwrap();
+        assert_relative_eq!(entry, 2., epsilon = 2e-7);
+        assert_relative_eq!(exit, 4., epsilon = 2e-7);
+
+        // starting inside, leaving through the far plane
+        let (entry, exit) = frustum.clip_ray(Vec3::new(0., 3., 0.), Vec3::Y).unwrap();
+        assert_eq!(entry, 0.);
+        assert_relative_eq!(exit, 3., epsilon = 2e-7);
+
+        // parallel to the near plane and behind it
+        assert!(frustum.clip_ray(Vec3::new(0., -2., 0.), Vec3::X).is_none());
+        // pointing away from the frustum
+        assert!(frustum
+            .clip_ray(Vec3::new(0., -2., 0.), Vec3::NEG_Y)
+            .is_none());
+        // passing beside the frustum
+        assert!(frustum
+            .clip_ray(Vec3::new(-10., -2., 0.), Vec3::new(0.5, 1., 0.))
+            .is_none());
+    }
+
+    #[test]
+    fn no_far_frustum_clip_ray() {
+        let (entry, exit) = no_far_frustum()
+            .clip_ray(Vec3::new(0., -1., 0.), Vec3::Y)
+            .unwrap();
+        assert_relative_eq!(entry, 1., epsilon = 2e-7);
+        assert_eq!(exit, f32::INFINITY);
+    }
+
+    #[test]
+    fn cuboid_frustum_intersects_frustum() {
+        let frustum = cuboid_frustum();
+        let overlapping = frustum.transformed(&Mat4::from_translation(Vec3::new(3., 2., 1.)));
+        assert!(frustum.intersects_frustum(&overlapping));
+        assert!(overlapping.intersects_frustum(&frustum));
+
+        let disjoint = frustum.transformed(&Mat4::from_translation(Vec3::new(20., 0., 0.)));
+        assert!(!frustum.intersects_frustum(&disjoint));
+        assert!(!disjoint.intersects_frustum(&frustum));
+
+        // a rotated copy that only overlaps a corner of the original
+        let rotated = frustum.transformed(
+            &(Mat4::from_translation(Vec3::new(4., 6., 0.)) * Mat4::from_rotation_z(0.5)),
+        );
+        assert!(frustum.intersects_frustum(&rotated));
+    }
+
+    #[test]
+    fn no_far_frustum_intersects_frustum() {
+        let frustum = no_far_frustum();
+        // far along the infinite frustum
+        let ahead = cuboid_frustum().transformed(&Mat4::from_translation(Vec3::new(0., 1e3, 0.)));
+        assert!(frustum.intersects_frustum(&ahead));
+        assert!(ahead.intersects_frustum(&frustum));
+
+        // behind the near plane
+        let behind = cuboid_frustum().transformed(&Mat4::from_translation(Vec3::new(0., -10., 0.)));
+        assert!(!frustum.intersects_frustum(&behind));
+        assert!(!behind.intersects_frustum(&frustum));
+
+        // beside the widening side planes
+        let beside = cuboid_frustum().transformed(&Mat4::from_translation(Vec3::new(100., 0., 0.)));
+        assert!(!frustum.intersects_frustum(&beside));
+        assert!(!beside.intersects_frustum(&frustum));
+
+        assert!(frustum.intersects_frustum(&frustum));
+    }
+
+    #[test]
+    fn from_corners_round_trip() {
+        let world_from_view =
+            Mat4::look_at_rh(Vec3::new(1., 2., 3.), Vec3::ZERO, Vec3::Y).inverse();
+        let clip_from_world =
+            Mat4::perspective_infinite_reverse_rh(1.2, 1.5, 0.1) * world_from_view.inverse();
+        let frustum = ViewFrustum::from_clip_from_world_custom_far(
+            &clip_from_world,
+            &world_from_view.w_axis.truncate(),
+            &world_from_view.z_axis.truncate(),
+            50.,
+        );
+
+        let rebuilt = ViewFrustum::from_corners(frustum.corners().unwrap()).unwrap();
+        for (original, rebuilt) in frustum.half_spaces.iter().zip(rebuilt.half_spaces) {
+            assert_relative_eq!(original.normal_d(), rebuilt.normal_d(), epsilon = 1e-4);
+        }
+
+        let cuboid = cuboid_frustum();
+        let rebuilt = ViewFrustum::from_corners(cuboid.corners().unwrap()).unwrap();
+        for (original, rebuilt) in cuboid.half_spaces.iter().zip(rebuilt.half_spaces) {
+            assert_relative_eq!(original.normal_d(), rebuilt.normal_d(), epsilon = 1e-6);
+        }
+
+        let corners = no_far_frustum().corners().unwrap();
+        assert!(ViewFrustum::from_corners(corners).is_none());
+    }
+
+    #[test]
+    fn from_perspective() {
+        let world_from_view =
+            Affine3A::look_at_rh(Vec3::new(1., 2., 3.), Vec3::ZERO, Vec3::Y).inverse();
+        let (fov_y, aspect_ratio, near, far) = (1.2, 1.5, 0.1, 50.);
+        let frustum =
+            ViewFrustum::from_perspective(fov_y, aspect_ratio, near, far, &world_from_view);
+
+        // an OpenGL-style projection, whose `row3 + row2` plane is the near plane
+        let clip_from_world = Mat4::perspective_rh_gl(fov_y, aspect_ratio, near, far)
+            * Mat4::from(world_from_view.inverse());
+        let expected = ViewFrustum::from_clip_from_world_custom_far(
+            &clip_from_world,
+            &world_from_view.translation.into(),
+            &world_from_view.z_axis.into(),
+            far,
+        );
+        for (corner, expected) in frustum
+            .corners()
+            .unwrap()
+            .into_iter()
+            .zip(expected.corners().unwrap())
+        {
+            assert_relative_eq!(corner, expected, epsilon = 1e-3);
+        }
+
+        let infinite = ViewFrustum::from_perspective(
+            fov_y,
+            aspect_ratio,
+            near,
+            f32::INFINITY,
+            &world_from_view,
+        );
+        assert_eq!(infinite.far(), None);
+    }
+
+    #[test]
+    fn from_orthographic() {
+        let world_from_view =
+            Affine3A::look_at_rh(Vec3::new(1., 2., 3.), Vec3::ZERO, Vec3::Y).inverse();
+        let area = Rect::new(-4., -1., 2., 3.);
+        let (near, far) = (0.5, 20.);
+        let frustum = ViewFrustum::from_orthographic(area, near, far, &world_from_view);
+
+        let clip_from_world =
+            Mat4::orthographic_rh_gl(area.min.x, area.max.x, area.min.y, area.max.y, near, far)
+                * Mat4::from(world_from_view.inverse());
+        let expected = ViewFrustum::from_clip_from_world_custom_far(
+            &clip_from_world,
+            &world_from_view.translation.into(),
+            &world_from_view.z_axis.into(),
+            far,
+        );
+        for (corner, expected) in frustum
+            .corners()
+            .unwrap()
+            .into_iter()
+            .zip(expected.corners().unwrap())
+        {
+            assert_relative_eq!(corner, expected, epsilon = 1e-4);
+        }
+    }
+
+    #[test]
+    fn from_degenerate_corners() {
+        // the near plane of the pyramid frustum collapses into a single point
+        let corners = pyramid_frustum().corners().unwrap();
+        assert!(ViewFrustum::from_corners(corners).is_none());
+        assert!(ViewFrustum::from_corners([Vec3::ONE; 8]).is_none());
+    }
+
+    #[test]
+    fn named_planes() {
+        let frustum = cuboid_frustum();
+        assert_eq!(frustum.left(), frustum.half_spaces[0]);
+        assert_eq!(frustum.right(), frustum.half_spaces[1]);
+        assert_eq!(frustum.top(), frustum.half_spaces[2]);
+        assert_eq!(frustum.bottom(), frustum.half_spaces[3]);
+        assert_eq!(
+            frustum.near(),
+            frustum.half_spaces[ViewFrustum::NEAR_PLANE_IDX]
+        );
+        assert_eq!(
+            frustum.far(),
+            Some(frustum.half_spaces[ViewFrustum::FAR_PLANE_IDX])
+        );
+
+        assert_eq!(no_far_frustum().far(), None);
+    }
+
+    #[test]
+    fn transformed_frustum() {
+        let transform = Mat4::from_scale_rotation_translation(
+            Vec3::new(1., 2., 0.5),
+            Quat::from_rotation_y(0.7),
+            Vec3::new(10., -3., 2.),
+        );
+        let points = [
+            Vec3::new(0., 3., 0.),
+            Vec3::new(-4.9, 5.9, 2.9),
+            Vec3::new(-6., 3., 0.),
+            Vec3::new(0., -1., 0.),
+            Vec3::new(0., 7., 0.),
+        ];
+
+        let cuboid = cuboid_frustum();
+        let transformed = cuboid.transformed(&transform);
+        for point in points {
+            assert_eq!(
+                cuboid.contains_point(point),
+                transformed.contains_point(transform.transform_point3(point))
+            );
+        }
+
+        let no_far = no_far_frustum();
+        let transformed = no_far.transformed(&transform);
+        assert_eq!(transformed.far(), None);
+        for point in points {
+            assert_eq!(
+                no_far.contains_point(point),
+                transformed.contains_point(transform.transform_point3(point))
+            );
+        }
+    }
+
+    #[test]
+    fn with_far_plane() {
+        let clip_from_world = Mat4::perspective_infinite_reverse_rh(1.0, 1.5, 0.1)
+            * Mat4::look_at_rh(Vec3::new(1., 2., 3.), Vec3::ZERO, Vec3::Y);
+        let view_translation = Vec3::new(1., 2., 3.);
+        let view_backward = view_translation.normalize();
+
+        let staged = ViewFrustum::from_clip_from_world_no_far(&clip_from_world).with_far_plane(
+            20.,
+            view_translation,
+            view_backward,
+        );
+        let custom_far = ViewFrustum::from_clip_from_world_custom_far(
+            &clip_from_world,
+            &view_translation,
+            &view_backward,
+            20.,
+        );
+        assert_eq!(staged, custom_far);
+        assert!(staged.has_far_plane());
+        assert!(staged.contains_point(Vec3::ZERO));
+        assert!(!staged.contains_point(-30. * view_backward));
+    }
+
+    #[test]
+    fn split_depth() {
+        let cuboid = cuboid_frustum();
+        assert_eq!(cuboid.split_depth(0., 1.), cuboid);
+
+        // near: y = 0; far: y = 6
+        let split = cuboid.split_depth(0.25, 0.5);
+        let corners = split.corners().unwrap();
+        assert_relative_eq!(corners[0], Vec3::new(-5., 1.5, 3.), epsilon = 2e-7);
+        assert_relative_eq!(corners[6], Vec3::new(4., 3., -2.), epsilon = 2e-7);
+        assert_eq!(split.left(), cuboid.left());
+        assert_eq!(split.bottom(), cuboid.bottom());
+
+        let no_far = no_far_frustum();
+        let split = no_far.split_depth(0.25, 0.5);
+        assert_eq!(split.near(), no_far.near());
+        assert_eq!(split.far(), None);
+    }
+
+    #[test]
+    fn bounding_aabb() {
+        let (min, max) = cuboid_frustum().bounding_aabb().unwrap();
+        assert_relative_eq!(min, Vec3::new(-5., 0., -2.), epsilon = 2e-7);
+        assert_relative_eq!(max, Vec3::new(4., 6., 3.), epsilon = 2e-7);
+
+        let (min, max) = pyramid_frustum().bounding_aabb().unwrap();
+        assert_relative_eq!(min, Vec3::new(-4., -1., -4.), epsilon = 2e-7);
+        assert_relative_eq!(max, Vec3::new(4., 3., 4.), epsilon = 2e-7);
+
+        // the NAN far corners are ignored
+        let (min, max) = no_far_frustum().bounding_aabb().unwrap();
+        assert_relative_eq!(min, Vec3::new(-1., 0., -1.), epsilon = 2e-7);
+        assert_relative_eq!(max, Vec3::new(1., 0., 1.), epsilon = 2e-7);
     }
 }
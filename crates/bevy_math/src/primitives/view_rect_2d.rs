@@ -0,0 +1,192 @@
+use crate::{Mat4, Vec2, Vec3};
+
+#[cfg(feature = "bevy_reflect")]
+use bevy_reflect::{std_traits::ReflectDefault, Reflect};
+#[cfg(all(feature = "serialize", feature = "bevy_reflect"))]
+use bevy_reflect::{ReflectDeserialize, ReflectSerialize};
+
+/// A region of 2D space defined by the intersection of 4 half-planes,
+/// the 2D counterpart of a [`ViewFrustum`](crate::primitives::ViewFrustum).
+///
+/// View rects are the area seen by a 2D camera, which is a rectangle that may be rotated.
+///
+/// Each half-plane is stored as a [`Vec3`] whose `x` and `y` components are the unit normal of
+/// its bounding line, pointing towards the interior of the rect, and whose `z` component is the
+/// signed distance along the normal from the line to the origin. A point `p` is inside the
+/// half-plane when `normal.dot(p) + distance > 0.0`. The half-planes are ordered left, right,
+/// top, bottom, the same way as the side half-spaces of a
+/// [`ViewFrustum`](crate::primitives::ViewFrustum).
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+#[cfg_attr(feature = "serialize", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "bevy_reflect",
+    derive(Reflect),
+    reflect(Clone, Debug, Default, PartialEq)
+)]
+#[cfg_attr(
+    all(feature = "serialize", feature = "bevy_reflect"),
+    reflect(Serialize, Deserialize)
+)]
+pub struct ViewRect2d {
+    /// The four half-planes making up the rect
+    pub half_planes: [Vec3; 4],
+}
+
+impl ViewRect2d {
+    /// Returns a view rect derived from `clip_from_world`.
+    ///
+    /// The world's z coordinate is ignored, which is exact for projections whose x and y clip
+    /// coordinates don't depend on depth, such as the orthographic projections of 2D cameras.
+    #[inline]
+    pub fn from_clip_from_world(clip_from_world: &Mat4) -> Self {
+        // The x, y and w components of each row, as z only multiplies the ignored coordinate.
+        let row = |index| {
+            let row = clip_from_world.row(index);
+            Vec3::new(row.x, row.y, row.w)
+        };
+        let [row0, row1, row3] = [row(0), row(1), row(3)];
+
+        Self {
+            half_planes: [
+                Self::half_plane(row3 + row0),
+                Self::half_plane(row3 - row0),
+                Self::half_plane(row3 + row1),
+                Self::half_plane(row3 - row1),
+            ],
+        }
+    }
+
+    /// Calculates the corners of this rect.
+    ///
+    /// The corners are returned in the same order as the near corners of
+    /// [`ViewFrustum::corners`](crate::primitives::ViewFrustum::corners), each being the
+    /// intersection of two half-planes: top and left, top and right, bottom and right,
+    /// bottom and left.
+    /// If two adjacent half-planes are parallel, their corner is not finite.
+    #[inline]
+    pub fn corners(&self) -> [Vec2; 4] {
+        let [left, right, top, bottom] = self.half_planes;
+        [(top, left), (top, right), (bottom, right), (bottom, left)].map(|(a, b)| {
+            // The intersection of two lines in homogeneous coordinates.
+            let point = a.cross(b);
+            point.truncate() / point.z
+        })
+    }
+
+    /// Checks if `point` lies inside the rect.
+    ///
+    /// A point is inside when it is within every half-plane of the rect.
+    #[inline]
+    pub fn contains_point(&self, point: Vec2) -> bool {
+        let point = point.extend(1.0);
+        self.half_planes
+            .iter()
+            .all(|half_plane| half_plane.dot(point) > 0.0)
+    }
+
+    /// Checks if the axis-aligned bounding box spanning from `min` to `max` intersects the rect.
+    ///
+    /// Unlike [`ViewFrustum::intersects_aabb`](crate::primitives::ViewFrustum::intersects_aabb),
+    /// this is exact: in 2D the only possible separating axes are the normals of the rect's
+    /// half-planes and the x and y axes of the box, and all of them are tested.
+    #[inline]
+    pub fn intersects_aabb(&self, min: Vec2, max: Vec2) -> bool {
+        let outside_half_plane = self.half_planes.iter().any(|half_plane| {
+            let normal = half_plane.truncate();
+            let positive_vertex = Vec2::select(normal.cmpge(Vec2::ZERO), max, min);
+            normal.dot(positive_vertex) + half_plane.z <= 0.0
+        });
+        if outside_half_plane {
+            return false;
+        }
+
+        let corners = self.corners();
+        let corners_min = corners.into_iter().fold(Vec2::INFINITY, Vec2::min);
+        let corners_max = corners.into_iter().fold(Vec2::NEG_INFINITY, Vec2::max);
+        corners_min.cmplt(max).all() && corners_max.cmpgt(min).all()
+    }
+
+    /// Returns `normal_d` scaled so that its normal is a unit vector.
+    #[inline]
+    fn half_plane(normal_d: Vec3) -> Vec3 {
+        normal_d / normal_d.truncate().length()
+    }
+}
+
+#[cfg(test)]
+mod view_rect_2d_tests {
+    use core::f32::consts::{FRAC_PI_4, SQRT_2};
+
+    use approx::assert_relative_eq;
+
+    use super::ViewRect2d;
+    use crate::{Mat4, Quat, Vec2};
+
+    /// A 4x2 view rect centered on `center`, rotated by `angle` radians.
+    fn view_rect(center: Vec2, angle: f32) -> ViewRect2d {
+        let world_from_view =
+            Mat4::from_rotation_translation(Quat::from_rotation_z(angle), center.extend(0.0));
+        let clip_from_view = Mat4::orthographic_rh(-2.0, 2.0, -1.0, 1.0, 0.0, 1000.0);
+        ViewRect2d::from_clip_from_world(&(clip_from_view * world_from_view.inverse()))
+    }
+
+    #[test]
+    fn axis_aligned_view_rect() {
+        let view_rect = view_rect(Vec2::new(1.0, 0.0), 0.0);
+
+        let corners = view_rect.corners();
+        for (corner, expected) in corners.into_iter().zip([
+            Vec2::new(-1.0, -1.0),
+            Vec2::new(3.0, -1.0),
+            Vec2::new(3.0, 1.0),
+            Vec2::new(-1.0, 1.0),
+        ]) {
+            assert_relative_eq!(corner, expected, epsilon = 1e-5);
+        }
+
+        assert!(view_rect.contains_point(Vec2::new(1.0, 0.0)));
+        assert!(view_rect.contains_point(Vec2::new(2.9, 0.9)));
+        assert!(!view_rect.contains_point(Vec2::new(3.1, 0.0)));
+        assert!(!view_rect.contains_point(Vec2::new(1.0, -1.1)));
+
+        // Inside, overlapping an edge, and enclosing the whole rect.
+        assert!(view_rect.intersects_aabb(Vec2::new(0.0, -0.5), Vec2::new(1.0, 0.5)));
+        assert!(view_rect.intersects_aabb(Vec2::new(2.5, 0.5), Vec2::new(4.0, 2.0)));
+        assert!(view_rect.intersects_aabb(Vec2::splat(-10.0), Vec2::splat(10.0)));
+        // Beyond each side.
+        assert!(!view_rect.intersects_aabb(Vec2::new(-3.0, -0.5), Vec2::new(-1.5, 0.5)));
+        assert!(!view_rect.intersects_aabb(Vec2::new(3.5, -0.5), Vec2::new(5.0, 0.5)));
+        assert!(!view_rect.intersects_aabb(Vec2::new(0.0, 1.5), Vec2::new(1.0, 2.0)));
+        assert!(!view_rect.intersects_aabb(Vec2::new(0.0, -2.0), Vec2::new(1.0, -1.5)));
+    }
+
+    #[test]
+    fn rotated_view_rect() {
+        let view_rect = view_rect(Vec2::ZERO, FRAC_PI_4);
+
+        // The corners of the rect, rotated by 45 degrees.
+        let corners = view_rect.corners();
+        for (corner, expected) in corners.into_iter().zip([
+            Vec2::new(-0.5, -1.5) * SQRT_2,
+            Vec2::new(1.5, 0.5) * SQRT_2,
+            Vec2::new(0.5, 1.5) * SQRT_2,
+            Vec2::new(-1.5, -0.5) * SQRT_2,
+        ]) {
+            assert_relative_eq!(corner, expected, epsilon = 1e-5);
+        }
+
+        // Along the rotated long axis, the rect reaches further than 2 units along x.
+        assert!(view_rect.contains_point(Vec2::splat(1.3)));
+        assert!(!view_rect.contains_point(Vec2::splat(1.5)));
+        // Across the rotated short axis, it doesn't reach the unrotated rect's corner.
+        assert!(view_rect.contains_point(Vec2::new(-0.6, 0.6)));
+        assert!(!view_rect.contains_point(Vec2::new(-0.8, 0.8)));
+
+        assert!(view_rect.intersects_aabb(Vec2::splat(1.0), Vec2::splat(2.0)));
+        // Outside one of the rect's half-planes.
+        assert!(!view_rect.intersects_aabb(Vec2::new(-2.0, 1.0), Vec2::new(-1.0, 2.0)));
+        // Passes the positive vertex test of every half-plane near the rect's rightmost corner,
+        // but is separated along the box's x axis, as the rect ends at x = 1.5 * sqrt(2).
+        assert!(!view_rect.intersects_aabb(Vec2::new(2.2, 0.4), Vec2::new(3.0, 1.0)));
+    }
+}
@@ -2497,6 +2497,7 @@ impl Mesh {
 /// [`Mesh::ATTRIBUTE_UV_1`].
 /// The default is [`UvChannel::Uv0`].
 #[derive(Reflect, Default, Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serialize", derive(Serialize, Deserialize))]
 #[reflect(Default, Debug, Clone, PartialEq)]
 pub enum UvChannel {
     #[default]
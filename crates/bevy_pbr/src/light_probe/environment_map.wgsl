@@ -1,6 +1,9 @@
 #define_import_path bevy_pbr::environment_map
 
-#import bevy_pbr::light_probe::{light_probe_iterator_new, light_probe_iterator_next}
+#import bevy_pbr::light_probe::{
+    light_probe_iterator_new, light_probe_iterator_next, light_probe_priority_coverage,
+    light_probe_priority_weight
+}
 #import bevy_pbr::mesh_view_bindings as bindings
 #import bevy_pbr::mesh_view_bindings::light_probes
 #import bevy_pbr::mesh_view_bindings::environment_map_uniform
@@ -112,12 +115,23 @@ fn compute_radiances(
         clusterable_object_index_ranges,
     );
 
+    // Lower-priority reflection probes only fill in what the highest-priority
+    // ones don't cover.
+    let priority_coverage = light_probe_priority_coverage(
+        world_position,
+        /*is_irradiance_volume=*/ false,
+        clusterable_object_index_ranges,
+    );
+
     var total_weight = 0.0;
     radiances.irradiance = vec3(0.0);
     radiances.radiance = vec3(0.0);
 
     while (true) {
         var query_result = light_probe_iterator_next(&iterator);
+        if (query_result.texture_index >= 0) {
+            query_result.weight = light_probe_priority_weight(query_result, priority_coverage);
+        }
 
         // If we reached the end of the light probe list, and we didn't find
         // enough reflection probes to reach a weight of 1.0, use the view
@@ -158,6 +172,8 @@ fn compute_radiances(
             (query_result.flags & LIGHT_PROBE_FLAG_AFFECTS_LIGHTMAPPED_MESH_DIFFUSE) != 0u;
 #endif  // LIGHTMAP
 
+        // Each probe computes its own sample direction, so probes with and
+        // without parallax correction can be blended together.
         let parallax_correct = (query_result.flags & LIGHT_PROBE_FLAG_PARALLAX_CORRECT) != 0u;
 
         if (enable_diffuse) {
@@ -168,7 +184,7 @@ fn compute_radiances(
                 query_result.parallax_correction_bounds,
                 parallax_correct
             );
-            radiances.irradiance = textureSampleLevel(
+            radiances.irradiance += textureSampleLevel(
                 bindings::diffuse_environment_maps[query_result.texture_index],
                 bindings::environment_map_sampler,
                 irradiance_sample_dir,
@@ -1,6 +1,9 @@
 #define_import_path bevy_pbr::irradiance_volume
 
-#import bevy_pbr::light_probe::{light_probe_iterator_new, light_probe_iterator_next}
+#import bevy_pbr::light_probe::{
+    light_probe_iterator_new, light_probe_iterator_next, light_probe_priority_coverage,
+    light_probe_priority_weight
+}
 #import bevy_pbr::mesh_view_bindings::{
     irradiance_volumes,
     irradiance_volume,
@@ -27,6 +30,14 @@ fn irradiance_volume_light(
         clusterable_object_index_ranges,
     );
 
+    // Lower-priority irradiance volumes only fill in what the highest-priority
+    // ones don't cover.
+    let priority_coverage = light_probe_priority_coverage(
+        world_position,
+        /*is_irradiance_volume=*/ true,
+        clusterable_object_index_ranges,
+    );
+
     var total_weight = 0.0;
     var total_irradiance = vec3(0.0);
 
@@ -38,6 +49,8 @@ fn irradiance_volume_light(
             break;
         }
 
+        query_result.weight = light_probe_priority_weight(query_result, priority_coverage);
+
         // If we're lightmapped, and the irradiance volume contributes no diffuse
         // light, then bail out.
     #ifdef LIGHTMAP
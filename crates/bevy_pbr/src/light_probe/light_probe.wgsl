@@ -32,6 +32,15 @@ struct LightProbeQueryResult {
     // The flags that the light probe has: a combination of
     // `LIGHT_PROBE_FLAG_*`.
     flags: u32,
+    // The priority of the light probe relative to overlapping light probes.
+    priority: i32,
+};
+
+// The highest priority among the light probes that contain a fragment, along
+// with the total weight of the light probes that have that priority.
+struct LightProbePriorityCoverage {
+    priority: i32,
+    weight: f32,
 };
 
 fn transpose_affine_matrix(matrix: mat3x4<f32>) -> mat4x4<f32> {
@@ -130,9 +139,9 @@ fn light_probe_iterator_next(iterator: ptr<function, LightProbeIterator>) -> Lig
         // Avoid division by zero.
         let falloff = max(light_probe.falloff, vec3(0.0001));
         // Calculate the per-axis weight by doing a linear ramp from 0.0 at the
-        // inside of the falloff region to 1.0 at the outside of the falloff
-        // region.
-        let axis_weights = saturate((1.0 - 2.0 * abs(probe_space_pos)) / (2.0 * falloff));
+        // outside of the falloff region to 1.0 at the inside of the falloff
+        // region. This must match `LightProbe::falloff_weight`.
+        let axis_weights = saturate((1.0 - 2.0 * abs(probe_space_pos)) / falloff);
         // The actual weight is the minimum of all the per-axis weights.
         let weight = min(min(axis_weights.x, axis_weights.y), axis_weights.z);
         // If the resulting weight is zero, we're outside the light probe
@@ -146,6 +155,7 @@ fn light_probe_iterator_next(iterator: ptr<function, LightProbeIterator>) -> Lig
         result.light_from_world = light_from_world;
         result.parallax_correction_bounds = light_probe.parallax_correction_bounds;
         result.flags = light_probe.flags;
+        result.priority = light_probe.priority;
         result.weight = weight;
         return result;
     }
@@ -226,9 +236,9 @@ fn light_probe_iterator_next(iterator: ptr<function, LightProbeIterator>) -> Lig
         // Avoid division by zero.
         let falloff = max(light_probe.falloff, vec3(0.0001));
         // Calculate the per-axis weight by doing a linear ramp from 0.0 at the
-        // inside of the falloff region to 1.0 at the outside of the falloff
-        // region.
-        let axis_weights = saturate((1.0 - 2.0 * abs(probe_space_pos)) / (2.0 * falloff));
+        // outside of the falloff region to 1.0 at the inside of the falloff
+        // region. This must match `LightProbe::falloff_weight`.
+        let axis_weights = saturate((1.0 - 2.0 * abs(probe_space_pos)) / falloff);
         // The actual weight is the minimum of all the per-axis weights.
         let weight = min(min(axis_weights.x, axis_weights.y), axis_weights.z);
         // If the resulting weight is zero, we're outside the light probe
@@ -242,6 +252,7 @@ fn light_probe_iterator_next(iterator: ptr<function, LightProbeIterator>) -> Lig
         result.light_from_world = light_from_world;
         result.parallax_correction_bounds = light_probe.parallax_correction_bounds;
         result.flags = light_probe.flags;
+        result.priority = light_probe.priority;
         result.weight = weight;
         return result;
     }
@@ -250,3 +261,66 @@ fn light_probe_iterator_next(iterator: ptr<function, LightProbeIterator>) -> Lig
 }
 
 #endif  // AVAILABLE_STORAGE_BUFFER_BINDINGS >= 3
+
+// Finds the highest priority among the light probes of one type that contain
+// the fragment, and how much those light probes cover the fragment.
+fn light_probe_priority_coverage(
+    world_position: vec3<f32>,
+    is_irradiance_volume: bool,
+    clusterable_object_index_ranges: ptr<function, ClusterableObjectIndexRanges>,
+) -> LightProbePriorityCoverage {
+    var coverage: LightProbePriorityCoverage;
+    coverage.priority = 0;
+    coverage.weight = 0.0;
+
+    // If all light probes of this type have the same priority, none of them
+    // are weakened, so skip iterating over them a second time. The lowest
+    // possible priority makes `light_probe_priority_weight` keep every weight.
+    var priorities_differ = light_probes.reflection_probe_priorities_differ;
+    if (is_irradiance_volume) {
+        priorities_differ = light_probes.irradiance_volume_priorities_differ;
+    }
+    if (priorities_differ == 0u) {
+        coverage.priority = -2147483647 - 1;
+        return coverage;
+    }
+
+    var found = false;
+    var iterator = light_probe_iterator_new(
+        world_position,
+        is_irradiance_volume,
+        clusterable_object_index_ranges,
+    );
+    while (true) {
+        let query_result = light_probe_iterator_next(&iterator);
+        if (query_result.texture_index < 0) {
+            break;
+        }
+
+        if (!found || query_result.priority > coverage.priority) {
+            found = true;
+            coverage.priority = query_result.priority;
+            coverage.weight = query_result.weight;
+        } else if (query_result.priority == coverage.priority) {
+            coverage.weight += query_result.weight;
+        }
+    }
+
+    return coverage;
+}
+
+// Returns the weight of a light probe, taking priorities into account.
+//
+// The highest-priority light probes keep their falloff weight, while
+// lower-priority light probes only fill in the influence that the
+// highest-priority ones leave over. If all light probes have the same priority,
+// this is just the falloff weight.
+fn light_probe_priority_weight(
+    query_result: LightProbeQueryResult,
+    coverage: LightProbePriorityCoverage,
+) -> f32 {
+    if (query_result.priority >= coverage.priority) {
+        return query_result.weight;
+    }
+    return query_result.weight * (1.0 - saturate(coverage.weight));
+}
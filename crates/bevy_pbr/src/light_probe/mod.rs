@@ -34,7 +34,7 @@ use bevy_transform::{components::Transform, prelude::GlobalTransform};
 use bitflags::bitflags;
 use tracing::error;
 
-use core::{any::TypeId, hash::Hash, ops::Deref};
+use core::{any::TypeId, cmp::Reverse, hash::Hash, ops::Deref};
 
 use crate::{
     extract_clusters, generate::EnvironmentMapGenerationPlugin,
@@ -100,6 +100,11 @@ struct RenderLightProbe {
     /// Various flags associated with the light probe: the bit value of
     /// [`RenderLightProbeFlags`].
     flags: u32,
+
+    /// The priority of the light probe relative to overlapping light probes.
+    ///
+    /// See the comments in [`LightProbe`] for more details.
+    priority: i32,
 }
 
 /// A per-view shader uniform that specifies all the light probes that the view
@@ -139,6 +144,17 @@ pub struct LightProbesUniform {
     ///
     /// This will be 1 if the map does affect lightmapped meshes or 0 otherwise.
     view_environment_map_affects_lightmapped_mesh_diffuse: u32,
+
+    /// Whether the reflection probes in the list have different priorities.
+    ///
+    /// This will be 1 if they do or 0 otherwise. If it's 0, the shader skips
+    /// the pass that weighs light probes by priority.
+    reflection_probe_priorities_differ: u32,
+
+    /// Whether the irradiance volumes in the list have different priorities.
+    ///
+    /// This will be 1 if they do or 0 otherwise.
+    irradiance_volume_priorities_differ: u32,
 }
 
 /// A GPU buffer that stores information about all light probes.
@@ -196,6 +212,11 @@ where
     // Various flags associated with the light probe.
     flags: RenderLightProbeFlags,
 
+    // The priority of the light probe relative to overlapping light probes.
+    //
+    // See the comments in [`LightProbe`] for more details.
+    priority: i32,
+
     // The IDs of all assets associated with this light probe.
     //
     // Because each type of light probe component may reference different types
@@ -484,9 +505,13 @@ fn gather_light_probes<C>(
             }
         }
 
-        // Sort by distance to camera.
+        // Sort by priority, then by distance to camera, so that the
+        // highest-priority light probes are kept if there are too many.
         view_light_probe_info.sort_by_cached_key(|light_probe_info| {
-            light_probe_info.camera_distance_sort_key(view_transform)
+            (
+                Reverse(light_probe_info.priority),
+                light_probe_info.camera_distance_sort_key(view_transform),
+            )
         });
 
         // Create the light probes list.
@@ -607,6 +632,12 @@ fn upload_light_probes(
                 }
                 None => 1,
             },
+            reflection_probe_priorities_differ: render_view_environment_maps
+                .is_some_and(RenderViewLightProbes::priorities_differ)
+                as u32,
+            irradiance_volume_priorities_differ: render_view_irradiance_volumes
+                .is_some_and(RenderViewLightProbes::priorities_differ)
+                as u32,
         };
 
         // Add any environment maps that [`gather_light_probes`] found to the
@@ -647,6 +678,8 @@ impl Default for LightProbesUniform {
             smallest_specular_mip_level_for_view: 0,
             intensity_for_view: 1.0,
             view_environment_map_affects_lightmapped_mesh_diffuse: 1,
+            reflection_probe_priorities_differ: 0,
+            irradiance_volume_priorities_differ: 0,
         }
     }
 }
@@ -685,6 +718,7 @@ where
             asset_id: id,
             intensity: environment_map.intensity(),
             flags: environment_map.flags(&query_components),
+            priority: light_probe.priority,
         })
     }
 
@@ -723,6 +757,14 @@ where
         self.render_light_probes.len()
     }
 
+    /// Returns true if the light probes in the list don't all have the same
+    /// priority.
+    fn priorities_differ(&self) -> bool {
+        self.render_light_probes
+            .windows(2)
+            .any(|pair| pair[0].priority != pair[1].priority)
+    }
+
     /// Adds a cubemap to the list of bindings, if it wasn't there already, and
     /// returns its index within that list.
     pub(crate) fn get_or_insert_cubemap(&mut self, cubemap_id: &C::AssetId) -> u32 {
@@ -769,6 +811,7 @@ where
                 texture_index: cubemap_index as i32,
                 intensity: light_probe.intensity,
                 flags: light_probe.flags.bits() as u32,
+                priority: light_probe.priority,
             });
         }
     }
@@ -787,6 +830,7 @@ where
             parallax_correction_bounds: self.parallax_correction_bounds,
             intensity: self.intensity,
             flags: self.flags,
+            priority: self.priority,
             asset_id: self.asset_id.clone(),
         }
     }
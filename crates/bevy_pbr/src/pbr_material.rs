@@ -1,5 +1,6 @@
 use bevy_asset::Asset;
 use bevy_color::{Alpha, ColorToComponents};
+use bevy_gltf::GltfMaterial;
 use bevy_material::OpaqueRendererMethod;
 use bevy_math::{Affine2, Affine3, Mat2, Mat3, Vec2, Vec3, Vec4};
 use bevy_mesh::{MeshVertexBufferLayoutRef, UvChannel};
@@ -17,7 +18,7 @@ use crate::{deferred::DEFAULT_PBR_DEFERRED_LIGHTING_PASS_ID, *};
 #[derive(Asset, AsBindGroup, Reflect, Debug, Clone)]
 #[bind_group_data(StandardMaterialKey)]
 #[data(0, StandardMaterialUniform, binding_array(10))]
-#[bindless(index_table(range(0..31)))]
+#[bindless(index_table(range(0..35)))]
 #[reflect(Default, Debug, Clone)]
 pub struct StandardMaterial {
     /// The color of the surface of the material before lighting.
@@ -333,6 +334,17 @@ pub struct StandardMaterial {
     #[doc(alias = "refractive_index")]
     pub ior: f32,
 
+    /// How much the [`StandardMaterial::ior`] varies with the wavelength of light, splitting
+    /// transmitted light into its component colors like a prism.
+    ///
+    /// This is `20 / abbe_number`, as defined by `KHR_materials_dispersion`. For example, diamond
+    /// has an Abbe number of about 55, for a dispersion of about `0.36`. Defaults to `0.0`,
+    /// which disables dispersion.
+    ///
+    /// **Note:** Only affects [`StandardMaterial::specular_transmission`], so this does nothing
+    /// when that is `0.0`. Nonzero values sample the transmitted background once per color channel.
+    pub dispersion: f32,
+
     /// How far, on average, light travels through the volume beneath the material's
     /// surface before being absorbed.
     ///
@@ -405,6 +417,13 @@ pub struct StandardMaterial {
     #[dependency]
     pub normal_map_texture: Option<Handle<Image>>,
 
+    /// Scales the X and Y components of the tangent-space normal sampled from the
+    /// [`StandardMaterial::normal_map_texture`], making bumps and dents stronger or weaker.
+    ///
+    /// This matches `normalTexture.scale` in glTF. Defaults to `1.0`, which applies the normal
+    /// map as-is.
+    pub normal_map_scale: f32,
+
     /// Normal map textures authored for DirectX have their y-component flipped. Set this to flip
     /// it to right-handed conventions.
     pub flip_normal_map_y: bool,
@@ -429,6 +448,13 @@ pub struct StandardMaterial {
     #[dependency]
     pub occlusion_texture: Option<Handle<Image>>,
 
+    /// How strongly the [`StandardMaterial::occlusion_texture`] darkens the material, within
+    /// `[0.0, 1.0]`.
+    ///
+    /// The sampled occlusion is blended towards `1.0` (fully exposed) as this decreases, so `0.0`
+    /// ignores the texture. This matches `occlusionTexture.strength` in glTF. Defaults to `1.0`.
+    pub occlusion_strength: f32,
+
     /// The UV channel to use for the [`StandardMaterial::specular_texture`].
     ///
     /// Defaults to [`UvChannel::Uv0`].
@@ -625,6 +651,67 @@ pub struct StandardMaterial {
     #[dependency]
     pub anisotropy_texture: Option<Handle<Image>>,
 
+    /// The color of the sheen layer, a retro-reflective highlight at grazing
+    /// angles, used for cloth-like surfaces such as velvet.
+    ///
+    /// The sheen layer sits on top of the base layer and darkens it
+    /// according to its brightness. It only reflects direct lights, not
+    /// environment maps or ambient light, and it isn't rendered by the
+    /// deferred renderer.
+    ///
+    /// Defaults to [`Color::BLACK`], which disables the sheen layer.
+    ///
+    /// See the [`KHR_materials_sheen` specification] for more details.
+    ///
+    /// [`KHR_materials_sheen` specification]:
+    /// https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_sheen/README.md
+    pub sheen_color: Color,
+
+    /// The perceptual roughness of the sheen layer, within `[0.0, 1.0]`.
+    ///
+    /// Low values concentrate the sheen at grazing angles, while high values
+    /// spread it out over the surface. This parameter has no effect if
+    /// [`StandardMaterial::sheen_color`] is black.
+    ///
+    /// Defaults to `0.0`.
+    pub sheen_roughness: f32,
+
+    /// The UV channel to use for the [`StandardMaterial::sheen_color_texture`].
+    ///
+    /// Defaults to [`UvChannel::Uv0`].
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub sheen_color_channel: UvChannel,
+
+    /// An image texture that specifies the sheen color in its RGB channels.
+    /// Values sampled from this texture are multiplied by
+    /// [`StandardMaterial::sheen_color`].
+    ///
+    /// This texture has no effect if [`StandardMaterial::sheen_color`] is
+    /// black.
+    #[cfg_attr(feature = "pbr_sheen_texture", texture(31))]
+    #[cfg_attr(feature = "pbr_sheen_texture", sampler(32))]
+    #[cfg(feature = "pbr_sheen_texture")]
+    #[dependency]
+    pub sheen_color_texture: Option<Handle<Image>>,
+
+    /// The UV channel to use for the
+    /// [`StandardMaterial::sheen_roughness_texture`].
+    ///
+    /// Defaults to [`UvChannel::Uv0`].
+    #[cfg(feature = "pbr_sheen_texture")]
+    pub sheen_roughness_channel: UvChannel,
+
+    /// An image texture that specifies the sheen roughness in its alpha
+    /// channel. Values sampled from this texture are multiplied by
+    /// [`StandardMaterial::sheen_roughness`].
+    ///
+    /// As this is a non-color map, it must not be loaded as sRGB.
+    #[cfg_attr(feature = "pbr_sheen_texture", texture(33))]
+    #[cfg_attr(feature = "pbr_sheen_texture", sampler(34))]
+    #[cfg(feature = "pbr_sheen_texture")]
+    #[dependency]
+    pub sheen_roughness_texture: Option<Handle<Image>>,
+
     /// Support two-sided lighting by automatically flipping the normals for "back" faces
     /// within the PBR lighting shader.
     ///
@@ -877,12 +964,15 @@ impl Default for StandardMaterial {
             #[cfg(feature = "pbr_transmission_textures")]
             thickness_texture: None,
             ior: 1.5,
+            dispersion: 0.0,
             attenuation_color: Color::WHITE,
             attenuation_distance: f32::INFINITY,
             occlusion_channel: UvChannel::Uv0,
             occlusion_texture: None,
+            occlusion_strength: 1.0,
             normal_map_channel: UvChannel::Uv0,
             normal_map_texture: None,
+            normal_map_scale: 1.0,
             #[cfg(feature = "pbr_specular_textures")]
             specular_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_specular_textures")]
@@ -912,6 +1002,16 @@ impl Default for StandardMaterial {
             anisotropy_channel: UvChannel::Uv0,
             #[cfg(feature = "pbr_anisotropy_texture")]
             anisotropy_texture: None,
+            sheen_color: Color::BLACK,
+            sheen_roughness: 0.0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_channel: UvChannel::Uv0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_texture: None,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_channel: UvChannel::Uv0,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_texture: None,
             flip_normal_map_y: false,
             double_sided: false,
             cull_mode: Some(Face::Back),
@@ -954,6 +1054,97 @@ impl From<Handle<Image>> for StandardMaterial {
     }
 }
 
+/// Converts a [`GltfMaterial`] into a [`StandardMaterial`], as done by the glTF loader
+/// when PBR rendering is enabled.
+///
+/// The conversion is lossy:
+/// - [`GltfMaterial::emissive_strength`] is multiplied into [`StandardMaterial::emissive`].
+/// - Only [`GltfMaterial::uv_transform`] is used, per-texture transforms such as
+///   [`GltfMaterial::normal_map_uv_transform`] are ignored.
+/// - Iridescence isn't supported by [`StandardMaterial`] and is dropped.
+impl From<&GltfMaterial> for StandardMaterial {
+    fn from(material: &GltfMaterial) -> Self {
+        StandardMaterial {
+            base_color: material.base_color,
+            base_color_channel: material.base_color_channel.clone(),
+            base_color_texture: material.base_color_texture.clone(),
+            emissive: material.emissive * material.emissive_strength,
+            emissive_channel: material.emissive_channel.clone(),
+            emissive_texture: material.emissive_texture.clone(),
+            perceptual_roughness: material.perceptual_roughness,
+            metallic: material.metallic,
+            metallic_roughness_channel: material.metallic_roughness_channel.clone(),
+            metallic_roughness_texture: material.metallic_roughness_texture.clone(),
+            reflectance: material.reflectance,
+            specular_tint: material.specular_tint,
+            specular_transmission: material.specular_transmission,
+            #[cfg(feature = "pbr_transmission_textures")]
+            specular_transmission_channel: material.specular_transmission_channel.clone(),
+            #[cfg(feature = "pbr_transmission_textures")]
+            specular_transmission_texture: material.specular_transmission_texture.clone(),
+            thickness: material.thickness,
+            #[cfg(feature = "pbr_transmission_textures")]
+            thickness_channel: material.thickness_channel.clone(),
+            #[cfg(feature = "pbr_transmission_textures")]
+            thickness_texture: material.thickness_texture.clone(),
+            ior: material.ior,
+            dispersion: material.dispersion,
+            attenuation_distance: material.attenuation_distance,
+            attenuation_color: material.attenuation_color,
+            normal_map_channel: material.normal_map_channel.clone(),
+            normal_map_texture: material.normal_map_texture.clone(),
+            normal_map_scale: material.normal_map_scale,
+            occlusion_channel: material.occlusion_channel.clone(),
+            occlusion_texture: material.occlusion_texture.clone(),
+            occlusion_strength: material.occlusion_strength,
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_channel: material.specular_channel.clone(),
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_texture: material.specular_texture.clone(),
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_tint_channel: material.specular_tint_channel.clone(),
+            #[cfg(feature = "pbr_specular_textures")]
+            specular_tint_texture: material.specular_tint_texture.clone(),
+            clearcoat: material.clearcoat,
+            clearcoat_perceptual_roughness: material.clearcoat_perceptual_roughness,
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_channel: material.clearcoat_channel.clone(),
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_texture: material.clearcoat_texture.clone(),
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_roughness_channel: material.clearcoat_roughness_channel.clone(),
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_roughness_texture: material.clearcoat_roughness_texture.clone(),
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_normal_channel: material.clearcoat_normal_channel.clone(),
+            #[cfg(feature = "pbr_multi_layer_material_textures")]
+            clearcoat_normal_texture: material.clearcoat_normal_texture.clone(),
+            anisotropy_strength: material.anisotropy_strength,
+            anisotropy_rotation: material.anisotropy_rotation,
+            #[cfg(feature = "pbr_anisotropy_texture")]
+            anisotropy_channel: material.anisotropy_channel.clone(),
+            #[cfg(feature = "pbr_anisotropy_texture")]
+            anisotropy_texture: material.anisotropy_texture.clone(),
+            sheen_color: material.sheen_color,
+            sheen_roughness: material.sheen_roughness,
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_channel: material.sheen_color_channel.clone(),
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_color_texture: material.sheen_color_texture.clone(),
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_channel: material.sheen_roughness_channel.clone(),
+            #[cfg(feature = "pbr_sheen_texture")]
+            sheen_roughness_texture: material.sheen_roughness_texture.clone(),
+            double_sided: material.double_sided,
+            cull_mode: material.cull_mode,
+            unlit: material.unlit,
+            alpha_mode: material.alpha_mode,
+            uv_transform: material.uv_transform,
+            ..Default::default()
+        }
+    }
+}
+
 // NOTE: These must match the bit flags in bevy_pbr/src/render/pbr_types.wgsl!
 bitflags::bitflags! {
     /// Bitflags info about the material a shader is currently rendering.
@@ -980,6 +1171,8 @@ bitflags::bitflags! {
         const ANISOTROPY_TEXTURE         = 1 << 17;
         const SPECULAR_TEXTURE           = 1 << 18;
         const SPECULAR_TINT_TEXTURE      = 1 << 19;
+        const SHEEN_COLOR_TEXTURE        = 1 << 20;
+        const SHEEN_ROUGHNESS_TEXTURE    = 1 << 21;
         const ALPHA_MODE_RESERVED_BITS   = Self::ALPHA_MODE_MASK_BITS << Self::ALPHA_MODE_SHIFT_BITS; // ← Bitmask reserving bits for the `AlphaMode`
         const ALPHA_MODE_OPAQUE          = 0 << Self::ALPHA_MODE_SHIFT_BITS;                          // ← Values are just sequential values bitshifted into
         const ALPHA_MODE_MASK            = 1 << Self::ALPHA_MODE_SHIFT_BITS;                          //   the bitmask, and can range from 0 to 7.
@@ -1027,12 +1220,22 @@ pub struct StandardMaterialUniform {
     pub thickness: f32,
     /// Index of Refraction
     pub ior: f32,
+    /// How much the index of refraction varies across the color channels
+    pub dispersion: f32,
     /// How far light travels through the volume underneath the material surface before being absorbed
     pub attenuation_distance: f32,
+    /// How strongly the occlusion texture darkens the material
+    pub occlusion_strength: f32,
+    /// The scale of the X and Y components of the sampled tangent-space normal
+    pub normal_map_scale: f32,
     pub clearcoat: f32,
     pub clearcoat_perceptual_roughness: f32,
     pub anisotropy_strength: f32,
     pub anisotropy_rotation: Vec2,
+    /// The color of the sheen layer, black if there's no sheen
+    pub sheen_color: Vec3,
+    /// Linear perceptual roughness of the sheen layer
+    pub sheen_roughness: f32,
     /// The [`StandardMaterialFlags`] accessible in the `wgsl` shader.
     pub flags: u32,
     /// When the alpha mode mask flag is set, any base color alpha above this cutoff means fully opaque,
@@ -1104,6 +1307,16 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
             }
         }
 
+        #[cfg(feature = "pbr_sheen_texture")]
+        {
+            if self.sheen_color_texture.is_some() {
+                flags |= StandardMaterialFlags::SHEEN_COLOR_TEXTURE;
+            }
+            if self.sheen_roughness_texture.is_some() {
+                flags |= StandardMaterialFlags::SHEEN_ROUGHNESS_TEXTURE;
+            }
+        }
+
         #[cfg(feature = "pbr_specular_textures")]
         {
             if self.specular_texture.is_some() {
@@ -1183,11 +1396,16 @@ impl AsBindGroupShaderType<StandardMaterialUniform> for StandardMaterial {
             clearcoat_perceptual_roughness: self.clearcoat_perceptual_roughness,
             anisotropy_strength: self.anisotropy_strength,
             anisotropy_rotation,
+            sheen_color: LinearRgba::from(self.sheen_color).to_vec3(),
+            sheen_roughness: self.sheen_roughness,
             diffuse_transmission: self.diffuse_transmission,
             specular_transmission: self.specular_transmission,
             thickness: self.thickness,
             ior: self.ior,
+            dispersion: self.dispersion,
             attenuation_distance: self.attenuation_distance,
+            occlusion_strength: self.occlusion_strength,
+            normal_map_scale: self.normal_map_scale,
             attenuation_color: LinearRgba::from(self.attenuation_color)
                 .to_f32_array()
                 .into(),
@@ -1231,6 +1449,9 @@ bitflags! {
         const CLEARCOAT_NORMAL_UV      = 0x100000;
         const SPECULAR_UV              = 0x200000;
         const SPECULAR_TINT_UV         = 0x400000;
+        const SHEEN                    = 0x800000;
+        const SHEEN_COLOR_UV           = 0x1000000;
+        const SHEEN_ROUGHNESS_UV       = 0x2000000;
         const DEPTH_BIAS               = 0xffffffff_00000000;
     }
 }
@@ -1281,6 +1502,11 @@ impl From<&StandardMaterial> for StandardMaterialKey {
             material.anisotropy_strength > 0.0,
         );
 
+        key.set(
+            StandardMaterialKey::SHEEN,
+            LinearRgba::from(material.sheen_color).to_vec3() != Vec3::ZERO,
+        );
+
         key.set(
             StandardMaterialKey::BASE_COLOR_UV,
             material.base_color_channel != UvChannel::Uv0,
@@ -1327,6 +1553,18 @@ impl From<&StandardMaterial> for StandardMaterialKey {
             );
         }
 
+        #[cfg(feature = "pbr_sheen_texture")]
+        {
+            key.set(
+                StandardMaterialKey::SHEEN_COLOR_UV,
+                material.sheen_color_channel != UvChannel::Uv0,
+            );
+            key.set(
+                StandardMaterialKey::SHEEN_ROUGHNESS_UV,
+                material.sheen_roughness_channel != UvChannel::Uv0,
+            );
+        }
+
         #[cfg(feature = "pbr_specular_textures")]
         {
             key.set(
@@ -1464,6 +1702,7 @@ impl Material for StandardMaterial {
                     StandardMaterialKey::ANISOTROPY,
                     "STANDARD_MATERIAL_ANISOTROPY",
                 ),
+                (StandardMaterialKey::SHEEN, "STANDARD_MATERIAL_SHEEN"),
                 (
                     StandardMaterialKey::BASE_COLOR_UV,
                     "STANDARD_MATERIAL_BASE_COLOR_UV_B",
@@ -1520,6 +1759,14 @@ impl Material for StandardMaterial {
                     StandardMaterialKey::SPECULAR_TINT_UV,
                     "STANDARD_MATERIAL_SPECULAR_TINT_UV_B",
                 ),
+                (
+                    StandardMaterialKey::SHEEN_COLOR_UV,
+                    "STANDARD_MATERIAL_SHEEN_COLOR_UV_B",
+                ),
+                (
+                    StandardMaterialKey::SHEEN_ROUGHNESS_UV,
+                    "STANDARD_MATERIAL_SHEEN_ROUGHNESS_UV_B",
+                ),
             ] {
                 if key.bind_group_data.intersects(flags) {
                     shader_defs.push(shader_def.into());
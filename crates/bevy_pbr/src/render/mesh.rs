@@ -2560,6 +2560,9 @@ impl SpecializedMeshPipeline for MeshPipeline {
         if cfg!(feature = "pbr_specular_textures") {
             shader_defs.push("PBR_SPECULAR_TEXTURES_SUPPORTED".into());
         }
+        if cfg!(feature = "pbr_sheen_texture") {
+            shader_defs.push("PBR_SHEEN_TEXTURE_SUPPORTED".into());
+        }
         if cfg!(feature = "bluenoise_texture") {
             shader_defs.push("BLUE_NOISE_TEXTURE".into());
         }
@@ -142,6 +142,8 @@ struct LightProbe {
     intensity: f32,
     // Various flags that apply to this light probe.
     flags: u32,
+    // The priority of this light probe relative to overlapping light probes.
+    priority: i32,
 };
 
 struct LightProbes {
@@ -161,6 +163,11 @@ struct LightProbes {
     // Whether the environment map attached to the view affects the diffuse
     // lighting for lightmapped meshes.
     view_environment_map_affects_lightmapped_mesh_diffuse: u32,
+    // Whether the reflection probes have different priorities. If they don't,
+    // weighing them by priority can be skipped.
+    reflection_probe_priorities_differ: u32,
+    // Whether the irradiance volumes have different priorities.
+    irradiance_volume_priorities_differ: u32,
 };
 
 // Settings for screen space reflections.
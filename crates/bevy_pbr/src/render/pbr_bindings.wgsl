@@ -35,6 +35,10 @@ struct StandardMaterialBindings {
     specular_sampler: u32,              // 28
     specular_tint_texture: u32,         // 29
     specular_tint_sampler: u32,         // 30
+    sheen_color_texture: u32,           // 31
+    sheen_color_sampler: u32,           // 32
+    sheen_roughness_texture: u32,       // 33
+    sheen_roughness_sampler: u32,       // 34
 }
 
 @group(#{MATERIAL_BIND_GROUP}) @binding(0) var<storage> material_indices: array<StandardMaterialBindings>;
@@ -86,4 +90,11 @@ struct StandardMaterialBindings {
 @group(#{MATERIAL_BIND_GROUP}) @binding(30) var specular_tint_sampler: sampler;
 #endif  // PBR_SPECULAR_TEXTURES_SUPPORTED
 
+#ifdef PBR_SHEEN_TEXTURE_SUPPORTED
+@group(#{MATERIAL_BIND_GROUP}) @binding(31) var sheen_color_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(32) var sheen_color_sampler: sampler;
+@group(#{MATERIAL_BIND_GROUP}) @binding(33) var sheen_roughness_texture: texture_2d<f32>;
+@group(#{MATERIAL_BIND_GROUP}) @binding(34) var sheen_roughness_sampler: sampler;
+#endif  // PBR_SHEEN_TEXTURE_SUPPORTED
+
 #endif  // BINDLESS
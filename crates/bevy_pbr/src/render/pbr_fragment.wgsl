@@ -242,6 +242,8 @@ pbr_input.material.uv_transform = uv_transform;
     if ((flags & pbr_types::STANDARD_MATERIAL_FLAGS_UNLIT_BIT) == 0u) {
 #ifdef BINDLESS
         pbr_input.material.ior = pbr_bindings::material_array[material_indices[slot].material].ior;
+        pbr_input.material.dispersion =
+                pbr_bindings::material_array[material_indices[slot].material].dispersion;
         pbr_input.material.attenuation_color =
                 pbr_bindings::material_array[material_indices[slot].material].attenuation_color;
         pbr_input.material.attenuation_distance =
@@ -250,6 +252,7 @@ pbr_input.material.uv_transform = uv_transform;
                 pbr_bindings::material_array[material_indices[slot].material].alpha_cutoff;
 #else   // BINDLESS
         pbr_input.material.ior = pbr_bindings::material.ior;
+        pbr_input.material.dispersion = pbr_bindings::material.dispersion;
         pbr_input.material.attenuation_color = pbr_bindings::material.attenuation_color;
         pbr_input.material.attenuation_distance = pbr_bindings::material.attenuation_distance;
         pbr_input.material.alpha_cutoff = pbr_bindings::material.alpha_cutoff;
@@ -493,6 +496,86 @@ pbr_input.material.uv_transform = uv_transform;
                 ).g;
         }
 #endif  // PBR_MULTI_LAYER_MATERIAL_TEXTURES_SUPPORTED
+#endif  // VERTEX_UVS
+
+        // Sheen color
+#ifdef BINDLESS
+        pbr_input.material.sheen_color =
+            pbr_bindings::material_array[material_indices[slot].material].sheen_color;
+#else   // BINDLESS
+        pbr_input.material.sheen_color = pbr_bindings::material.sheen_color;
+#endif  // BINDLESS
+
+#ifdef VERTEX_UVS
+#ifdef PBR_SHEEN_TEXTURE_SUPPORTED
+        if ((flags & pbr_types::STANDARD_MATERIAL_FLAGS_SHEEN_COLOR_TEXTURE_BIT) != 0u) {
+            pbr_input.material.sheen_color *=
+#ifdef MESHLET_MESH_MATERIAL_PASS
+                textureSampleGrad(
+#else   // MESHLET_MESH_MATERIAL_PASS
+                textureSampleBias(
+#endif  // MESHLET_MESH_MATERIAL_PASS
+#ifdef BINDLESS
+                    bindless_textures_2d[material_indices[slot].sheen_color_texture],
+                    bindless_samplers_filtering[material_indices[slot].sheen_color_sampler],
+#else   // BINDLESS
+                    pbr_bindings::sheen_color_texture,
+                    pbr_bindings::sheen_color_sampler,
+#endif  // BINDLESS
+#ifdef STANDARD_MATERIAL_SHEEN_COLOR_UV_B
+                    uv_b,
+#else
+                    uv,
+#endif
+#ifdef MESHLET_MESH_MATERIAL_PASS
+                    bias.ddx_uv,
+                    bias.ddy_uv,
+#else   // MESHLET_MESH_MATERIAL_PASS
+                    bias.mip_bias,
+#endif  // MESHLET_MESH_MATERIAL_PASS
+                ).rgb;
+        }
+#endif  // PBR_SHEEN_TEXTURE_SUPPORTED
+#endif  // VERTEX_UVS
+
+        // Sheen roughness
+#ifdef BINDLESS
+        pbr_input.material.sheen_roughness =
+            pbr_bindings::material_array[material_indices[slot].material].sheen_roughness;
+#else   // BINDLESS
+        pbr_input.material.sheen_roughness = pbr_bindings::material.sheen_roughness;
+#endif  // BINDLESS
+
+#ifdef VERTEX_UVS
+#ifdef PBR_SHEEN_TEXTURE_SUPPORTED
+        if ((flags & pbr_types::STANDARD_MATERIAL_FLAGS_SHEEN_ROUGHNESS_TEXTURE_BIT) != 0u) {
+            pbr_input.material.sheen_roughness *=
+#ifdef MESHLET_MESH_MATERIAL_PASS
+                textureSampleGrad(
+#else   // MESHLET_MESH_MATERIAL_PASS
+                textureSampleBias(
+#endif  // MESHLET_MESH_MATERIAL_PASS
+#ifdef BINDLESS
+                    bindless_textures_2d[material_indices[slot].sheen_roughness_texture],
+                    bindless_samplers_filtering[material_indices[slot].sheen_roughness_sampler],
+#else   // BINDLESS
+                    pbr_bindings::sheen_roughness_texture,
+                    pbr_bindings::sheen_roughness_sampler,
+#endif  // BINDLESS
+#ifdef STANDARD_MATERIAL_SHEEN_ROUGHNESS_UV_B
+                    uv_b,
+#else
+                    uv,
+#endif
+#ifdef MESHLET_MESH_MATERIAL_PASS
+                    bias.ddx_uv,
+                    bias.ddy_uv,
+#else   // MESHLET_MESH_MATERIAL_PASS
+                    bias.mip_bias,
+#endif  // MESHLET_MESH_MATERIAL_PASS
+                ).a;
+        }
+#endif  // PBR_SHEEN_TEXTURE_SUPPORTED
 #endif  // VERTEX_UVS
 
 #ifdef BINDLESS
@@ -628,7 +711,13 @@ pbr_input.material.uv_transform = uv_transform;
         var specular_occlusion: f32 = 1.0;
 #ifdef VERTEX_UVS
         if ((flags & pbr_types::STANDARD_MATERIAL_FLAGS_OCCLUSION_TEXTURE_BIT) != 0u) {
-            diffuse_occlusion *=
+#ifdef BINDLESS
+            let occlusion_strength =
+                    pbr_bindings::material_array[material_indices[slot].material].occlusion_strength;
+#else   // BINDLESS
+            let occlusion_strength = pbr_bindings::material.occlusion_strength;
+#endif  // BINDLESS
+            let occlusion =
 #ifdef MESHLET_MESH_MATERIAL_PASS
                 textureSampleGrad(
 #else   // MESHLET_MESH_MATERIAL_PASS
@@ -653,6 +742,8 @@ pbr_input.material.uv_transform = uv_transform;
                     bias.mip_bias,
 #endif  // MESHLET_MESH_MATERIAL_PASS
                 ).r;
+            // Blend towards no occlusion as the strength decreases, as in glTF.
+            diffuse_occlusion *= mix(1.0, occlusion, occlusion_strength);
         }
 #endif
 #ifdef SCREEN_SPACE_AMBIENT_OCCLUSION
@@ -706,7 +797,21 @@ pbr_input.material.uv_transform = uv_transform;
 #endif  // MESHLET_MESH_MATERIAL_PASS
             ).rgb;
 
-        pbr_input.N = pbr_functions::apply_normal_mapping(flags, TBN, double_sided, is_front, Nt);
+#ifdef BINDLESS
+        let normal_map_scale =
+                pbr_bindings::material_array[material_indices[slot].material].normal_map_scale;
+#else   // BINDLESS
+        let normal_map_scale = pbr_bindings::material.normal_map_scale;
+#endif  // BINDLESS
+
+        pbr_input.N = pbr_functions::apply_scaled_normal_mapping(
+            flags,
+            TBN,
+            double_sided,
+            is_front,
+            Nt,
+            normal_map_scale,
+        );
 
 #endif  // STANDARD_MATERIAL_NORMAL_MAP
 
@@ -178,6 +178,20 @@ fn apply_normal_mapping(
     double_sided: bool,
     is_front: bool,
     in_Nt: vec3<f32>,
+) -> vec3<f32> {
+    return apply_scaled_normal_mapping(standard_material_flags, TBN, double_sided, is_front, in_Nt, 1.0);
+}
+
+// Like `apply_normal_mapping`, but scales the x and y components of the
+// tangent-space normal by `normal_map_scale`, as `normalTexture.scale` does in
+// glTF.
+fn apply_scaled_normal_mapping(
+    standard_material_flags: u32,
+    TBN: mat3x3<f32>,
+    double_sided: bool,
+    is_front: bool,
+    in_Nt: vec3<f32>,
+    normal_map_scale: f32,
 ) -> vec3<f32> {
     // Unpack the TBN vectors.
     var T = TBN[0];
@@ -197,6 +211,7 @@ fn apply_normal_mapping(
     if (standard_material_flags & pbr_types::STANDARD_MATERIAL_FLAGS_FLIP_NORMAL_MAP_Y) != 0u {
         Nt.y = -Nt.y;
     }
+    Nt = vec3<f32>(Nt.xy * normal_map_scale, Nt.z);
 
     if double_sided && !is_front {
         Nt = -Nt;
@@ -403,6 +418,11 @@ fn apply_pbr_lighting(
     lighting_input.Ta = in.anisotropy_T;
     lighting_input.Ba = in.anisotropy_B;
 #endif  // STANDARD_MATERIAL_ANISOTROPY
+#ifdef STANDARD_MATERIAL_SHEEN
+    lighting_input.sheen_color = in.material.sheen_color;
+    lighting_input.sheen_roughness =
+        lighting::perceptualRoughnessToRoughness(in.material.sheen_roughness);
+#endif  // STANDARD_MATERIAL_SHEEN
 
     // And do the same for transmissive if we need to.
 #ifdef STANDARD_MATERIAL_DIFFUSE_TRANSMISSION
@@ -430,6 +450,10 @@ fn apply_pbr_lighting(
     transmissive_lighting_input.Ta = in.anisotropy_T;
     transmissive_lighting_input.Ba = in.anisotropy_B;
 #endif  // STANDARD_MATERIAL_ANISOTROPY
+#ifdef STANDARD_MATERIAL_SHEEN
+    transmissive_lighting_input.sheen_color = vec3(0.0);
+    transmissive_lighting_input.sheen_roughness = 1.0;
+#endif  // STANDARD_MATERIAL_SHEEN
 #endif  // STANDARD_MATERIAL_DIFFUSE_TRANSMISSION
 
     let view_z = dot(vec4<f32>(
@@ -797,7 +821,7 @@ fn apply_pbr_lighting(
     emissive_light = emissive_light * mix(1.0, view_bindings::view.exposure, emissive.a);
 
 #ifdef STANDARD_MATERIAL_SPECULAR_TRANSMISSION
-    transmitted_light += transmission::specular_transmissive_light(in.world_position, in.frag_coord.xyz, view_z, in.N, in.V, F0, ior, thickness, perceptual_roughness, specular_transmissive_color, specular_transmitted_environment_light).rgb;
+    transmitted_light += transmission::specular_transmissive_light(in.world_position, in.frag_coord.xyz, view_z, in.N, in.V, F0, ior, in.material.dispersion, thickness, perceptual_roughness, specular_transmissive_color, specular_transmitted_environment_light).rgb;
 
     if (in.material.flags & pbr_types::STANDARD_MATERIAL_FLAGS_ATTENUATION_ENABLED_BIT) != 0u {
         // We reuse the `atmospheric_fog()` function here, as it's fundamentally
@@ -105,6 +105,13 @@ struct LightingInput {
     // the tangent direction.
     Ba: vec3<f32>,
 #endif  // STANDARD_MATERIAL_ANISOTROPY
+
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The color of the sheen layer.
+    sheen_color: vec3<f32>,
+    // The roughness of the sheen layer.
+    sheen_roughness: f32,
+#endif  // STANDARD_MATERIAL_SHEEN
 }
 
 // Values derived from the `LightingInput` for both diffuse and specular lights.
@@ -296,6 +303,27 @@ fn V_Kelemen(LdotH: f32) -> f32 {
     return 0.25 / (LdotH * LdotH);
 }
 
+// The "Charlie" sheen distribution function, from Estevez and Kulla 2017,
+// "Production Friendly Microfacet Sheen BRDF":
+// <https://blog.selfshadow.com/publications/s2017-shading-course/imageworks/s2017_pbs_imageworks_sheen.pdf>
+//
+// D_Charlie(h,α) = (2 + 1/α) sin(θ_h)^(1/α) / 2π
+fn D_Charlie(roughness: f32, NdotH: f32) -> f32 {
+    let inv_alpha = 1.0 / roughness;
+    // Clamp to avoid precision problems when using fp16.
+    let sin2h = max(1.0 - NdotH * NdotH, 0.0078125);
+    return (2.0 + inv_alpha) * pow(sin2h, inv_alpha * 0.5) / (2.0 * PI);
+}
+
+// The sheen visibility function, from Neubelt and Pettineo 2013, "Crafting a
+// Next-gen Material Pipeline for The Order: 1886". We use this instead of the
+// more expensive fitted Charlie visibility, as Filament does.
+//
+// https://google.github.io/filament/Filament.html#materialsystem/clothmodel/clothspecularbrdf
+fn V_Neubelt(NdotV: f32, NdotL: f32) -> f32 {
+    return saturate(1.0 / (4.0 * (NdotL + NdotV - NdotL * NdotV)));
+}
+
 // Fresnel function
 // see https://google.github.io/filament/Filament.html#citation-schlick94
 // F_Schlick(v,h,f_0,f_90) = f_0 + (f_90 − f_0) (1 − v⋅h)^5
@@ -418,6 +446,34 @@ fn specular(
     return Fr;
 }
 
+#ifdef STANDARD_MATERIAL_SHEEN
+// Calculates the light reflected by the sheen layer, following the
+// `KHR_materials_sheen` spec:
+// <https://github.com/KhronosGroup/glTF/blob/main/extensions/2.0/Khronos/KHR_materials_sheen/README.md#sheen-brdf>
+fn sheen(
+    input: ptr<function, LightingInput>,
+    derived_input: ptr<function, DerivedLightingInput>,
+) -> vec3<f32> {
+    // Unpack.
+    let NdotV = (*input).layers[LAYER_BASE].NdotV;
+    let NdotL = (*derived_input).NdotL;
+    let NdotH = (*derived_input).NdotH;
+
+    let D = D_Charlie((*input).sheen_roughness, NdotH);
+    let V = V_Neubelt(NdotV, NdotL);
+    return (*input).sheen_color * D * V;
+}
+
+// Returns how much the sheen layer darkens the layers underneath it.
+//
+// This approximates the directional albedo of the sheen layer by its
+// maximum, like three.js does, instead of looking it up in a table.
+fn sheen_albedo_scaling(input: ptr<function, LightingInput>) -> f32 {
+    let sheen_color = (*input).sheen_color;
+    return 1.0 - 0.157 * max(sheen_color.r, max(sheen_color.g, sheen_color.b));
+}
+#endif  // STANDARD_MATERIAL_SHEEN
+
 // Calculates the specular light for the clearcoat layer. Returns Fc, the
 // Fresnel term, in the first channel, and Frc, the specular clearcoat light, in
 // the second channel.
@@ -727,6 +783,14 @@ fn point_light(
         diffuse = diffuse_color * Fd_Burley(input, &derived_input);
     }
 
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The sheen layer darkens the base layer underneath it. Its lobe is
+    // weighted by the same N⋅L as the diffuse lobe, so it's folded into that.
+    let sheen_scaling = sheen_albedo_scaling(input);
+    diffuse = diffuse * sheen_scaling + sheen(input, &derived_input);
+    specular_light *= sheen_scaling;
+#endif  // STANDARD_MATERIAL_SHEEN
+
     // See https://google.github.io/filament/Filament.html#mjx-eqn-pointLightLuminanceEquation
     // Lout = f(v,l) Φ / { 4 π d^2 }⟨n⋅l⟩
     // where
@@ -843,11 +907,18 @@ fn directional_light(
     }
 
 #ifdef STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular_anisotropy(input, &derived_input, L, roughness, 1.0);
+    var specular_light = specular_anisotropy(input, &derived_input, L, roughness, 1.0);
 #else   // STANDARD_MATERIAL_ANISOTROPY
-    let specular_light = specular(input, &derived_input, roughness, 1.0);
+    var specular_light = specular(input, &derived_input, roughness, 1.0);
 #endif  // STANDARD_MATERIAL_ANISOTROPY
 
+#ifdef STANDARD_MATERIAL_SHEEN
+    // The sheen layer darkens the base layer underneath it.
+    let sheen_scaling = sheen_albedo_scaling(input);
+    diffuse = diffuse * sheen_scaling + sheen(input, &derived_input);
+    specular_light *= sheen_scaling;
+#endif  // STANDARD_MATERIAL_SHEEN
+
 #ifdef STANDARD_MATERIAL_CLEARCOAT
     let clearcoat_N = (*input).layers[LAYER_CLEARCOAT].N;
     let clearcoat_strength = (*input).clearcoat_strength;
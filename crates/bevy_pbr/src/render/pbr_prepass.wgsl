@@ -115,12 +115,20 @@ fn fragment(
             ).rgb;
         let TBN = pbr_functions::calculate_tbn_mikktspace(normal, in.world_tangent);
 
-        normal = pbr_functions::apply_normal_mapping(
+#ifdef BINDLESS
+        let normal_map_scale =
+                pbr_bindings::material_array[material_indices[slot].material].normal_map_scale;
+#else   // BINDLESS
+        let normal_map_scale = pbr_bindings::material.normal_map_scale;
+#endif  // BINDLESS
+
+        normal = pbr_functions::apply_scaled_normal_mapping(
             flags,
             TBN,
             double_sided,
             is_front,
             Nt,
+            normal_map_scale,
         );
 
 #endif  // STANDARD_MATERIAL_NORMAL_MAP
@@ -14,18 +14,50 @@
 #import bevy_core_pipeline::tonemapping::approximate_inverse_tone_mapping
 #endif
 
-fn specular_transmissive_light(world_position: vec4<f32>, frag_coord: vec3<f32>, view_z: f32, N: vec3<f32>, V: vec3<f32>, F0: vec3<f32>, ior: f32, thickness: f32, perceptual_roughness: f32, specular_transmissive_color: vec3<f32>, transmitted_environment_light_specular: vec3<f32>) -> vec3<f32> {
+fn specular_transmissive_light(world_position: vec4<f32>, frag_coord: vec3<f32>, view_z: f32, N: vec3<f32>, V: vec3<f32>, F0: vec3<f32>, ior: f32, dispersion: f32, thickness: f32, perceptual_roughness: f32, specular_transmissive_color: vec3<f32>, transmitted_environment_light_specular: vec3<f32>) -> vec3<f32> {
+    // Calculate incidence vector (opposite to view vector)
+    let I = -V;
+
+    let T = refracted_direction(N, I, ior);
+
+    // Fetch background color
+    var background_color = fetch_refracted_background(world_position, frag_coord, view_z, T, thickness, perceptual_roughness);
+    var background_alpha = vec3(background_color.a);
+
+    if dispersion > 0.0 {
+        // Refract red and blue light with a lower and higher index of refraction respectively,
+        // using the spread from `KHR_materials_dispersion`
+        let half_spread = (ior - 1.0) * 0.025 * dispersion;
+        let red = fetch_refracted_background(world_position, frag_coord, view_z, refracted_direction(N, I, ior - half_spread), thickness, perceptual_roughness);
+        let blue = fetch_refracted_background(world_position, frag_coord, view_z, refracted_direction(N, I, ior + half_spread), thickness, perceptual_roughness);
+        background_color = vec4(red.r, background_color.g, blue.b, background_color.a);
+        background_alpha = vec3(red.a, background_color.a, blue.a);
+    }
+
+    // Compensate for exposure, since the background color is coming from an already exposure-adjusted texture
+    background_color = vec4(background_color.rgb / view_bindings::view.exposure, background_color.a);
+
+    // Dot product of the refracted direction with the exit normal (Note: We assume the exit normal is the entry normal but inverted)
+    let MinusNdotT = dot(-N, T);
+
+    // Calculate 1.0 - fresnel factor (how much light is _NOT_ reflected, i.e. how much is transmitted)
+    let F = vec3(1.0) - lighting::fresnel(F0, MinusNdotT);
+
+    // Calculate final color by applying fresnel multiplied specular transmissive color to a mix of background color and transmitted specular environment light
+    return F * specular_transmissive_color * mix(transmitted_environment_light_specular, background_color.rgb, background_alpha);
+}
+
+fn refracted_direction(N: vec3<f32>, I: vec3<f32>, ior: f32) -> vec3<f32> {
     // Calculate the ratio between refraction indexes. Assume air/vacuum for the space outside the mesh
     let eta = 1.0 / ior;
-
-    // Calculate incidence vector (opposite to view vector) and its dot product with the mesh normal
-    let I = -V;
     let NdotI = dot(N, I);
 
     // Calculate refracted direction using Snell's law
     let k = 1.0 - eta * eta * (1.0 - NdotI * NdotI);
-    let T = eta * I - (eta * NdotI + sqrt(k)) * N;
+    return eta * I - (eta * NdotI + sqrt(k)) * N;
+}
 
+fn fetch_refracted_background(world_position: vec4<f32>, frag_coord: vec3<f32>, view_z: f32, T: vec3<f32>, thickness: f32, perceptual_roughness: f32) -> vec4<f32> {
     // Calculate the exit position of the refracted ray, by propagating refracted direction through thickness
     let exit_position = world_position.xyz + T * thickness;
 
@@ -35,26 +67,11 @@ fn specular_transmissive_light(world_position: vec4<f32>, frag_coord: vec3<f32>,
     // Scale / offset position so that coordinate is in right space for sampling transmissive background texture
     let offset_position = (clip_exit_position.xy / clip_exit_position.w) * vec2<f32>(0.5, -0.5) + 0.5;
 
-    // Fetch background color
-    var background_color: vec4<f32>;
     if perceptual_roughness == 0.0 {
         // If the material has zero roughness, we can use a faster approach without the blur
-        background_color = fetch_transmissive_background_non_rough(offset_position, frag_coord);
-    } else {
-        background_color = fetch_transmissive_background(offset_position, frag_coord, view_z, perceptual_roughness);
+        return fetch_transmissive_background_non_rough(offset_position, frag_coord);
     }
-
-    // Compensate for exposure, since the background color is coming from an already exposure-adjusted texture
-    background_color = vec4(background_color.rgb / view_bindings::view.exposure, background_color.a);
-
-    // Dot product of the refracted direction with the exit normal (Note: We assume the exit normal is the entry normal but inverted)
-    let MinusNdotT = dot(-N, T);
-
-    // Calculate 1.0 - fresnel factor (how much light is _NOT_ reflected, i.e. how much is transmitted)
-    let F = vec3(1.0) - lighting::fresnel(F0, MinusNdotT);
-
-    // Calculate final color by applying fresnel multiplied specular transmissive color to a mix of background color and transmitted specular environment light
-    return F * specular_transmissive_color * mix(transmitted_environment_light_specular, background_color.rgb, background_color.a);
+    return fetch_transmissive_background(offset_position, frag_coord, view_z, perceptual_roughness);
 }
 
 fn fetch_transmissive_background_non_rough(offset_position: vec2<f32>, frag_coord: vec3<f32>) -> vec4<f32> {
@@ -1,7 +1,7 @@
 use crate::{
     render_resource::{encase::internal::WriteInto, DynamicUniformBuffer, ShaderType},
     renderer::{RenderDevice, RenderQueue},
-    sync_component::{SyncComponent, SyncComponentPlugin},
+    sync_component::{SyncComponent, SyncComponentFilter, SyncComponentPlugin},
     sync_world::RenderEntity,
     Extract, ExtractSchedule, Render, RenderApp, RenderSystems,
 };
@@ -41,7 +41,10 @@ impl<C: Component> DynamicUniformIndex<C> {
 /// The marker type is only used as a way to bypass the orphan rules. To
 /// implement the trait for a foreign type you can use a local type as the
 /// marker, e.g. the type of the plugin that calls [`ExtractComponentPlugin`].
-pub trait ExtractComponent<Marker = ()>: SyncComponent {
+/// The same marker is used for [`SyncComponent`], so a filter registered with
+/// [`SyncComponentPlugin::<C, Marker>::with_filter`](SyncComponentPlugin::with_filter)
+/// applies to the extraction.
+pub trait ExtractComponent<Marker = ()>: SyncComponent<Marker> {
     /// ECS [`ReadOnlyQueryData`] to fetch the components to extract.
     type QueryData: ReadOnlyQueryData;
     /// Filters the entities with additional constraints.
@@ -174,9 +177,11 @@ impl<C, F> ExtractComponentPlugin<C, F> {
     }
 }
 
-impl<C: ExtractComponent<Marker>, Marker: 'static> Plugin for ExtractComponentPlugin<C, Marker> {
+impl<C: ExtractComponent<Marker>, Marker: Send + Sync + 'static> Plugin
+    for ExtractComponentPlugin<C, Marker>
+{
     fn build(&self, app: &mut App) {
-        app.add_plugins(SyncComponentPlugin::<C>::default());
+        app.add_plugins(SyncComponentPlugin::<C, Marker>::default());
 
         if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
             if self.only_extract_visible {
@@ -192,10 +197,16 @@ impl<C: ExtractComponent<Marker>, Marker: 'static> Plugin for ExtractComponentPl
 fn extract_components<C: ExtractComponent<Marker>, Marker>(
     mut commands: Commands,
     mut previous_len: Local<usize>,
-    query: Extract<Query<(RenderEntity, C::QueryData), C::QueryFilter>>,
+    filter: Extract<Option<Res<SyncComponentFilter<C, Marker>>>>,
+    query: Extract<Query<(RenderEntity, Option<&C>, C::QueryData), C::QueryFilter>>,
 ) {
     let mut values = Vec::with_capacity(*previous_len);
-    for (entity, query_item) in &query {
+    for (entity, sync_component, query_item) in &query {
+        if let (Some(filter), Some(sync_component)) = (&*filter, sync_component)
+            && !filter.should_sync(sync_component)
+        {
+            continue;
+        }
         if let Some(component) = C::extract_component(query_item) {
             values.push((entity, component));
         } else {
@@ -210,10 +221,18 @@ fn extract_components<C: ExtractComponent<Marker>, Marker>(
 fn extract_visible_components<C: ExtractComponent<Marker>, Marker>(
     mut commands: Commands,
     mut previous_len: Local<usize>,
-    query: Extract<Query<(RenderEntity, &ViewVisibility, C::QueryData), C::QueryFilter>>,
+    filter: Extract<Option<Res<SyncComponentFilter<C, Marker>>>>,
+    query: Extract<
+        Query<(RenderEntity, &ViewVisibility, Option<&C>, C::QueryData), C::QueryFilter>,
+    >,
 ) {
     let mut values = Vec::with_capacity(*previous_len);
-    for (entity, view_visibility, query_item) in &query {
+    for (entity, view_visibility, sync_component, query_item) in &query {
+        if let (Some(filter), Some(sync_component)) = (&*filter, sync_component)
+            && !filter.should_sync(sync_component)
+        {
+            continue;
+        }
         if view_visibility.get() {
             if let Some(component) = C::extract_component(query_item) {
                 values.push((entity, component));
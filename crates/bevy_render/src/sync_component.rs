@@ -1,29 +1,173 @@
-use core::marker::PhantomData;
+use core::{any::type_name, marker::PhantomData};
 
-use bevy_app::{App, Plugin};
+use bevy_app::{App, Plugin, PreUpdate};
+use bevy_diagnostic::{Diagnostic, DiagnosticPath, Diagnostics, RegisterDiagnostic};
 use bevy_ecs::{
     bundle::{Bundle, NoBundleEffect},
     component::Component,
+    lifecycle::RemovedComponents,
+    query::{Changed, Without},
+    resource::Resource,
+    system::{Commands, Local, Query, Res, ResMut},
+    world::EntityWorldMut,
 };
 
-use crate::sync_world::{EntityRecord, PendingSyncEntity, SyncToRenderWorld};
+use crate::{
+    sync_world::{
+        EntityRecord, MainEntity, PendingSyncEntity, RenderEntity, SyncToRenderWorld,
+        TemporaryRenderEntity,
+    },
+    Extract, ExtractSchedule, MainWorld, RenderApp,
+};
 
 /// Plugin that registers a component for automatic sync to the render world. See [`SyncWorldPlugin`] for more information.
 ///
 /// This plugin is automatically added by [`ExtractComponentPlugin`], and only needs to be added for manual extraction implementations.
 ///
+/// # Filtering
+///
+/// [`SyncComponentPlugin::with_filter`] restricts syncing to components for which a predicate returns `true`.
+/// When a component stops passing the filter, its [`SyncComponent::Out`] is removed from the render world
+/// just like when the component itself is removed. [`ExtractComponentPlugin`] skips filtered out components,
+/// and manual extraction implementations can read the [`SyncComponentFilter`] resource to do the same.
+/// The filter is evaluated whenever the component changes, so it should only depend on the component's data.
+/// Removing the component always cleans up the render world, even if it was filtered out at that point,
+/// since it may have been changed to fail the filter after the last extraction.
+///
+/// # Adding the plugin more than once
+///
+/// The plugin can be added several times for the same component, in any order, for example manually with a
+/// filter in addition to the one added by [`ExtractComponentPlugin`]. The configurations are merged, but only
+/// one of them may set a filter.
+///
+/// # Additional cleanup
+///
+/// [`SyncComponentPlugin::also_remove`] registers extra bundles to remove from the render world alongside
+/// [`SyncComponent::Out`]. This is useful when several render world components are inserted by unrelated systems
+/// and shouldn't be coupled into a single bundle type.
+///
+/// # Transforming on sync
+///
+/// [`SyncComponentPlugin::with_sync_transform`] inserts the result of [`SyncComponent::sync_transform`]
+/// into the render world every frame, which covers the common case of a component that maps to a single
+/// render world bundle without a separate [`ExtractComponent`] implementation. Since the inserted bundle is
+/// [`SyncComponent::Out`], it is cleaned up on removal exactly like an extracted one.
+///
+/// # Diagnostics
+///
+/// [`SyncComponentPlugin::with_removal_diagnostic`] registers a diagnostic counting how many cleanup records
+/// are queued for the component each frame, which helps tracking down components that are constantly added
+/// and removed. It is disabled by default.
+///
 /// # Implementation details
 ///
 /// It adds [`SyncToRenderWorld`] as a required component to make the [`SyncWorldPlugin`] aware of the component, and
 /// handles cleanup of the component in the render world when it is removed from an entity.
 ///
+/// On removal, one cleanup record is queued for [`SyncComponent::Out`], followed by one for each
+/// [`also_remove`](SyncComponentPlugin::also_remove) bundle in registration order. Records are applied
+/// to the render world in the order they were queued.
+///
+/// [`ExtractComponent`]: crate::extract_component::ExtractComponent
 /// [`ExtractComponentPlugin`]: crate::extract_component::ExtractComponentPlugin
 /// [`SyncWorldPlugin`]: crate::sync_world::SyncWorldPlugin
-pub struct SyncComponentPlugin<C, Marker = ()>(PhantomData<(C, Marker)>);
+pub struct SyncComponentPlugin<C, Marker = ()> {
+    filter: Option<fn(&C) -> bool>,
+    also_remove: Vec<fn(EntityWorldMut<'_>)>,
+    removal_diagnostic: bool,
+    sync_transform: bool,
+    marker: PhantomData<Marker>,
+}
 
 impl<C: SyncComponent<Marker>, Marker> Default for SyncComponentPlugin<C, Marker> {
     fn default() -> Self {
-        Self(PhantomData)
+        Self {
+            filter: None,
+            also_remove: Vec::new(),
+            removal_diagnostic: false,
+            sync_transform: false,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<C: SyncComponent<Marker>, Marker> SyncComponentPlugin<C, Marker> {
+    /// Creates a plugin that only syncs components for which `filter` returns `true`.
+    pub fn with_filter(filter: fn(&C) -> bool) -> Self {
+        Self {
+            filter: Some(filter),
+            also_remove: Vec::new(),
+            removal_diagnostic: false,
+            sync_transform: false,
+            marker: PhantomData,
+        }
+    }
+
+    /// Also removes `B` from the render world when `C` is removed from an entity.
+    ///
+    /// Cleanup for `B` happens after the cleanup of [`SyncComponent::Out`] and of previously registered bundles.
+    pub fn also_remove<B: Bundle<Effect: NoBundleEffect>>(mut self) -> Self {
+        self.also_remove.push(|mut entity| {
+            entity.remove::<B>();
+        });
+        self
+    }
+
+    /// Registers a diagnostic counting the cleanup records queued each frame when `C` is removed.
+    ///
+    /// See [`SyncComponentPlugin::removal_diagnostic_path`].
+    pub fn with_removal_diagnostic(mut self) -> Self {
+        self.removal_diagnostic = true;
+        self
+    }
+
+    /// Inserts the result of [`SyncComponent::sync_transform`] into the render world each frame.
+    ///
+    /// If it returns `None`, the render world is left untouched, so the default implementation makes
+    /// this a no-op. This shouldn't be combined with an [`ExtractComponentPlugin`] for the same
+    /// component, as both would insert the output.
+    ///
+    /// [`ExtractComponentPlugin`]: crate::extract_component::ExtractComponentPlugin
+    pub fn with_sync_transform(mut self) -> Self {
+        self.sync_transform = true;
+        self
+    }
+
+    /// Get the [`DiagnosticPath`] for the removal counter, `sync_component_removals/<TypeName>`.
+    pub fn removal_diagnostic_path() -> DiagnosticPath {
+        DiagnosticPath::from_components(["sync_component_removals", type_name::<C>()])
+    }
+}
+
+/// Resource marking that [`SyncComponentPlugin`] has already set up syncing for `C`.
+#[derive(Resource)]
+struct SyncComponentRegistration<C, Marker> {
+    sync_transform: bool,
+    removal_diagnostic: bool,
+    marker: PhantomData<fn() -> (C, Marker)>,
+}
+
+/// Resource holding the extra removal functions registered with [`SyncComponentPlugin::also_remove`].
+#[derive(Resource)]
+struct SyncComponentAlsoRemove<C, Marker> {
+    removals: Vec<fn(EntityWorldMut<'_>)>,
+    marker: PhantomData<fn() -> (C, Marker)>,
+}
+
+/// Resource holding the filter passed to [`SyncComponentPlugin::with_filter`].
+///
+/// It is only present in the main world if a filter was set.
+#[derive(Resource)]
+pub struct SyncComponentFilter<C, Marker = ()> {
+    filter: fn(&C) -> bool,
+    marker: PhantomData<fn() -> Marker>,
+}
+
+impl<C, Marker> SyncComponentFilter<C, Marker> {
+    /// Returns `true` if `component` should be synced to the render world.
+    #[inline]
+    pub fn should_sync(&self, component: &C) -> bool {
+        (self.filter)(component)
     }
 }
 
@@ -49,24 +193,496 @@ pub trait SyncComponent<Marker = ()>: Component {
     type Out: Bundle<Effect: NoBundleEffect>;
     // TODO: https://github.com/rust-lang/rust/issues/29661
     // type Out: Component = Self;
+
+    /// Transforms the component into its render world representation.
+    ///
+    /// This is only used if [`SyncComponentPlugin::with_sync_transform`] is enabled. Returning `None`
+    /// leaves the render world untouched, which is what the default implementation does, so enabling
+    /// the transform without implementing this never removes an existing [`SyncComponent::Out`].
+    /// Use [`SyncComponentPlugin::with_filter`] to stop syncing a component instead.
+    fn sync_transform(&self) -> Option<Self::Out> {
+        None
+    }
 }
 
 impl<C: SyncComponent<Marker>, Marker: Send + Sync + 'static> Plugin
     for SyncComponentPlugin<C, Marker>
 {
     fn build(&self, app: &mut App) {
-        app.register_required_components::<C, SyncToRenderWorld>();
+        if !app
+            .world()
+            .contains_resource::<SyncComponentRegistration<C, Marker>>()
+        {
+            app.insert_resource(SyncComponentRegistration::<C, Marker> {
+                sync_transform: false,
+                removal_diagnostic: false,
+                marker: PhantomData,
+            });
+            register_sync_component::<C, Marker>(app);
+        }
+
+        if !self.also_remove.is_empty() {
+            app.world_mut()
+                .get_resource_or_insert_with(|| SyncComponentAlsoRemove::<C, Marker> {
+                    removals: Vec::new(),
+                    marker: PhantomData,
+                })
+                .removals
+                .extend(&self.also_remove);
+        }
+
+        if self.removal_diagnostic {
+            let mut registration = app
+                .world_mut()
+                .resource_mut::<SyncComponentRegistration<C, Marker>>();
+            let already_added = core::mem::replace(&mut registration.removal_diagnostic, true);
+            if !already_added {
+                app.register_diagnostic(
+                    Diagnostic::new(Self::removal_diagnostic_path()).with_suffix(" removals"),
+                )
+                .add_systems(PreUpdate, add_removal_measurement::<C, Marker>);
+            }
+        }
+
+        if self.sync_transform {
+            let mut registration = app
+                .world_mut()
+                .resource_mut::<SyncComponentRegistration<C, Marker>>();
+            let already_added = core::mem::replace(&mut registration.sync_transform, true);
+            if !already_added && let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+                render_app.add_systems(ExtractSchedule, sync_transformed_components::<C, Marker>);
+            }
+        }
+
+        if let Some(filter) = self.filter {
+            assert!(
+                !app.world()
+                    .contains_resource::<SyncComponentFilter<C, Marker>>(),
+                "`SyncComponentPlugin<{}>` was added with more than one filter",
+                type_name::<C>()
+            );
+            app.insert_resource(SyncComponentFilter::<C, Marker> {
+                filter,
+                marker: PhantomData,
+            });
+
+            if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+                render_app.add_systems(ExtractSchedule, remove_filtered_components::<C, Marker>);
+            }
+        }
+    }
+
+    fn is_unique(&self) -> bool {
+        false
+    }
+}
 
-        app.world_mut()
-            .register_component_hooks::<C>()
-            .on_remove(|mut world, context| {
-                let mut pending = world.resource_mut::<PendingSyncEntity>();
-                pending.push(EntityRecord::ComponentRemoved(
+/// Registers the required component and the removal hook shared by every [`SyncComponentPlugin`] for `C`.
+fn register_sync_component<C: SyncComponent<Marker>, Marker: Send + Sync + 'static>(app: &mut App) {
+    app.register_required_components::<C, SyncToRenderWorld>();
+
+    app.world_mut()
+        .register_component_hooks::<C>()
+        .on_remove(|mut world, context| {
+            world
+                .resource_mut::<PendingSyncEntity>()
+                .push(EntityRecord::ComponentRemoved(
                     context.entity,
                     |mut entity| {
                         entity.remove::<C::Out>();
                     },
                 ));
-            });
+
+            // The removals are fetched one at a time, since the list can't stay borrowed
+            // while `PendingSyncEntity` is borrowed mutably.
+            let mut index = 0;
+            while let Some(&removal) = world
+                .get_resource::<SyncComponentAlsoRemove<C, Marker>>()
+                .and_then(|also_remove| also_remove.removals.get(index))
+            {
+                world
+                    .resource_mut::<PendingSyncEntity>()
+                    .push(EntityRecord::ComponentRemoved(context.entity, removal));
+                index += 1;
+            }
+        });
+}
+
+/// Removes [`SyncComponent::Out`] from the render world for components that changed
+/// and no longer pass the [`SyncComponentFilter`].
+fn remove_filtered_components<C: SyncComponent<Marker>, Marker: Send + Sync + 'static>(
+    mut commands: Commands,
+    filter: Extract<Res<SyncComponentFilter<C, Marker>>>,
+    also_remove: Extract<Option<Res<SyncComponentAlsoRemove<C, Marker>>>>,
+    components: Extract<Query<(RenderEntity, &C), Changed<C>>>,
+) {
+    for (entity, component) in &components {
+        if !filter.should_sync(component) {
+            let mut entity = commands.entity(entity);
+            entity.remove::<C::Out>();
+            for removal in also_remove
+                .iter()
+                .flat_map(|also_remove| also_remove.removals.iter())
+            {
+                entity.queue(*removal);
+            }
+        }
+    }
+}
+
+/// Inserts the result of [`SyncComponent::sync_transform`] into the render world for every synced component.
+fn sync_transformed_components<C: SyncComponent<Marker>, Marker: Send + Sync + 'static>(
+    mut commands: Commands,
+    mut previous_len: Local<usize>,
+    filter: Extract<Option<Res<SyncComponentFilter<C, Marker>>>>,
+    components: Extract<Query<(RenderEntity, &C)>>,
+) {
+    let mut values = Vec::with_capacity(*previous_len);
+    for (entity, component) in &components {
+        if let Some(filter) = &*filter
+            && !filter.should_sync(component)
+        {
+            continue;
+        }
+        if let Some(out) = component.sync_transform() {
+            values.push((entity, out));
+        }
+    }
+    *previous_len = values.len();
+    commands.try_insert_batch(values);
+}
+
+/// Measures the cleanup records queued for `C` since the last run: one for [`SyncComponent::Out`]
+/// and one per [`also_remove`](SyncComponentPlugin::also_remove) bundle for each removal.
+fn add_removal_measurement<C: SyncComponent<Marker>, Marker: Send + Sync + 'static>(
+    mut diagnostics: Diagnostics,
+    mut removed: RemovedComponents<C>,
+    also_remove: Option<Res<SyncComponentAlsoRemove<C, Marker>>>,
+) {
+    let records_per_removal = 1 + also_remove.map_or(0, |also_remove| also_remove.removals.len());
+    let removals = removed.read().count() * records_per_removal;
+    diagnostics.add_measurement(
+        &SyncComponentPlugin::<C, Marker>::removal_diagnostic_path(),
+        || removals as f64,
+    );
+}
+
+/// Plugin that copies a component from the render world back to the corresponding main world entity.
+///
+/// This is the counterpart of [`SyncComponentPlugin`] for data produced by the renderer, such as
+/// values computed from a GPU readback. Every frame during [`ExtractSchedule`], components of type `C`
+/// that changed in the render world are cloned and inserted on the [`MainEntity`] of their render entity.
+///
+/// # Latency
+///
+/// The copy happens during extraction, before the render world update of the current frame. The main
+/// world therefore observes the value written by the previous render world update, which means it is
+/// always at least one frame behind the render world.
+///
+/// # Entities
+///
+/// Only synced render entities are copied back. Entities that exist only in the render world, either
+/// because they have no [`MainEntity`] or because they are marked as [`TemporaryRenderEntity`], are ignored,
+/// as are render entities whose main world entity has been despawned in the meantime.
+///
+/// Removing `C` from a render entity also removes it from the corresponding main world entity.
+pub struct ReverseSyncComponentPlugin<C>(PhantomData<fn() -> C>);
+
+impl<C> Default for ReverseSyncComponentPlugin<C> {
+    fn default() -> Self {
+        Self(PhantomData)
+    }
+}
+
+impl<C: Component + Clone> Plugin for ReverseSyncComponentPlugin<C> {
+    fn build(&self, app: &mut App) {
+        if let Some(render_app) = app.get_sub_app_mut(RenderApp) {
+            render_app.add_systems(ExtractSchedule, reverse_sync_components::<C>);
+        }
+    }
+}
+
+/// Copies changed render world components of type `C` to the corresponding main world entities,
+/// and removes them from the main world when they are removed from the render world.
+fn reverse_sync_components<C: Component + Clone>(
+    mut main_world: ResMut<MainWorld>,
+    mut removed: RemovedComponents<C>,
+    without_component: Query<&MainEntity, (Without<C>, Without<TemporaryRenderEntity>)>,
+    components: Query<(&MainEntity, &C), (Changed<C>, Without<TemporaryRenderEntity>)>,
+) {
+    for main_entity in without_component.iter_many(removed.read()) {
+        if let Ok(mut entity) = main_world.get_entity_mut(main_entity.id()) {
+            entity.remove::<C>();
+        }
+    }
+    for (main_entity, component) in &components {
+        if let Ok(mut entity) = main_world.get_entity_mut(main_entity.id()) {
+            entity.insert(component.clone());
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use bevy_app::App;
+    use bevy_diagnostic::DiagnosticsStore;
+    use bevy_ecs::{prelude::*, query::QueryItem, schedule::ScheduleLabel};
+
+    use super::{ReverseSyncComponentPlugin, SyncComponent, SyncComponentPlugin};
+    use crate::{
+        extract_component::{ExtractComponent, ExtractComponentPlugin},
+        extract_plugin::ExtractPlugin,
+        sync_world::{RenderEntity, SyncToRenderWorld},
+        Render, RenderApp,
+    };
+
+    #[derive(Component, Clone, ExtractComponent)]
+    struct Filtered(bool);
+
+    struct FilterMarker;
+
+    #[derive(Component, Clone)]
+    struct MarkedFiltered(bool);
+
+    impl SyncComponent<FilterMarker> for MarkedFiltered {
+        type Out = Self;
+    }
+
+    impl ExtractComponent<FilterMarker> for MarkedFiltered {
+        type QueryData = &'static Self;
+        type QueryFilter = ();
+
+        fn extract_component(item: QueryItem<'_, '_, Self::QueryData>) -> Option<Self> {
+            Some(item.clone())
+        }
+    }
+
+    #[derive(Component, Clone, ExtractComponent)]
+    struct WithExtra;
+
+    #[derive(Component)]
+    struct RenderExtraA;
+
+    #[derive(Component)]
+    struct RenderExtraB;
+
+    #[derive(Component, Clone, ExtractComponent)]
+    struct Churn;
+
+    #[derive(Component)]
+    struct Scaled(f32);
+
+    #[derive(Component, Debug, PartialEq)]
+    struct RenderScaled(f32);
+
+    #[derive(Component, Clone, Debug, PartialEq)]
+    struct Readback(u32);
+
+    impl SyncComponent for Scaled {
+        type Out = RenderScaled;
+
+        fn sync_transform(&self) -> Option<RenderScaled> {
+            Some(RenderScaled(self.0 * 2.0))
+        }
+    }
+
+    fn test_app() -> App {
+        let mut app = App::new();
+        app.add_plugins(ExtractPlugin::default());
+        app.get_sub_app_mut(RenderApp).unwrap().update_schedule = Some(Render.intern());
+        app
+    }
+
+    fn filtered_app() -> App {
+        let mut app = test_app();
+        app.add_plugins((
+            ExtractComponentPlugin::<Filtered>::default(),
+            SyncComponentPlugin::<Filtered>::with_filter(|filtered| filtered.0),
+        ));
+        app
+    }
+
+    fn render_get<T: Component>(app: &App, entity: Entity) -> Option<&T> {
+        let render_entity = app.world().get::<RenderEntity>(entity)?.id();
+        app.get_sub_app(RenderApp)?.world().get::<T>(render_entity)
+    }
+
+    #[test]
+    fn filter_pass_to_fail() {
+        let mut app = filtered_app();
+        let entity = app.world_mut().spawn(Filtered(true)).id();
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_some());
+
+        app.world_mut().get_mut::<Filtered>(entity).unwrap().0 = false;
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_none());
+    }
+
+    #[test]
+    fn filter_fail_to_pass() {
+        let mut app = filtered_app();
+        let entity = app.world_mut().spawn(Filtered(false)).id();
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_none());
+
+        app.world_mut().get_mut::<Filtered>(entity).unwrap().0 = true;
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_some());
+    }
+
+    #[test]
+    fn filter_fail_and_remove_in_same_frame() {
+        let mut app = filtered_app();
+        let entity = app.world_mut().spawn(Filtered(true)).id();
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_some());
+
+        let mut main_entity = app.world_mut().entity_mut(entity);
+        main_entity.get_mut::<Filtered>().unwrap().0 = false;
+        main_entity.remove::<Filtered>();
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_none());
+    }
+
+    #[test]
+    fn filter_added_before_extract_component_plugin() {
+        let mut app = test_app();
+        app.add_plugins((
+            SyncComponentPlugin::<Filtered>::with_filter(|filtered| filtered.0),
+            ExtractComponentPlugin::<Filtered>::default(),
+        ));
+        let entity = app.world_mut().spawn(Filtered(false)).id();
+        app.update();
+        assert!(render_get::<Filtered>(&app, entity).is_none());
+    }
+
+    #[test]
+    fn filter_with_marker() {
+        let mut app = test_app();
+        app.add_plugins((
+            ExtractComponentPlugin::<MarkedFiltered, FilterMarker>::default(),
+            SyncComponentPlugin::<MarkedFiltered, FilterMarker>::with_filter(|filtered| filtered.0),
+        ));
+        let passing = app.world_mut().spawn(MarkedFiltered(true)).id();
+        let failing = app.world_mut().spawn(MarkedFiltered(false)).id();
+        app.update();
+        assert!(render_get::<MarkedFiltered>(&app, passing).is_some());
+        assert!(render_get::<MarkedFiltered>(&app, failing).is_none());
+    }
+
+    #[test]
+    fn also_remove_bundles_are_removed() {
+        let mut app = test_app();
+        app.add_plugins((
+            ExtractComponentPlugin::<WithExtra>::default(),
+            SyncComponentPlugin::<WithExtra>::default()
+                .also_remove::<RenderExtraA>()
+                .also_remove::<RenderExtraB>(),
+        ));
+        let entity = app.world_mut().spawn(WithExtra).id();
+        app.update();
+
+        let render_entity = app.world().get::<RenderEntity>(entity).unwrap().id();
+        app.get_sub_app_mut(RenderApp)
+            .unwrap()
+            .world_mut()
+            .entity_mut(render_entity)
+            .insert((RenderExtraA, RenderExtraB));
+
+        app.world_mut().entity_mut(entity).remove::<WithExtra>();
+        app.update();
+        assert!(render_get::<WithExtra>(&app, entity).is_none());
+        assert!(render_get::<RenderExtraA>(&app, entity).is_none());
+        assert!(render_get::<RenderExtraB>(&app, entity).is_none());
+    }
+
+    #[test]
+    fn removal_diagnostic_counts_cleanup_records() {
+        let mut app = test_app();
+        app.add_plugins((
+            ExtractComponentPlugin::<Churn>::default(),
+            SyncComponentPlugin::<Churn>::default()
+                .also_remove::<RenderExtraA>()
+                .with_removal_diagnostic(),
+        ));
+        let entities = [
+            app.world_mut().spawn(Churn).id(),
+            app.world_mut().spawn(Churn).id(),
+        ];
+        app.update();
+        assert!(render_get::<Churn>(&app, entities[0]).is_some());
+
+        for entity in entities {
+            app.world_mut().entity_mut(entity).remove::<Churn>();
+        }
+        app.update();
+        assert!(render_get::<Churn>(&app, entities[0]).is_none());
+        assert!(render_get::<Churn>(&app, entities[1]).is_none());
+
+        let path = SyncComponentPlugin::<Churn>::removal_diagnostic_path();
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        // One record for `Churn` itself and one for `RenderExtraA`, per entity.
+        assert_eq!(diagnostics.get(&path).unwrap().value(), Some(4.0));
+
+        app.update();
+        let diagnostics = app.world().resource::<DiagnosticsStore>();
+        assert_eq!(diagnostics.get(&path).unwrap().value(), Some(0.0));
+    }
+
+    #[test]
+    fn sync_transform_output_survives_unchanged_frames() {
+        let mut app = test_app();
+        app.add_plugins(SyncComponentPlugin::<Scaled>::default().with_sync_transform());
+        let entity = app.world_mut().spawn(Scaled(1.0)).id();
+
+        for _ in 0..3 {
+            app.update();
+            assert_eq!(
+                render_get::<RenderScaled>(&app, entity),
+                Some(&RenderScaled(2.0))
+            );
+        }
+
+        app.world_mut().get_mut::<Scaled>(entity).unwrap().0 = 2.0;
+        app.update();
+        assert_eq!(
+            render_get::<RenderScaled>(&app, entity),
+            Some(&RenderScaled(4.0))
+        );
+
+        app.world_mut().entity_mut(entity).remove::<Scaled>();
+        app.update();
+        assert_eq!(render_get::<RenderScaled>(&app, entity), None);
+    }
+
+    #[test]
+    fn reverse_sync_insert_update_remove() {
+        let mut app = test_app();
+        app.add_plugins(ReverseSyncComponentPlugin::<Readback>::default());
+        let entity = app.world_mut().spawn(SyncToRenderWorld).id();
+        app.update();
+
+        let render_entity = app.world().get::<RenderEntity>(entity).unwrap().id();
+        let set_readback = |app: &mut App, readback: Option<Readback>| {
+            let render_world = app.get_sub_app_mut(RenderApp).unwrap().world_mut();
+            let mut render_entity = render_world.entity_mut(render_entity);
+            match readback {
+                Some(readback) => render_entity.insert(readback),
+                None => render_entity.remove::<Readback>(),
+            };
+        };
+
+        set_readback(&mut app, Some(Readback(1)));
+        app.update();
+        assert_eq!(app.world().get::<Readback>(entity), Some(&Readback(1)));
+
+        set_readback(&mut app, Some(Readback(2)));
+        app.update();
+        assert_eq!(app.world().get::<Readback>(entity), Some(&Readback(2)));
+
+        set_readback(&mut app, None);
+        app.update();
+        assert_eq!(app.world().get::<Readback>(entity), None);
     }
 }
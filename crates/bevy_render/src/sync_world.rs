@@ -206,6 +206,9 @@ pub(crate) enum EntityRecord {
 }
 
 // Entity Record in MainWorld pending to Sync
+//
+// Records are applied in push order. When a synced component is removed, `SyncComponentPlugin` pushes
+// the removal of `SyncComponent::Out` first, then one record per `also_remove` bundle in registration order.
 #[derive(Resource, Default, Deref, DerefMut)]
 pub(crate) struct PendingSyncEntity {
     records: Vec<EntityRecord>,
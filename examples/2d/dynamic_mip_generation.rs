@@ -41,8 +41,8 @@ use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
 use crate::widgets::{
-    RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender, BUTTON_BORDER,
-    BUTTON_BORDER_COLOR, BUTTON_BORDER_RADIUS_SIZE, BUTTON_PADDING,
+    WidgetClickEvent, WidgetClickSender, BUTTON_BORDER, BUTTON_BORDER_COLOR,
+    BUTTON_BORDER_RADIUS_SIZE, BUTTON_PADDING,
 };
 
 #[path = "../helpers/widgets.rs"]
@@ -94,7 +94,7 @@ impl Default for AppStatus {
 }
 
 /// Identifies one of the settings that can be changed by the user.
-#[derive(Clone)]
+#[derive(Clone, PartialEq)]
 enum AppSetting {
     /// Regenerates the top mipmap level.
     ///
@@ -227,7 +227,7 @@ fn main() {
         Update,
         (
             widgets::handle_ui_interactions::<AppSetting>,
-            update_radio_buttons,
+            widgets::update_radio_groups::<AppSetting>,
         )
             .chain(),
     )
@@ -349,7 +349,7 @@ fn spawn_ui(commands: &mut Commands) {
             ),
             // Spawn the "Mip Generation" switch that allows the user to toggle
             // mip generation on and off.
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Mip Generation",
                 &[
                     (
@@ -360,11 +360,12 @@ fn spawn_ui(commands: &mut Commands) {
                         AppSetting::EnableMipGeneration(EnableMipGeneration::Off),
                         "Off"
                     ),
-                ]
+                ],
+                AppSetting::EnableMipGeneration(EnableMipGeneration::On),
             ),
             // Spawn the "Image Width" control that allows the user to set the
             // width of the image.
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Image Width",
                 &[
                     (AppSetting::ImageWidth(ImageSize::Size240), "240"),
@@ -372,11 +373,12 @@ fn spawn_ui(commands: &mut Commands) {
                     (AppSetting::ImageWidth(ImageSize::Size640), "640"),
                     (AppSetting::ImageWidth(ImageSize::Size1080), "1080"),
                     (AppSetting::ImageWidth(ImageSize::Size1920), "1920"),
-                ]
+                ],
+                AppSetting::ImageWidth(ImageSize::Size640),
             ),
             // Spawn the "Image Height" control that allows the user to set the
             // height of the image.
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Image Height",
                 &[
                     (AppSetting::ImageHeight(ImageSize::Size240), "240"),
@@ -384,7 +386,8 @@ fn spawn_ui(commands: &mut Commands) {
                     (AppSetting::ImageHeight(ImageSize::Size640), "640"),
                     (AppSetting::ImageHeight(ImageSize::Size1080), "1080"),
                     (AppSetting::ImageHeight(ImageSize::Size1920), "1920"),
-                ]
+                ],
+                AppSetting::ImageHeight(ImageSize::Size480),
             ),
         ],
     ));
@@ -464,40 +467,6 @@ fn extract_mipmap_source_image(
     }
 }
 
-/// Updates the widgets at the bottom of the screen to reflect the settings that
-/// the user has chosen.
-fn update_radio_buttons(
-    mut widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<AppSetting>,
-        ),
-        Or<(With<RadioButton>, With<RadioButtonText>)>,
-    >,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, image, has_text, sender) in widgets.iter_mut() {
-        let selected = match **sender {
-            AppSetting::RegenerateTopMipLevel => continue,
-            AppSetting::EnableMipGeneration(enable_mip_generation) => {
-                enable_mip_generation == app_status.enable_mip_generation
-            }
-            AppSetting::ImageWidth(image_width) => image_width == app_status.image_width,
-            AppSetting::ImageHeight(image_height) => image_height == app_status.image_height,
-        };
-
-        if let Some(mut bg_color) = image {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 /// Handles a request from the user to change application settings via the UI.
 ///
 /// This also handles clicks on the "Regenerate Top Mip Level" button.
@@ -14,7 +14,7 @@ use bevy::{
 use rand::{Rng, SeedableRng};
 use rand_chacha::ChaCha8Rng;
 
-use crate::widgets::{RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 
 #[path = "../helpers/widgets.rs"]
 mod widgets;
@@ -153,7 +153,8 @@ fn main() {
             Update,
             (
                 widgets::handle_ui_interactions::<AppSetting>,
-                update_radio_buttons,
+                widgets::update_radio_groups::<AppSetting>
+                    .after(widgets::handle_ui_interactions::<AppSetting>),
             ),
         )
         .add_systems(
@@ -249,12 +250,13 @@ fn spawn_camera(commands: &mut Commands) {
 fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Emissive Decals",
             &[
                 (AppSetting::EmissiveDecals(true), "On"),
                 (AppSetting::EmissiveDecals(false), "Off"),
             ],
+            AppSetting::EmissiveDecals(false),
         ),],
     ));
 }
@@ -389,40 +391,6 @@ fn animate_decals(
     }
 }
 
-/// Updates the appearance of the radio buttons to reflect the current
-/// application status.
-fn update_radio_buttons(
-    mut widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<AppSetting>,
-        ),
-        Or<(With<RadioButton>, With<RadioButtonText>)>,
-    >,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, image, has_text, sender) in widgets.iter_mut() {
-        // We only have one setting in this particular application.
-        let selected = match **sender {
-            AppSetting::EmissiveDecals(emissive_decals) => {
-                emissive_decals == app_status.emissive_decals
-            }
-        };
-
-        if let Some(mut bg_color) = image {
-            // Update the colors of the button itself.
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            // Update the colors of the button text.
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 /// Handles the user's clicks on the radio button that determines whether the
 /// newly-spawned decals have an emissive map.
 fn handle_emission_type_change(
@@ -18,8 +18,7 @@ use bevy::{
 };
 use ops::{acos, cos, sin};
 use widgets::{
-    WidgetClickEvent, WidgetClickSender, BUTTON_BORDER, BUTTON_BORDER_COLOR,
-    BUTTON_BORDER_RADIUS_SIZE, BUTTON_PADDING,
+    WidgetClickEvent, BUTTON_BORDER, BUTTON_BORDER_COLOR, BUTTON_BORDER_RADIUS_SIZE, BUTTON_PADDING,
 };
 
 #[path = "../helpers/widgets.rs"]
@@ -138,7 +137,10 @@ fn main() {
         .add_systems(Update, widgets::handle_ui_interactions::<Selection>)
         .add_systems(
             Update,
-            (handle_selection_change, update_radio_buttons)
+            (
+                handle_selection_change,
+                widgets::update_radio_groups::<Selection>,
+            )
                 .after(widgets::handle_ui_interactions::<Selection>),
         )
         .add_systems(Update, process_move_input)
@@ -247,13 +249,14 @@ fn spawn_buttons(commands: &mut Commands) {
     // control.
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Drag to Move",
             &[
                 (Selection::Camera, "Camera"),
                 (Selection::DecalA, "Decal A"),
                 (Selection::DecalB, "Decal B"),
             ],
+            Selection::default(),
         )],
     ));
 
@@ -350,28 +353,6 @@ fn rotate_cube(mut meshes: Query<&mut Transform, With<Mesh3d>>) {
     }
 }
 
-/// Updates the state of the radio buttons when the user clicks on one.
-fn update_radio_buttons(
-    mut widgets: Query<(
-        Entity,
-        Option<&mut BackgroundColor>,
-        Has<Text>,
-        &WidgetClickSender<Selection>,
-    )>,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, maybe_bg_color, has_text, sender) in &mut widgets {
-        let selected = app_status.selection == **sender;
-        if let Some(mut bg_color) = maybe_bg_color {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 /// Changes the selection when the user clicks a radio button.
 fn handle_selection_change(
     mut events: MessageReader<WidgetClickEvent<Selection>>,
@@ -1,6 +1,6 @@
 //! Demonstrates contact shadows, also known as screen-space shadows.
 
-use crate::widgets::{RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 use bevy::anti_alias::taa::TemporalAntiAliasing;
 use bevy::core_pipeline::tonemapping::Tonemapping;
 use bevy::light::Skybox;
@@ -65,11 +65,7 @@ const LIGHT_ROTATION_SPEED: f32 = 0.002;
 
 #[derive(Resource, Default)]
 struct AppStatus {
-    contact_shadows: ContactShadowState,
-    shadow_maps: ShadowMaps,
     light_rotation: LightRotation,
-    light_type: LightType,
-    receive_shadows: ReceiveShadows,
 }
 
 #[derive(Component)]
@@ -99,7 +95,8 @@ fn main() {
             Update,
             (
                 widgets::handle_ui_interactions::<ExampleSetting>,
-                update_radio_buttons.after(widgets::handle_ui_interactions::<ExampleSetting>),
+                widgets::update_radio_groups::<ExampleSetting>
+                    .after(widgets::handle_ui_interactions::<ExampleSetting>),
                 handle_setting_change.after(widgets::handle_ui_interactions::<ExampleSetting>),
             ),
         )
@@ -276,7 +273,7 @@ fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
         children![
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Contact Shadows",
                 &[
                     (
@@ -288,15 +285,17 @@ fn spawn_buttons(commands: &mut Commands) {
                         "Off"
                     ),
                 ],
+                ExampleSetting::ContactShadows(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Shadow Maps",
                 &[
                     (ExampleSetting::ShadowMaps(ShadowMaps::Enabled), "On"),
                     (ExampleSetting::ShadowMaps(ShadowMaps::Disabled), "Off"),
                 ],
+                ExampleSetting::ShadowMaps(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Light Rotation",
                 &[
                     (ExampleSetting::LightRotation(LightRotation::Rotating), "On"),
@@ -305,8 +304,9 @@ fn spawn_buttons(commands: &mut Commands) {
                         "Off"
                     ),
                 ],
+                ExampleSetting::LightRotation(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Light Type",
                 &[
                     (
@@ -316,8 +316,9 @@ fn spawn_buttons(commands: &mut Commands) {
                     (ExampleSetting::LightType(LightType::Point), "Point"),
                     (ExampleSetting::LightType(LightType::Spot), "Spot"),
                 ],
+                ExampleSetting::LightType(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Receive Shadows",
                 &[
                     (
@@ -329,42 +330,12 @@ fn spawn_buttons(commands: &mut Commands) {
                         "Off"
                     ),
                 ],
+                ExampleSetting::ReceiveShadows(default()),
             ),
         ],
     ));
 }
 
-fn update_radio_buttons(
-    mut widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<ExampleSetting>,
-        ),
-        Or<(With<RadioButton>, With<RadioButtonText>)>,
-    >,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, background_color, has_text, sender) in widgets.iter_mut() {
-        let selected = match **sender {
-            ExampleSetting::ContactShadows(value) => value == app_status.contact_shadows,
-            ExampleSetting::ShadowMaps(value) => value == app_status.shadow_maps,
-            ExampleSetting::LightRotation(value) => value == app_status.light_rotation,
-            ExampleSetting::LightType(value) => value == app_status.light_type,
-            ExampleSetting::ReceiveShadows(value) => value == app_status.receive_shadows,
-        };
-
-        if let Some(mut background_color) = background_color {
-            widgets::update_ui_radio_button(&mut background_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 fn handle_setting_change(
     mut lights: Query<
         (
@@ -383,7 +354,6 @@ fn handle_setting_change(
     for event in events.read() {
         match **event {
             ExampleSetting::ContactShadows(value) => {
-                app_status.contact_shadows = value;
                 for (_, maybe_directional_light, maybe_point_light, maybe_spot_light) in
                     lights.iter_mut()
                 {
@@ -400,7 +370,6 @@ fn handle_setting_change(
                 }
             }
             ExampleSetting::ShadowMaps(value) => {
-                app_status.shadow_maps = value;
                 for (_, maybe_directional_light, maybe_point_light, maybe_spot_light) in
                     lights.iter_mut()
                 {
@@ -419,7 +388,6 @@ fn handle_setting_change(
                 app_status.light_rotation = value;
             }
             ExampleSetting::LightType(value) => {
-                app_status.light_type = value;
                 for (
                     mut visibility,
                     maybe_directional_light,
@@ -440,7 +408,6 @@ fn handle_setting_change(
                 }
             }
             ExampleSetting::ReceiveShadows(value) => {
-                app_status.receive_shadows = value;
                 for entity in ground_plane.iter_mut() {
                     match value {
                         ReceiveShadows::Enabled => {
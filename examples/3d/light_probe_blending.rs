@@ -8,16 +8,18 @@ use std::f32::consts::{FRAC_PI_4, PI};
 
 use bevy::{
     camera::Hdr,
-    camera_controller::free_camera::{self, FreeCamera, FreeCameraPlugin},
-    color::palettes::css::{CORNFLOWER_BLUE, CRIMSON, TAN, WHITE},
-    input::mouse::{AccumulatedMouseMotion, AccumulatedMouseScroll},
-    light::ParallaxCorrection,
-    math::ops::{atan2, cos, sin},
+    camera_controller::{
+        free_camera::{self, FreeCamera, FreeCameraPlugin},
+        orbit_camera::{self, OrbitCamera, OrbitCameraPlugin},
+        transition::{self, CameraTransition, CameraTransitionPlugin},
+    },
+    color::palettes::css::{SILVER, WHITE},
+    light::{update_light_probe_influences, LightProbeInfluence, ParallaxCorrection},
     prelude::*,
     window::{CursorGrabMode, CursorOptions},
 };
 
-use crate::widgets::{WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 
 #[path = "../helpers/widgets.rs"]
 mod widgets;
@@ -28,8 +30,6 @@ struct AppStatus {
     /// Whether the gizmos that show the boundaries of the light probe regions
     /// are to be shown.
     gizmos_enabled: GizmosEnabled,
-    /// Which object to show: either a reflective sphere or a reflective prism.
-    object_to_show: ObjectToShow,
     /// Whether to use an orbital pan/zoom camera or a free camera.
     camera_mode: CameraMode,
 }
@@ -80,6 +80,11 @@ struct ReflectivePrism;
 #[derive(Clone, Copy, Component, Debug)]
 struct HelpText;
 
+/// A marker component for the text showing the light probe blend weights at
+/// the sphere's position.
+#[derive(Clone, Copy, Component, Debug)]
+struct InfluenceText;
+
 /// The speed at which the sphere moves, as a ratio of the total distance it
 /// travels to seconds.
 ///
@@ -100,48 +105,17 @@ const LIGHT_PROBE_SIDE_LENGTH: f32 = 15.0;
 /// of the side length of the probe.
 const LIGHT_PROBE_FALLOFF: f32 = 0.5;
 
-/// The side length of the simulated reflected area for each light probe,
-/// specified as a half-extent in light probe space.
+/// The side length of the simulated reflected area for each light probe, in
+/// meters.
 ///
-/// We want this side length, in world space, to be half of the world-space room
-/// side length. Since the light probe is scaled by `LIGHT_PROBE_SIDE_LENGTH`,
-/// we divide the room side length by the light probe side length to get this
-/// value, and multiply by 0.5 to convert from a full extent to a half-extent.
-/// That way, when Bevy applies the `LIGHT_PROBE_SIDE_LENGTH` scale, the light
-/// probe side length factor cancels, and we're left with a parallax correction
-/// side length of `ROOM_SIDE_LENGTH` in world space.
+/// We want the parallax correction box to match the room rather than the
+/// larger light probe, so we use the room side length here and let
+/// [`ParallaxCorrection::from_world_size`] convert it to light probe space.
 ///
-/// A small epsilon value of 0.01 is added in order to ensure that the light
+/// A small epsilon value of 0.3 is added in order to ensure that the light
 /// probe parallax bounds encompass the entire room. Otherwise, unsightly
 /// Z-fighting can occur on the room walls.
-const LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH: f32 =
-    ROOM_SIDE_LENGTH / LIGHT_PROBE_SIDE_LENGTH * 0.5 + 0.01;
-
-/// The number of radians of inclination (pitch) that one pixel of mouse
-/// movement corresponds to.
-const CAMERA_ORBIT_SPEED_INCLINATION: f32 = 0.003;
-
-/// The number of radians of azumith (yaw) that one pixel of mouse movement
-/// corresponds to.
-const CAMERA_ORBIT_SPEED_AZIMUTH: f32 = 0.004;
-
-/// The number of meters that one line of mouse scroll corresponds to.
-const CAMERA_ZOOM_SPEED: f32 = 0.15;
-
-/// Information about the orbital pan/zoom camera.
-///
-/// These are in [spherical coordinates].
-///
-/// [spherical coordinates]: https://en.wikipedia.org/wiki/Spherical_coordinate_system
-#[derive(Component)]
-struct OrbitCamera {
-    /// The distance between the camera and the sphere, in meters.
-    radius: f32,
-    /// The camera latitude in radians, relative to the sphere.
-    inclination: f32,
-    /// The camera longitude in radians, relative to the sphere.
-    azimuth: f32,
-}
+const LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH: f32 = ROOM_SIDE_LENGTH + 0.3;
 
 /// The brightness of the light probe.
 const LIGHT_PROBE_INTENSITY: f32 = 500.0;
@@ -156,13 +130,26 @@ fn main() {
             }),
             ..default()
         }))
-        .add_plugins(FreeCameraPlugin)
+        .add_plugins((
+            FreeCameraPlugin,
+            OrbitCameraPlugin,
+            CameraTransitionPlugin::<OrbitCamera>::default(),
+        ))
         .init_resource::<AppStatus>()
         .add_message::<WidgetClickEvent<GizmosEnabled>>()
         .add_message::<WidgetClickEvent<ObjectToShow>>()
         .add_message::<WidgetClickEvent<CameraMode>>()
         .add_systems(Startup, setup)
-        .add_systems(Update, (move_sphere, orbit_camera).chain())
+        // Move the focus before the orbit camera controller runs, so the camera doesn't lag
+        // behind the sphere.
+        .add_systems(
+            RunFixedMainLoop,
+            (move_sphere, update_orbit_camera_focus)
+                .chain()
+                .in_set(RunFixedMainLoopSystems::BeforeFixedMainLoop)
+                .before(orbit_camera::run_orbitcamera_controller)
+                .before(transition::run_camera_transition::<OrbitCamera>),
+        )
         .add_systems(
             Update,
             (
@@ -190,12 +177,20 @@ fn main() {
         )
         .add_systems(
             Update,
-            update_radio_buttons
-                .after(widgets::handle_ui_interactions::<GizmosEnabled>)
-                .after(widgets::handle_ui_interactions::<ObjectToShow>)
-                .after(widgets::handle_ui_interactions::<CameraMode>),
+            (
+                widgets::update_radio_groups::<GizmosEnabled>
+                    .after(widgets::handle_ui_interactions::<GizmosEnabled>),
+                widgets::update_radio_groups::<ObjectToShow>
+                    .after(widgets::handle_ui_interactions::<ObjectToShow>),
+                widgets::update_radio_groups::<CameraMode>
+                    .after(widgets::handle_ui_interactions::<CameraMode>),
+            ),
         )
         .add_systems(Update, draw_gizmos)
+        .add_systems(
+            PostUpdate,
+            update_influence_text.after(update_light_probe_influences),
+        )
         .run();
 }
 
@@ -218,6 +213,7 @@ fn setup(
     spawn_light_probes(&mut commands, &asset_server);
     spawn_buttons(&mut commands);
     spawn_help_text(&mut commands);
+    spawn_influence_text(&mut commands);
 }
 
 /// Adjusts the gizmo settings so that the gizmos appear on top of all other
@@ -251,8 +247,9 @@ fn spawn_camera(commands: &mut Commands) {
         Hdr,
         OrbitCamera {
             radius: 3.0,
-            inclination: 7.0 * FRAC_PI_4,
-            azimuth: FRAC_PI_4,
+            inclination: FRAC_PI_4,
+            azimuth: 5.0 * FRAC_PI_4,
+            ..default()
         },
     ));
 }
@@ -279,6 +276,9 @@ fn spawn_reflective_sphere(
         MeshMaterial3d(material),
         Transform::IDENTITY,
         ReflectiveSphere,
+        // Have Bevy compute the blend weights of the light probes at the
+        // sphere's position, so that we can display them.
+        LightProbeInfluence::default(),
     ));
 }
 
@@ -317,8 +317,10 @@ fn spawn_reflective_prism(
 fn spawn_light_probes(commands: &mut Commands, asset_server: &AssetServer) {
     // Spawn the first room's light probe.
     commands.spawn((
+        Name::new("Room 1"),
         LightProbe {
             falloff: Vec3::splat(LIGHT_PROBE_FALLOFF),
+            ..default()
         },
         EnvironmentMapLight {
             diffuse_map: asset_server.load(get_web_asset_url("diffuse_room1.ktx2")),
@@ -328,13 +330,18 @@ fn spawn_light_probes(commands: &mut Commands, asset_server: &AssetServer) {
         },
         Transform::from_scale(vec3(1.0, -1.0, 1.0) * LIGHT_PROBE_SIDE_LENGTH)
             .with_rotation(Quat::from_rotation_x(PI)),
-        ParallaxCorrection::Custom(Vec3::splat(LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH)),
+        ParallaxCorrection::from_world_size(
+            Vec3::splat(LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH),
+            Vec3::splat(LIGHT_PROBE_SIDE_LENGTH),
+        ),
     ));
 
     // Spawn the second room's light probe.
     commands.spawn((
+        Name::new("Room 2"),
         LightProbe {
             falloff: Vec3::splat(LIGHT_PROBE_FALLOFF),
+            ..default()
         },
         EnvironmentMapLight {
             diffuse_map: asset_server.load(get_web_asset_url("diffuse_room2.ktx2")),
@@ -345,7 +352,10 @@ fn spawn_light_probes(commands: &mut Commands, asset_server: &AssetServer) {
         Transform::from_scale(vec3(1.0, -1.0, 1.0) * LIGHT_PROBE_SIDE_LENGTH)
             .with_rotation(Quat::from_rotation_x(PI))
             .with_translation(vec3(0.0, 0.0, -ROOM_SEPARATION)),
-        ParallaxCorrection::Custom(Vec3::splat(LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH)),
+        ParallaxCorrection::from_world_size(
+            Vec3::splat(LIGHT_PROBE_PARALLAX_CORRECTION_SIDE_LENGTH),
+            Vec3::splat(LIGHT_PROBE_SIDE_LENGTH),
+        ),
     ));
 }
 
@@ -354,20 +364,23 @@ fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
         children![
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Gizmos",
-                &[(GizmosEnabled::On, "On"), (GizmosEnabled::Off, "Off"),]
+                &[(GizmosEnabled::On, "On"), (GizmosEnabled::Off, "Off"),],
+                GizmosEnabled::default(),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Object to Show",
                 &[
                     (ObjectToShow::Sphere, "Sphere"),
                     (ObjectToShow::Prism, "Prism"),
-                ]
+                ],
+                ObjectToShow::default(),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Camera Mode",
-                &[(CameraMode::Orbit, "Orbit"), (CameraMode::Free, "Free"),]
+                &[(CameraMode::Orbit, "Orbit"), (CameraMode::Free, "Free"),],
+                CameraMode::default(),
             ),
         ],
     ));
@@ -387,6 +400,21 @@ fn spawn_help_text(commands: &mut Commands) {
     ));
 }
 
+/// Spawns the text showing the light probe blend weights at the top right of
+/// the screen.
+fn spawn_influence_text(commands: &mut Commands) {
+    commands.spawn((
+        Text::new(""),
+        Node {
+            position_type: PositionType::Absolute,
+            top: px(12),
+            right: px(12),
+            ..default()
+        },
+        InfluenceText,
+    ));
+}
+
 /// Moves the sphere a bit every frame.
 fn move_sphere(mut spheres: Query<&mut Transform, With<ReflectiveSphere>>, time: Res<Time>) {
     let Some(t) = SmoothStepCurve
@@ -403,48 +431,23 @@ fn move_sphere(mut spheres: Query<&mut Transform, With<ReflectiveSphere>>, time:
     }
 }
 
-/// Processes requests from the user to move the camera.
-fn orbit_camera(
-    mut cameras: Query<(&mut Transform, &mut OrbitCamera)>,
-    spheres: Query<&Transform, (With<ReflectiveSphere>, Without<OrbitCamera>)>,
-    mouse_buttons: Res<ButtonInput<MouseButton>>,
-    mouse_motion: Res<AccumulatedMouseMotion>,
-    mouse_scroll: Res<AccumulatedMouseScroll>,
+/// Keeps the orbital camera focused on the reflective sphere as it moves, including while the
+/// camera is still transitioning into the orbit.
+fn update_orbit_camera_focus(
+    mut cameras: Query<&mut OrbitCamera>,
+    mut transitions: Query<&mut CameraTransition<OrbitCamera>>,
+    spheres: Query<&Transform, With<ReflectiveSphere>>,
 ) {
-    // Grab the sphere transform.
     let Some(sphere_transform) = spheres.iter().next() else {
         return;
     };
-
-    for (mut camera_transform, mut orbit_camera) in &mut cameras {
-        // Only pan if the left mouse button is pressed.
-        if mouse_buttons.pressed(MouseButton::Left) {
-            let delta = mouse_motion.delta;
-            orbit_camera.azimuth -= delta.x * CAMERA_ORBIT_SPEED_AZIMUTH;
-            orbit_camera.inclination += delta.y * CAMERA_ORBIT_SPEED_INCLINATION;
+    for mut orbit_camera in &mut cameras {
+        orbit_camera.focus = sphere_transform.translation;
+    }
+    for mut transition in &mut transitions {
+        if let Some(orbit_camera) = transition.controller_mut() {
+            orbit_camera.focus = sphere_transform.translation;
         }
-
-        // Zooming doesn't require a mouse button press, as it uses the mouse
-        // wheel.
-        orbit_camera.radius =
-            (orbit_camera.radius - CAMERA_ZOOM_SPEED * mouse_scroll.delta.y).max(0.01);
-
-        // Calculate the new translation using the [spherical coordinates
-        // formula].
-        //
-        // [spherical coordinates formula]:
-        // https://en.wikipedia.org/wiki/Spherical_coordinate_system#Cartesian_coordinates
-        let new_translation = orbit_camera.radius
-            * vec3(
-                sin(orbit_camera.inclination) * cos(orbit_camera.azimuth),
-                cos(orbit_camera.inclination),
-                sin(orbit_camera.inclination) * sin(orbit_camera.azimuth),
-            );
-
-        // Write in the new transform.
-        *camera_transform =
-            Transform::from_translation(new_translation + sphere_transform.translation)
-                .looking_at(sphere_transform.translation, Vec3::Y);
     }
 }
 
@@ -471,12 +474,9 @@ fn handle_gizmos_enabled_change(
 fn handle_object_to_show_change(
     mut spheres_query: Query<&mut Visibility, (With<ReflectiveSphere>, Without<ReflectivePrism>)>,
     mut prisms_query: Query<&mut Visibility, (With<ReflectivePrism>, Without<ReflectiveSphere>)>,
-    mut app_status: ResMut<AppStatus>,
     mut messages: MessageReader<WidgetClickEvent<ObjectToShow>>,
 ) {
     for message in messages.read() {
-        app_status.object_to_show = **message;
-
         for mut sphere_visibility in &mut spheres_query {
             *sphere_visibility = match **message {
                 ObjectToShow::Sphere => Visibility::Inherited,
@@ -514,28 +514,19 @@ fn handle_camera_mode_change(
         match **message {
             CameraMode::Orbit => {
                 for (camera_entity, camera_transform) in &cameras_query {
-                    // Convert from Cartesian coordinates back to spherical
-                    // coordinates.
-                    let relative_camera_position =
-                        camera_transform.translation - sphere_transform.translation;
-                    let radius = relative_camera_position.length();
-                    let inclination = atan2(
-                        relative_camera_position.xz().length() / radius,
-                        relative_camera_position.y / radius,
-                    );
-                    let azimuth = atan2(
-                        relative_camera_position.z * relative_camera_position.xz().length_recip(),
-                        relative_camera_position.x * relative_camera_position.xz().length_recip(),
+                    // Ease the camera into the orbit, rather than snapping it to
+                    // face the sphere.
+                    let orbit_camera = OrbitCamera::from_translation(
+                        sphere_transform.translation,
+                        camera_transform.translation,
                     );
-
                     commands
                         .entity(camera_entity)
                         .remove::<FreeCamera>()
-                        .insert(OrbitCamera {
-                            radius,
-                            inclination,
-                            azimuth,
-                        });
+                        .insert(CameraTransition::following(
+                            orbit_camera,
+                            OrbitCamera::transform,
+                        ));
                 }
             }
 
@@ -543,7 +534,7 @@ fn handle_camera_mode_change(
                 for (camera_entity, _) in &cameras_query {
                     commands
                         .entity(camera_entity)
-                        .remove::<OrbitCamera>()
+                        .remove::<(OrbitCamera, CameraTransition<OrbitCamera>)>()
                         .insert(FreeCamera::default());
                 }
             }
@@ -564,48 +555,6 @@ fn handle_camera_mode_change(
     }
 }
 
-/// A system that updates the radio buttons at the bottom of the screen to
-/// reflect whether gizmos are enabled or not.
-fn update_radio_buttons(
-    mut widgets_query: Query<(
-        Entity,
-        Option<&mut BackgroundColor>,
-        Has<Text>,
-        AnyOf<(
-            &WidgetClickSender<GizmosEnabled>,
-            &WidgetClickSender<ObjectToShow>,
-            &WidgetClickSender<CameraMode>,
-        )>,
-    )>,
-    app_status: Res<AppStatus>,
-    mut text_ui_writer: TextUiWriter,
-) {
-    for (
-        entity,
-        maybe_bg_color,
-        has_text,
-        (maybe_gizmos_enabled, maybe_object_to_show, maybe_camera_mode),
-    ) in &mut widgets_query
-    {
-        let selected = if let Some(sender) = maybe_gizmos_enabled {
-            app_status.gizmos_enabled == **sender
-        } else if let Some(sender) = maybe_object_to_show {
-            app_status.object_to_show == **sender
-        } else if let Some(sender) = maybe_camera_mode {
-            app_status.camera_mode == **sender
-        } else {
-            continue;
-        };
-
-        if let Some(mut bg_color) = maybe_bg_color {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut text_ui_writer, selected);
-        }
-    }
-}
-
 /// Draws gizmos that show the boundaries of the various boxes associated with
 /// the light probes in the scene.
 fn draw_gizmos(
@@ -618,28 +567,18 @@ fn draw_gizmos(
         return;
     }
 
+    // Draw the light probe bounds, falloff, and parallax correction bounds. The
+    // falloff and parallax correction boxes are dashed to tell them apart.
     for (light_probe, parallax_correction, transform) in &light_probes {
-        // Draw light probe bounds.
-        gizmos.cube(*transform, TAN);
-
-        // Draw light probe falloff.
-        gizmos.cube(
-            Transform {
-                scale: transform.scale * (Vec3::ONE - light_probe.falloff),
-                ..*transform
-            },
-            CRIMSON,
-        );
-
-        // Draw light probe parallax correction bounds.
-        if let ParallaxCorrection::Custom(parallax_correction_bounds) = *parallax_correction {
-            gizmos.cube(
-                Transform {
-                    scale: transform.scale * parallax_correction_bounds,
-                    ..*transform
-                },
-                CORNFLOWER_BLUE,
-            );
+        let light_probe_gizmo = gizmos
+            .light_probe(*transform, light_probe, parallax_correction)
+            .dashed_inner_boxes(0.3, 0.2);
+
+        // Probes without parallax correction reflect an infinitely distant
+        // environment, and their bounds only limit their region of influence.
+        // Draw those bounds in a different color to tell them apart.
+        if let ParallaxCorrection::None = parallax_correction {
+            light_probe_gizmo.bounds_color(SILVER);
         }
     }
 }
@@ -678,6 +617,29 @@ Blue: Parallax correction bounds",
     }
 }
 
+/// Displays the blend weights of the light probes at the sphere's position.
+fn update_influence_text(
+    spheres_query: Query<&LightProbeInfluence, With<ReflectiveSphere>>,
+    names_query: Query<&Name>,
+    mut influence_text_query: Query<&mut Text, With<InfluenceText>>,
+) {
+    let Some(influence) = spheres_query.iter().next() else {
+        return;
+    };
+
+    let mut influence_text = String::from("Light probe weights:");
+    for &(light_probe, weight) in &influence.weights {
+        let name = names_query
+            .get(light_probe)
+            .map_or("Light probe", |name| name.as_str());
+        influence_text.push_str(&format!("\n{name}: {weight:.2}"));
+    }
+
+    for mut ui_text in &mut influence_text_query {
+        *ui_text = Text::new(influence_text.clone());
+    }
+}
+
 /// Returns the GitHub download URL for the given asset.
 ///
 /// The files are expected to be in the `light_probe_blending` directory in the
@@ -124,7 +124,11 @@ fn main() {
         .add_systems(Update, widgets::handle_ui_interactions::<Visibility>)
         .add_systems(
             Update,
-            (handle_selection_change, update_radio_buttons)
+            (
+                handle_selection_change,
+                widgets::update_radio_groups::<Selection>,
+                update_visibility_buttons,
+            )
                 .after(widgets::handle_ui_interactions::<Selection>)
                 .after(widgets::handle_ui_interactions::<Visibility>),
         )
@@ -285,7 +289,7 @@ fn spawn_buttons(commands: &mut Commands) {
     // control.
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Drag to Move",
             &[
                 (Selection::Camera, "Camera"),
@@ -293,6 +297,7 @@ fn spawn_buttons(commands: &mut Commands) {
                 (Selection::PointLight, "Point Light"),
                 (Selection::DirectionalLight, "Directional Light"),
             ],
+            Selection::default(),
         )],
     ));
 
@@ -386,37 +391,19 @@ fn hide_shadows(
     }
 }
 
-/// Updates the state of the radio buttons when the user clicks on one.
-fn update_radio_buttons(
-    mut widgets: Query<(
+/// Updates the state of the show and hide buttons to reflect the visibility of
+/// the selected object.
+fn update_visibility_buttons(
+    app_status: Res<AppStatus>,
+    mut writer: TextUiWriter,
+    visible: Query<(&Visibility, &Selection)>,
+    mut visibility_widgets: Query<(
         Entity,
         Option<&mut BackgroundColor>,
         Has<Text>,
-        &WidgetClickSender<Selection>,
+        &WidgetClickSender<Visibility>,
     )>,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-    visible: Query<(&Visibility, &Selection)>,
-    mut visibility_widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<Visibility>,
-        ),
-        Without<WidgetClickSender<Selection>>,
-    >,
 ) {
-    for (entity, maybe_bg_color, has_text, sender) in &mut widgets {
-        let selected = app_status.selection == **sender;
-        if let Some(mut bg_color) = maybe_bg_color {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-
     let visibility = visible
         .iter()
         .filter(|(_, selection)| **selection == app_status.selection)
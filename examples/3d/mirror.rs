@@ -2,7 +2,7 @@
 
 use std::f32::consts::FRAC_PI_2;
 
-use crate::widgets::{RadioButton, WidgetClickEvent, WidgetClickSender};
+use crate::widgets::{RadioButton, WidgetClickEvent};
 use bevy::camera::RenderTarget;
 use bevy::{
     asset::RenderAssetUsages,
@@ -128,7 +128,10 @@ fn main() {
         .add_systems(Update, widgets::handle_ui_interactions::<DragAction>)
         .add_systems(
             Update,
-            (handle_mouse_action_change, update_radio_buttons)
+            (
+                handle_mouse_action_change,
+                widgets::update_radio_groups::<DragAction>,
+            )
                 .after(widgets::handle_ui_interactions::<DragAction>),
         )
         .add_systems(
@@ -326,12 +329,13 @@ fn spawn_buttons(commands: &mut Commands) {
     // control.
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Drag Action",
             &[
                 (DragAction::MoveCamera, "Move Camera"),
                 (DragAction::MoveFox, "Move Fox"),
             ],
+            DragAction::default(),
         )],
     ));
 }
@@ -500,29 +504,6 @@ fn handle_mouse_action_change(
     }
 }
 
-/// A system that updates the radio buttons at the bottom of the screen to
-/// reflect the current drag action.
-fn update_radio_buttons(
-    mut widgets_query: Query<(
-        Entity,
-        Option<&mut BackgroundColor>,
-        Has<Text>,
-        &WidgetClickSender<DragAction>,
-    )>,
-    app_status: Res<AppStatus>,
-    mut text_ui_writer: TextUiWriter,
-) {
-    for (entity, maybe_bg_color, has_text, sender) in &mut widgets_query {
-        let selected = app_status.drag_action == **sender;
-        if let Some(mut bg_color) = maybe_bg_color {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut text_ui_writer, selected);
-        }
-    }
-}
-
 /// A system that processes user mouse actions that move the camera.
 ///
 /// This is mostly copied from `examples/camera/camera_orbit.rs`.
@@ -8,7 +8,7 @@ use bevy::{
     scene::SceneInstanceReady,
 };
 
-use crate::widgets::{RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 
 #[path = "../helpers/widgets.rs"]
 mod widgets;
@@ -136,7 +136,7 @@ fn main() {
         .add_systems(Update, update_lightmaps)
         .add_systems(Update, update_directional_light)
         .add_systems(Update, make_sphere_nonpickable)
-        .add_systems(Update, update_radio_buttons)
+        .add_systems(Update, widgets::update_radio_groups::<LightingMode>)
         .add_systems(Update, handle_lighting_mode_change)
         .add_systems(Update, widgets::handle_ui_interactions::<LightingMode>)
         .add_systems(Update, reset_sphere_position)
@@ -185,7 +185,7 @@ fn spawn_scene(commands: &mut Commands, asset_server: &AssetServer) {
 fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Lighting",
             &[
                 (LightingMode::Baked, "Baked"),
@@ -193,6 +193,7 @@ fn spawn_buttons(commands: &mut Commands) {
                 (LightingMode::MixedIndirect, "Mixed (Indirect)"),
                 (LightingMode::RealTime, "Real-Time"),
             ],
+            LightingMode::default(),
         )],
     ));
 }
@@ -356,33 +357,6 @@ fn update_directional_light(
     }
 }
 
-/// Updates the state of the selection widgets at the bottom of the window when
-/// the lighting mode changes.
-fn update_radio_buttons(
-    mut widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<LightingMode>,
-        ),
-        Or<(With<RadioButton>, With<RadioButtonText>)>,
-    >,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, image, has_text, sender) in &mut widgets {
-        let selected = **sender == app_status.lighting_mode;
-
-        if let Some(mut bg_color) = image {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 /// Handles clicks on the widgets at the bottom of the screen and fires
 /// [`LightingModeChanged`] events.
 fn handle_lighting_mode_change(
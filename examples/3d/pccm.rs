@@ -9,7 +9,7 @@ use bevy::{
     prelude::*,
 };
 
-use crate::widgets::{WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 
 #[path = "../helpers/widgets.rs"]
 mod widgets;
@@ -32,13 +32,6 @@ const ENV_DIFFUSE_URL: &str =
 const ENV_SPECULAR_URL: &str =
     "https://github.com/bevyengine/bevy_asset_files/raw/main/pccm_example/env_specular.ktx2";
 
-/// The current value of user-customizable settings for this demo.
-#[derive(Resource, Default)]
-struct AppStatus {
-    /// Whether parallax correction is enabled.
-    pccm_enabled: PccmEnableStatus,
-}
-
 /// Whether parallax correction is enabled.
 #[derive(Clone, Copy, PartialEq, Default)]
 enum PccmEnableStatus {
@@ -62,13 +55,15 @@ fn main() {
             }),
             FreeCameraPlugin,
         ))
-        .init_resource::<AppStatus>()
         .add_message::<WidgetClickEvent<PccmEnableStatus>>()
         .add_systems(Startup, setup)
         .add_systems(Update, widgets::handle_ui_interactions::<PccmEnableStatus>)
         .add_systems(
             Update,
-            (handle_pccm_enable_change, update_radio_buttons)
+            (
+                handle_pccm_enable_change,
+                widgets::update_radio_groups::<PccmEnableStatus>,
+            )
                 .after(widgets::handle_ui_interactions::<PccmEnableStatus>),
         )
         .run();
@@ -154,12 +149,13 @@ fn spawn_reflection_probe(commands: &mut Commands, asset_server: &AssetServer) {
 fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
-        children![widgets::option_buttons(
+        children![widgets::radio_group(
             "Parallax Correction",
             &[
                 (PccmEnableStatus::Enabled, "On"),
                 (PccmEnableStatus::Disabled, "Off"),
             ],
+            PccmEnableStatus::default(),
         )],
     ));
 }
@@ -168,7 +164,6 @@ fn spawn_buttons(commands: &mut Commands) {
 fn handle_pccm_enable_change(
     mut commands: Commands,
     light_probe_query: Query<Entity, With<LightProbe>>,
-    mut app_status: ResMut<AppStatus>,
     mut messages: MessageReader<WidgetClickEvent<PccmEnableStatus>>,
 ) {
     let Some(light_probe_entity) = light_probe_query.iter().next() else {
@@ -177,10 +172,8 @@ fn handle_pccm_enable_change(
 
     for message in messages.read() {
         // The UI message contains the `PccmEnableStatus` value that the user
-        // selected.
-        app_status.pccm_enabled = **message;
-
-        // Add the appropriate variant of the `ParallaxCorrection` component.
+        // selected. Add the appropriate variant of the `ParallaxCorrection`
+        // component.
         match **message {
             PccmEnableStatus::Enabled => {
                 commands
@@ -195,29 +188,3 @@ fn handle_pccm_enable_change(
         }
     }
 }
-
-/// Updates the state of the UI at the bottom of the screen to reflect the
-/// current application settings.
-fn update_radio_buttons(
-    mut widgets_query: Query<(
-        Entity,
-        Option<&mut BackgroundColor>,
-        Has<Text>,
-        &WidgetClickSender<PccmEnableStatus>,
-    )>,
-    app_status: Res<AppStatus>,
-    mut text_ui_writer: TextUiWriter,
-) {
-    for (entity, maybe_bg_color, has_text, sender) in &mut widgets_query {
-        // The `sender` value contains the `PccmEnableStatus` that the user
-        // selected.
-        let selected = app_status.pccm_enabled == **sender;
-
-        if let Some(mut bg_color) = maybe_bg_color {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut text_ui_writer, selected);
-        }
-    }
-}
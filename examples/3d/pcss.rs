@@ -17,7 +17,7 @@ use bevy::{
     render::camera::TemporalJitter,
 };
 
-use crate::widgets::{RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender};
+use crate::widgets::WidgetClickEvent;
 
 #[path = "../helpers/widgets.rs"]
 mod widgets;
@@ -48,25 +48,16 @@ const POINT_SHADOW_DEPTH_BIAS: f32 = 0.35;
 /// and spot lights.
 const SHADOW_MAP_NEAR_Z: f32 = 50.0;
 
-/// The current application settings (light type, shadow filter, and the status
-/// of PCSS).
+/// The current application settings that the lights are created from.
 #[derive(Resource)]
 struct AppStatus {
-    /// The type of light presently in the scene: either directional or point.
-    light_type: LightType,
-    /// The type of shadow filter: Gaussian or temporal.
-    shadow_filter: ShadowFilter,
     /// Whether soft shadows are enabled.
     soft_shadows: bool,
 }
 
 impl Default for AppStatus {
     fn default() -> Self {
-        Self {
-            light_type: default(),
-            shadow_filter: default(),
-            soft_shadows: true,
-        }
+        Self { soft_shadows: true }
     }
 }
 
@@ -132,7 +123,8 @@ fn main() {
         .add_systems(Update, widgets::handle_ui_interactions::<AppSetting>)
         .add_systems(
             Update,
-            update_radio_buttons.after(widgets::handle_ui_interactions::<AppSetting>),
+            widgets::update_radio_groups::<AppSetting>
+                .after(widgets::handle_ui_interactions::<AppSetting>),
         )
         .add_systems(
             Update,
@@ -219,15 +211,16 @@ fn spawn_buttons(commands: &mut Commands) {
     commands.spawn((
         widgets::main_ui_node(),
         children![
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Light Type",
                 &[
                     (AppSetting::LightType(LightType::Directional), "Directional"),
                     (AppSetting::LightType(LightType::Point), "Point"),
                     (AppSetting::LightType(LightType::Spot), "Spot"),
                 ],
+                AppSetting::LightType(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Shadow Filter",
                 &[
                     (AppSetting::ShadowFilter(ShadowFilter::Temporal), "Temporal"),
@@ -236,61 +229,31 @@ fn spawn_buttons(commands: &mut Commands) {
                         "Non-Temporal",
                     ),
                 ],
+                AppSetting::ShadowFilter(default()),
             ),
-            widgets::option_buttons(
+            widgets::radio_group(
                 "Soft Shadows",
                 &[
                     (AppSetting::SoftShadows(true), "On"),
                     (AppSetting::SoftShadows(false), "Off"),
                 ],
+                AppSetting::SoftShadows(true),
             ),
         ],
     ));
 }
 
-/// Updates the style of the radio buttons that enable and disable soft shadows
-/// to reflect whether PCSS is enabled.
-fn update_radio_buttons(
-    mut widgets: Query<
-        (
-            Entity,
-            Option<&mut BackgroundColor>,
-            Has<Text>,
-            &WidgetClickSender<AppSetting>,
-        ),
-        Or<(With<RadioButton>, With<RadioButtonText>)>,
-    >,
-    app_status: Res<AppStatus>,
-    mut writer: TextUiWriter,
-) {
-    for (entity, image, has_text, sender) in widgets.iter_mut() {
-        let selected = match **sender {
-            AppSetting::LightType(light_type) => light_type == app_status.light_type,
-            AppSetting::ShadowFilter(shadow_filter) => shadow_filter == app_status.shadow_filter,
-            AppSetting::SoftShadows(soft_shadows) => soft_shadows == app_status.soft_shadows,
-        };
-
-        if let Some(mut bg_color) = image {
-            widgets::update_ui_radio_button(&mut bg_color, selected);
-        }
-        if has_text {
-            widgets::update_ui_radio_button_text(entity, &mut writer, selected);
-        }
-    }
-}
-
 /// Handles requests from the user to change the type of light.
 fn handle_light_type_change(
     mut commands: Commands,
     mut lights: Query<Entity, Or<(With<DirectionalLight>, With<PointLight>, With<SpotLight>)>>,
     mut events: MessageReader<WidgetClickEvent<AppSetting>>,
-    mut app_status: ResMut<AppStatus>,
+    app_status: Res<AppStatus>,
 ) {
     for event in events.read() {
         let AppSetting::LightType(light_type) = **event else {
             continue;
         };
-        app_status.light_type = light_type;
 
         for light in lights.iter_mut() {
             let mut light_commands = commands.entity(light);
@@ -321,13 +284,11 @@ fn handle_shadow_filter_change(
     mut commands: Commands,
     mut cameras: Query<(Entity, &mut ShadowFilteringMethod)>,
     mut events: MessageReader<WidgetClickEvent<AppSetting>>,
-    mut app_status: ResMut<AppStatus>,
 ) {
     for event in events.read() {
         let AppSetting::ShadowFilter(shadow_filter) = **event else {
             continue;
         };
-        app_status.shadow_filter = shadow_filter;
 
         for (camera, mut shadow_filtering_method) in cameras.iter_mut() {
             match shadow_filter {
@@ -27,7 +27,7 @@ use bevy::{
 mod widgets;
 
 use widgets::{
-    handle_ui_interactions, main_ui_node, option_buttons, update_ui_radio_button,
+    handle_ui_interactions, main_ui_node, radio_group, update_radio_groups,
     update_ui_radio_button_text, RadioButton, RadioButtonText, WidgetClickEvent, WidgetClickSender,
     BUTTON_BORDER, BUTTON_BORDER_COLOR, BUTTON_BORDER_RADIUS_SIZE, BUTTON_PADDING,
 };
@@ -222,6 +222,10 @@ fn main() {
         .add_systems(Update, move_camera)
         .add_systems(Update, adjust_app_settings)
         .add_systems(Update, handle_ui_interactions::<ExampleSetting>)
+        .add_systems(
+            Update,
+            update_radio_groups::<ExampleSetting>.after(handle_ui_interactions::<ExampleSetting>),
+        )
         .run();
 }
 
@@ -441,15 +445,16 @@ fn spawn_camera(commands: &mut Commands, asset_server: &AssetServer, app_setting
 
 fn spawn_buttons(commands: &mut Commands, app_settings: &AppSettings) {
     commands.spawn(main_ui_node()).with_children(|parent| {
-        parent.spawn(option_buttons(
+        parent.spawn(radio_group(
             "SSR",
             &[
                 (ExampleSetting::Ssr(true), "On"),
                 (ExampleSetting::Ssr(false), "Off"),
             ],
+            ExampleSetting::Ssr(app_settings.ssr_on),
         ));
 
-        parent.spawn(option_buttons(
+        parent.spawn(radio_group(
             "Model",
             &[
                 (ExampleSetting::Model(DisplayedModel::Cube), "Cube"),
@@ -459,15 +464,17 @@ fn spawn_buttons(commands: &mut Commands, app_settings: &AppSettings) {
                 ),
                 (ExampleSetting::Model(DisplayedModel::Capsules), "Capsules"),
             ],
+            ExampleSetting::Model(app_settings.displayed_model),
         ));
 
-        parent.spawn(option_buttons(
+        parent.spawn(radio_group(
             "Base",
             &[
                 (ExampleSetting::Base(DisplayedBase::Water), "Water"),
                 (ExampleSetting::Base(DisplayedBase::Metallic), "Metallic"),
                 (ExampleSetting::Base(DisplayedBase::RedPlane), "Red Plane"),
             ],
+            ExampleSetting::Base(app_settings.displayed_base),
         ));
 
         parent.spawn(range_row(
@@ -802,30 +809,20 @@ fn adjust_app_settings(
         }
     }
 
-    // Update radio buttons.
+    // Reset the adjustment buttons. The radio groups restyle themselves.
     for (entity, has_background, has_text, sender) in radio_buttons.iter() {
-        let selected = match **sender {
-            ExampleSetting::Ssr(on) => app_settings.ssr_on == on,
-            ExampleSetting::Model(model) => app_settings.displayed_model == model,
-            ExampleSetting::Base(base) => app_settings.displayed_base == base,
-            _ => {
-                if has_background
-                    && let Ok(mut background_color) = background_colors.get_mut(entity)
-                {
-                    *background_color = BackgroundColor(Color::BLACK);
-                }
-                if has_text {
-                    update_ui_radio_button_text(entity, &mut writer, false);
-                }
-                continue;
-            }
-        };
+        if matches!(
+            **sender,
+            ExampleSetting::Ssr(_) | ExampleSetting::Model(_) | ExampleSetting::Base(_)
+        ) {
+            continue;
+        }
 
         if has_background && let Ok(mut background_color) = background_colors.get_mut(entity) {
-            update_ui_radio_button(&mut background_color, selected);
+            *background_color = BackgroundColor(Color::BLACK);
         }
         if has_text {
-            update_ui_radio_button_text(entity, &mut writer, selected);
+            update_ui_radio_button_text(entity, &mut writer, false);
         }
     }
 
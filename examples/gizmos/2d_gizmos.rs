@@ -131,12 +131,12 @@ fn update_config(
 ) {
     let (config, _) = config_store.config_mut::<DefaultGizmoConfigGroup>();
     if keyboard.pressed(KeyCode::ArrowRight) {
-        config.line.width += 5. * real_time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width + 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.pressed(KeyCode::ArrowLeft) {
-        config.line.width -= 5. * real_time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width - 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.just_pressed(KeyCode::Digit1) {
         config.enabled ^= true;
@@ -181,12 +181,12 @@ fn update_config(
 
     let (my_config, _) = config_store.config_mut::<MyRoundGizmos>();
     if keyboard.pressed(KeyCode::ArrowUp) {
-        my_config.line.width += 5. * real_time.delta_secs();
-        my_config.line.width = my_config.line.width.clamp(0., 50.);
+        let width = my_config.line.width.value_mut();
+        *width = (*width + 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.pressed(KeyCode::ArrowDown) {
-        my_config.line.width -= 5. * real_time.delta_secs();
-        my_config.line.width = my_config.line.width.clamp(0., 50.);
+        let width = my_config.line.width.value_mut();
+        *width = (*width - 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.just_pressed(KeyCode::Digit2) {
         my_config.enabled ^= true;
@@ -38,7 +38,7 @@ fn setup(mut commands: Commands, mut gizmo_config_store: ResMut<GizmoConfigStore
 
     let (config, _) = gizmo_config_store.config_mut::<DefaultGizmoConfigGroup>();
 
-    config.line.width = 1.;
+    config.line.width = GizmoLineWidth::Pixels(1.);
 }
 
 fn draw_labels(mut text_gizmos: Gizmos, diagnostic: Res<DiagnosticsStore>) {
@@ -41,7 +41,7 @@ fn setup(
         Gizmo {
             handle: gizmo_assets.add(gizmo),
             line_config: GizmoLineConfig {
-                width: 5.,
+                width: GizmoLineWidth::Pixels(5.),
                 ..default()
             },
             ..default()
@@ -220,18 +220,18 @@ fn update_config(
             // Toggle line perspective
             config.line.perspective ^= true;
             // Increase the line width when line perspective is on
-            config.line.width *= if config.line.perspective { 5. } else { 1. / 5. };
+            *config.line.width.value_mut() *= if config.line.perspective { 5. } else { 1. / 5. };
         }
     }
 
     let (config, _) = config_store.config_mut::<DefaultGizmoConfigGroup>();
     if keyboard.pressed(KeyCode::ArrowRight) {
-        config.line.width += 5. * real_time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width + 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.pressed(KeyCode::ArrowLeft) {
-        config.line.width -= 5. * real_time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width - 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.just_pressed(KeyCode::Digit1) {
         config.enabled ^= true;
@@ -257,12 +257,12 @@ fn update_config(
 
     let (my_config, _) = config_store.config_mut::<MyRoundGizmos>();
     if keyboard.pressed(KeyCode::ArrowUp) {
-        my_config.line.width += 5. * real_time.delta_secs();
-        my_config.line.width = my_config.line.width.clamp(0., 50.);
+        let width = my_config.line.width.value_mut();
+        *width = (*width + 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.pressed(KeyCode::ArrowDown) {
-        my_config.line.width -= 5. * real_time.delta_secs();
-        my_config.line.width = my_config.line.width.clamp(0., 50.);
+        let width = my_config.line.width.value_mut();
+        *width = (*width - 5. * real_time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.just_pressed(KeyCode::Digit2) {
         my_config.enabled ^= true;
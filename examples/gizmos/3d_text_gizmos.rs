@@ -1,6 +1,7 @@
 //! Basic example demonstrating 3d text gizmos
 
-use bevy::color::palettes::css::{ORANGE, RED, YELLOW};
+use bevy::color::palettes::css::{ORANGE, RED, WHITE, YELLOW};
+use bevy::gizmos::stroke_text::GizmoTextScale;
 use bevy::prelude::*;
 
 fn main() {
@@ -19,10 +20,16 @@ fn setup_camera(mut commands: Commands, mut gizmo_config_store: ResMut<GizmoConf
 
     let (config, _) = gizmo_config_store.config_mut::<DefaultGizmoConfigGroup>();
 
-    config.line.width = 4.;
+    config.line.width = GizmoLineWidth::Pixels(4.);
+    // Always draw text in front of other geometry, independently of the other gizmos.
+    config.text.depth_bias = Some(-1.0);
 }
 
-fn hello_world(mut text_gizmos: Gizmos, time: Res<Time>) {
+fn hello_world(
+    mut text_gizmos: Gizmos,
+    time: Res<Time>,
+    camera: Single<(&Camera, &GlobalTransform)>,
+) {
     let t = 0.2 * time.elapsed_secs();
 
     text_gizmos.text(
@@ -48,4 +55,14 @@ fn hello_world(mut text_gizmos: Gizmos, time: Res<Time>) {
         Vec2::ZERO,
         YELLOW,
     );
+
+    // This label keeps the same size on screen while it moves towards and away from the camera.
+    text_gizmos.text_with_scale(
+        Isometry3d::from_translation(Vec3::new(3.0, -3.0, -5.0 * ops::sin(4.0 * t))),
+        "Screen-sized",
+        GizmoTextScale::Screen(20.),
+        Vec2::ZERO,
+        WHITE,
+        *camera,
+    );
 }
@@ -2,8 +2,13 @@
 //!
 //! The anchor selects which part of the text is aligned to the isometry’s position:
 //! `(0, 0)` center, `(-0.5, 0.0)` left edge, `(0.0, 0.5)` top edge.
+//! For multi-line text, the anchor applies to the whole block of lines.
+//! The lines of multi-line text can be justified left, center or right within the block,
+//! with `text_2d_styled` in 2D and `text_styled` in 3D.
+//! An outline and a frame can be added to make text readable over busy scenes.
 
-use bevy::color::palettes::css::{BLUE, GREEN, ORANGE, RED, YELLOW};
+use bevy::color::palettes::css::{BLACK, BLUE, DIM_GRAY, GREEN, ORANGE, RED, YELLOW};
+use bevy::gizmos::stroke_text::{GizmoTextJustify, GizmoTextStyle};
 use bevy::prelude::*;
 
 fn main() {
@@ -43,4 +48,58 @@ fn anchors(mut text_gizmos: Gizmos, time: Res<Time>) {
             color,
         );
     }
+
+    // The top-left corner of this two-line block is aligned with the cross.
+    let position = vec2(-350.0, -250.0);
+    text_gizmos.text_2d(
+        Isometry2d::from_translation(position),
+        "+",
+        12.,
+        Vec2::ZERO,
+        Color::WHITE,
+    );
+    text_gizmos.text_2d_with_line_spacing(
+        Isometry2d::from_translation(position),
+        "two lines\nanchored top-left",
+        25.,
+        1.5,
+        vec2(-0.5, 0.5),
+        Color::WHITE,
+    );
+
+    // An outline and a frame make text stand out from what's behind it.
+    text_gizmos.text_2d_styled(
+        Isometry2d::from_translation(vec2(250.0, -250.0)),
+        "styled",
+        25.,
+        Vec2::ZERO,
+        Color::WHITE,
+        GizmoTextStyle {
+            outline: Some(BLACK.into()),
+            frame: Some(DIM_GRAY.into()),
+            ..default()
+        },
+    );
+
+    // The lines of this block are centered on each other, and the block's
+    // bottom center is aligned with the cross.
+    let position = vec2(0.0, -250.0);
+    text_gizmos.text_2d(
+        Isometry2d::from_translation(position),
+        "+",
+        12.,
+        Vec2::ZERO,
+        Color::WHITE,
+    );
+    text_gizmos.text_2d_styled(
+        Isometry2d::from_translation(position),
+        "justified\ncenter",
+        25.,
+        vec2(0.0, -0.5),
+        Color::WHITE,
+        GizmoTextStyle {
+            justify: GizmoTextJustify::Center,
+            ..default()
+        },
+    );
 }
@@ -155,12 +155,12 @@ fn update_config(
 
     let (config, light_config) = config_store.config_mut::<LightGizmoConfigGroup>();
     if keyboard.pressed(KeyCode::ArrowRight) {
-        config.line.width += 5. * time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width + 5. * time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.pressed(KeyCode::ArrowLeft) {
-        config.line.width -= 5. * time.delta_secs();
-        config.line.width = config.line.width.clamp(0., 50.);
+        let width = config.line.width.value_mut();
+        *width = (*width - 5. * time.delta_secs()).clamp(0., 50.);
     }
     if keyboard.just_pressed(KeyCode::KeyA) {
         config.enabled ^= true;
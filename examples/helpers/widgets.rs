@@ -24,6 +24,18 @@ pub struct RadioButton;
 #[derive(Clone, Copy, Component)]
 pub struct RadioButtonText;
 
+/// A component that tracks which option of a row of radio buttons is
+/// selected.
+///
+/// This is placed on the row spawned by [`radio_group`]. Add
+/// [`update_radio_groups`] to the app to select the option that the user
+/// clicks on and keep the buttons' appearance in sync. Changing the value
+/// directly also updates the buttons.
+#[derive(Clone, Component, Deref, DerefMut, PartialEq)]
+pub struct RadioGroup<T>(pub T)
+where
+    T: Clone + PartialEq + Send + Sync + 'static;
+
 /// The size of the border that surrounds buttons.
 pub const BUTTON_BORDER: UiRect = UiRect::all(Val::Px(1.0));
 
@@ -150,6 +162,16 @@ where
     )
 }
 
+/// Spawns the buttons that allow configuration of a setting, like
+/// [`option_buttons`], along with a [`RadioGroup`] that keeps track of the
+/// `selected` option.
+pub fn radio_group<T>(title: &str, options: &[(T, &str)], selected: T) -> impl Bundle
+where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    (option_buttons(title, options), RadioGroup(selected))
+}
+
 /// Creates a text bundle for the UI.
 pub fn ui_text(label: &str, color: Color) -> impl Bundle + use<> {
     (
@@ -192,3 +214,56 @@ pub fn update_ui_radio_button_text(entity: Entity, writer: &mut TextUiWriter, se
         color.0 = text_color;
     });
 }
+
+/// Selects the option of each [`RadioGroup`] that a [`WidgetClickEvent`] was
+/// sent for, and restyles the radio buttons of the groups that changed.
+///
+/// A group is only updated by events for one of its own options, so several
+/// groups can share a type, such as an enum with a variant per setting. This
+/// should run after [`handle_ui_interactions`] for the same type.
+pub fn update_radio_groups<T>(
+    mut groups: Query<(Entity, &mut RadioGroup<T>)>,
+    mut widget_click_events: MessageReader<WidgetClickEvent<T>>,
+    children: Query<&Children>,
+    mut widgets: Query<(
+        Option<&mut BackgroundColor>,
+        Has<Text>,
+        &WidgetClickSender<T>,
+    )>,
+    mut text_ui_writer: TextUiWriter,
+) where
+    T: Clone + PartialEq + Send + Sync + 'static,
+{
+    for widget_click_event in widget_click_events.read() {
+        for (group_entity, mut group) in &mut groups {
+            let is_option = children.iter_descendants(group_entity).any(|entity| {
+                widgets
+                    .get(entity)
+                    .is_ok_and(|(_, _, sender)| **sender == **widget_click_event)
+            });
+            if is_option {
+                group.set_if_neq(RadioGroup((**widget_click_event).clone()));
+            }
+        }
+    }
+
+    for (group_entity, group) in &mut groups {
+        if !group.is_changed() {
+            continue;
+        }
+
+        for entity in children.iter_descendants(group_entity) {
+            let Ok((maybe_bg_color, has_text, sender)) = widgets.get_mut(entity) else {
+                continue;
+            };
+            let selected = **group == **sender;
+
+            if let Some(mut bg_color) = maybe_bg_color {
+                update_ui_radio_button(&mut bg_color, selected);
+            }
+            if has_text {
+                update_ui_radio_button_text(entity, &mut text_ui_writer, selected);
+            }
+        }
+    }
+}